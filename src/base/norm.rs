@@ -210,6 +210,58 @@ impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
     pub fn lp_norm(&self, p: i32) -> N::RealField {
         self.apply_norm(&LpNorm(p))
     }
+
+    /// The operator norm induced by the vector 1-norm, i.e., the maximum sum of the moduli of the
+    /// elements of a column of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0, -2.0,
+    ///                      3.0,  4.0);
+    /// assert_eq!(m.induced_norm_1(), 6.0);
+    /// ```
+    #[inline]
+    pub fn induced_norm_1(&self) -> N::RealField {
+        let mut res = N::RealField::zero();
+
+        for j in 0..self.ncols() {
+            let sum = self.column(j).fold(N::RealField::zero(), |acc, e| acc + e.modulus());
+
+            if sum > res {
+                res = sum;
+            }
+        }
+
+        res
+    }
+
+    /// The operator norm induced by the vector ∞-norm, i.e., the maximum sum of the moduli of the
+    /// elements of a row of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0, -2.0,
+    ///                      3.0,  4.0);
+    /// assert_eq!(m.induced_norm_inf(), 7.0);
+    /// ```
+    #[inline]
+    pub fn induced_norm_inf(&self) -> N::RealField {
+        let mut res = N::RealField::zero();
+
+        for i in 0..self.nrows() {
+            let sum = self.row(i).fold(N::RealField::zero(), |acc, e| acc + e.modulus());
+
+            if sum > res {
+                res = sum;
+            }
+        }
+
+        res
+    }
 }
 
 
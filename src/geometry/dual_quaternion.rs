@@ -0,0 +1,678 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use std::fmt;
+use std::hash;
+#[cfg(feature = "abomonation-serialize")]
+use std::io::{Result as IOResult, Write};
+
+#[cfg(feature = "serde-serialize")]
+use crate::base::storage::Owned;
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "abomonation-serialize")]
+use abomonation::Abomonation;
+
+use alga::general::RealField;
+
+#[cfg(feature = "serde-serialize")]
+use crate::base::dimension::U4;
+use crate::base::{Matrix4, Unit, Vector3};
+
+use crate::geometry::{Isometry3, Point3, Quaternion, Translation3, UnitQuaternion};
+
+/// A dual quaternion `real + ε * dual` where `ε² = 0`. See the type alias
+/// `UnitDualQuaternion = Unit<DualQuaternion>` for a dual quaternion that may be used as a
+/// rigid-body motion.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DualQuaternion<N: RealField> {
+    /// The real part of this dual quaternion.
+    pub real: Quaternion<N>,
+    /// The dual part of this dual quaternion.
+    pub dual: Quaternion<N>,
+}
+
+#[cfg(feature = "abomonation-serialize")]
+impl<N: RealField> Abomonation for DualQuaternion<N>
+where Quaternion<N>: Abomonation
+{
+    unsafe fn entomb<W: Write>(&self, writer: &mut W) -> IOResult<()> {
+        self.real.entomb(writer)?;
+        self.dual.entomb(writer)
+    }
+
+    fn extent(&self) -> usize {
+        self.real.extent() + self.dual.extent()
+    }
+
+    unsafe fn exhume<'a, 'b>(&'a mut self, bytes: &'b mut [u8]) -> Option<&'b mut [u8]> {
+        self.real
+            .exhume(bytes)
+            .and_then(|bytes| self.dual.exhume(bytes))
+    }
+}
+
+impl<N: RealField + Eq> Eq for DualQuaternion<N> {}
+
+impl<N: RealField> PartialEq for DualQuaternion<N> {
+    fn eq(&self, rhs: &Self) -> bool {
+        (self.real.coords == rhs.real.coords && self.dual.coords == rhs.dual.coords) ||
+        // Account for the double-covering of rigid-body motions, i.e. q = -q
+        (self.real.coords == -rhs.real.coords && self.dual.coords == -rhs.dual.coords)
+    }
+}
+
+impl<N: RealField + hash::Hash> hash::Hash for DualQuaternion<N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.real.hash(state);
+        self.dual.hash(state)
+    }
+}
+
+impl<N: RealField> Copy for DualQuaternion<N> {}
+
+impl<N: RealField> Clone for DualQuaternion<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_real_and_dual(self.real, self.dual)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<N: RealField> Serialize for DualQuaternion<N>
+where Owned<N, U4>: Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        (&self.real, &self.dual).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'a, N: RealField> Deserialize<'a> for DualQuaternion<N>
+where Owned<N, U4>: Deserialize<'a>
+{
+    fn deserialize<Des>(deserializer: Des) -> Result<Self, Des::Error>
+    where Des: Deserializer<'a> {
+        let (real, dual) = <(Quaternion<N>, Quaternion<N>)>::deserialize(deserializer)?;
+
+        Ok(Self::from_real_and_dual(real, dual))
+    }
+}
+
+impl<N: RealField> DualQuaternion<N> {
+    /// Normalizes this dual quaternion.
+    ///
+    /// The result has a unit real part, and a dual part orthogonal to it so that it represents a
+    /// rigid-body motion. Its real part is the normalized real part of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DualQuaternion, Quaternion};
+    /// let real = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let dual = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    /// let dq = DualQuaternion::from_real_and_dual(real, dual);
+    /// let dq_normalized = dq.normalize();
+    ///
+    /// assert_relative_eq!(dq_normalized.real.norm(), 1.0);
+    /// assert_relative_eq!(dq_normalized.real.dot(&dq_normalized.dual), 0.0, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let mut res = *self;
+        let _ = res.normalize_mut();
+        res
+    }
+
+    /// Normalizes this dual quaternion in-place and returns the norm of its real part.
+    ///
+    /// See `.normalize()` for more details.
+    #[inline]
+    pub fn normalize_mut(&mut self) -> N {
+        let norm = self.real.norm();
+        let dot = self.real.dot(&self.dual);
+
+        // Division by the dual number `norm + ε * dot / norm`.
+        self.real /= norm;
+        self.dual /= norm;
+        self.dual -= self.real * (dot / (norm * norm));
+
+        norm
+    }
+
+    /// The conjugate of this dual quaternion, i.e., the dual quaternion made of the conjugates of
+    /// its real and dual parts.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DualQuaternion, Quaternion};
+    /// let real = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let dual = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    /// let dq = DualQuaternion::from_real_and_dual(real, dual);
+    /// let conj = dq.conjugate();
+    ///
+    /// assert_eq!(conj.real, real.conjugate());
+    /// assert_eq!(conj.dual, dual.conjugate());
+    /// ```
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Self::from_real_and_dual(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Replaces this dual quaternion by its conjugate.
+    #[inline]
+    pub fn conjugate_mut(&mut self) {
+        self.real.conjugate_mut();
+        self.dual.conjugate_mut();
+    }
+
+    /// Inverts this dual quaternion if its real part is not zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DualQuaternion, Quaternion};
+    /// let real = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let dual = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    /// let dq = DualQuaternion::from_real_and_dual(real, dual);
+    /// let inv = dq.try_inverse().unwrap();
+    ///
+    /// assert_relative_eq!(inv * dq, DualQuaternion::identity(), epsilon = 1.0e-7);
+    ///
+    /// //Non-invertible case
+    /// let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    /// let dq = DualQuaternion::from_real_and_dual(zero, dual);
+    ///
+    /// assert!(dq.try_inverse().is_none());
+    /// ```
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let mut res = *self;
+
+        if res.try_inverse_mut() {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Inverts this dual quaternion in-place if its real part is not zero.
+    ///
+    /// Returns `false` and leaves `self` unchanged if the real part is zero.
+    #[inline]
+    pub fn try_inverse_mut(&mut self) -> bool {
+        match self.real.try_inverse() {
+            Some(real_inv) => {
+                self.dual = -(real_inv * self.dual * real_inv);
+                self.real = real_inv;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Linear interpolation between two dual quaternions.
+    ///
+    /// Computes `self * (1 - t) + other * t`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DualQuaternion, Quaternion};
+    /// let dq1 = DualQuaternion::from_real_and_dual(
+    ///     Quaternion::new(1.0, 0.0, 0.0, 4.0),
+    ///     Quaternion::new(0.0, 2.0, 0.0, 0.0)
+    /// );
+    /// let dq2 = DualQuaternion::from_real_and_dual(
+    ///     Quaternion::new(2.0, 0.0, 1.0, 0.0),
+    ///     Quaternion::new(0.0, 2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// assert_eq!(dq1.lerp(&dq2, 0.25), DualQuaternion::from_real_and_dual(
+    ///     Quaternion::new(1.25, 0.0, 0.25, 3.0),
+    ///     Quaternion::new(0.0, 2.0, 0.0, 0.0)
+    /// ));
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: N) -> Self {
+        self * (N::one() - t) + other * t
+    }
+}
+
+impl<N: RealField + AbsDiffEq<Epsilon = N>> AbsDiffEq for DualQuaternion<N> {
+    type Epsilon = N;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        (self.real.coords.abs_diff_eq(&other.real.coords, epsilon) &&
+         self.dual.coords.abs_diff_eq(&other.dual.coords, epsilon)) ||
+        // Account for the double-covering of rigid-body motions, i.e. q = -q
+        (self.real.coords.abs_diff_eq(&-other.real.coords, epsilon) &&
+         self.dual.coords.abs_diff_eq(&-other.dual.coords, epsilon))
+    }
+}
+
+impl<N: RealField + RelativeEq<Epsilon = N>> RelativeEq for DualQuaternion<N> {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    {
+        (self.real.coords.relative_eq(&other.real.coords, epsilon, max_relative) &&
+         self.dual.coords.relative_eq(&other.dual.coords, epsilon, max_relative)) ||
+        // Account for the double-covering of rigid-body motions, i.e. q = -q
+        (self.real.coords.relative_eq(&-other.real.coords, epsilon, max_relative) &&
+         self.dual.coords.relative_eq(&-other.dual.coords, epsilon, max_relative))
+    }
+}
+
+impl<N: RealField + UlpsEq<Epsilon = N>> UlpsEq for DualQuaternion<N> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        (self.real.coords.ulps_eq(&other.real.coords, epsilon, max_ulps) &&
+         self.dual.coords.ulps_eq(&other.dual.coords, epsilon, max_ulps)) ||
+        // Account for the double-covering of rigid-body motions, i.e. q = -q
+        (self.real.coords.ulps_eq(&-other.real.coords, epsilon, max_ulps) &&
+         self.dual.coords.ulps_eq(&-other.dual.coords, epsilon, max_ulps))
+    }
+}
+
+impl<N: RealField + fmt::Display> fmt::Display for DualQuaternion<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DualQuaternion real: {} − dual: {}",
+            self.real, self.dual
+        )
+    }
+}
+
+/// A unit dual quaternion. May be used to represent a rigid-body motion, i.e., a rotation followed
+/// by a translation.
+pub type UnitDualQuaternion<N> = Unit<DualQuaternion<N>>;
+
+impl<N: RealField> UnitDualQuaternion<N> {
+    /// The underlying dual quaternion.
+    ///
+    /// Same as `self.as_ref()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DualQuaternion, UnitDualQuaternion};
+    /// let id: UnitDualQuaternion<f64> = UnitDualQuaternion::identity();
+    /// assert_eq!(*id.dual_quaternion(), DualQuaternion::identity());
+    /// ```
+    #[inline]
+    pub fn dual_quaternion(&self) -> &DualQuaternion<N> {
+        self.as_ref()
+    }
+
+    /// Compute the conjugate of this unit dual quaternion.
+    ///
+    /// This is the same as its inverse.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Self::new_unchecked(self.as_ref().conjugate())
+    }
+
+    /// Compute the conjugate of this unit dual quaternion in-place.
+    #[inline]
+    pub fn conjugate_mut(&mut self) {
+        self.as_mut_unchecked().conjugate_mut()
+    }
+
+    /// Inverts this unit dual quaternion.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3};
+    /// let rot = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+    /// let dq = UnitDualQuaternion::from_parts(Translation3::new(1.0, 2.0, 3.0), rot);
+    /// let inv = dq.inverse();
+    ///
+    /// assert_relative_eq!(dq * inv, UnitDualQuaternion::identity(), epsilon = 1.0e-7);
+    /// assert_relative_eq!(inv * dq, UnitDualQuaternion::identity(), epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.conjugate()
+    }
+
+    /// Inverts this unit dual quaternion in-place.
+    #[inline]
+    pub fn inverse_mut(&mut self) {
+        self.conjugate_mut()
+    }
+
+    /// The unit dual quaternion needed to make `self` and `other` coincide.
+    ///
+    /// The result is such that: `self.isometry_to(other) * self == other`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3};
+    /// let dq1 = UnitDualQuaternion::from_parts(Translation3::new(1.0, 0.0, 0.0), UnitQuaternion::identity());
+    /// let dq2 = UnitDualQuaternion::from_parts(Translation3::new(0.0, 2.0, 0.0), UnitQuaternion::from_euler_angles(0.3, 0.0, 0.0));
+    /// let dq_to = dq1.isometry_to(&dq2);
+    ///
+    /// assert_relative_eq!(dq_to * dq1, dq2, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn isometry_to(&self, other: &Self) -> Self {
+        other / self
+    }
+
+    /// Linear interpolation between two unit dual quaternions.
+    ///
+    /// The result is not normalized.
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: N) -> DualQuaternion<N> {
+        self.as_ref().lerp(other.as_ref(), t)
+    }
+
+    /// Normalized linear interpolation between two unit dual quaternions.
+    ///
+    /// This is the same as `self.lerp` except that the result is normalized. This is the blending
+    /// used by dual quaternion skinning: it is cheap, but its velocity is not constant.
+    #[inline]
+    pub fn nlerp(&self, other: &Self, t: N) -> Self {
+        let mut res = self.lerp(other, t);
+        let _ = res.normalize_mut();
+
+        Self::new_unchecked(res)
+    }
+
+    /// Screw linear interpolation between two unit dual quaternions.
+    ///
+    /// The result follows the screw motion, i.e., a simultaneous rotation and translation along
+    /// a fixed axis, that takes `self` to `other` with a constant velocity, taking the shortest
+    /// path.
+    ///
+    /// Panics if the angle of the rotation between both dual quaternions is 180 degrees (in which
+    /// case the interpolation is not well-defined). Use `.try_sclerp` instead to avoid the panic.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3, Vector3};
+    /// let dq1 = UnitDualQuaternion::from_parts(Translation3::new(0.0, 0.0, 0.0), UnitQuaternion::identity());
+    /// let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), f32::consts::FRAC_PI_2);
+    /// let dq2 = UnitDualQuaternion::from_parts(Translation3::new(0.0, 0.0, 4.0), rot);
+    /// let dq = dq1.sclerp(&dq2, 0.5);
+    ///
+    /// assert_relative_eq!(dq.rotation().angle(), f32::consts::FRAC_PI_4, epsilon = 1.0e-6);
+    /// assert_relative_eq!(dq.translation().vector, Vector3::new(0.0, 0.0, 2.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn sclerp(&self, other: &Self, t: N) -> Self {
+        self.try_sclerp(other, t, N::default_epsilon())
+            .expect("DualQuaternion sclerp: ambiguous configuration.")
+    }
+
+    /// Computes the screw linear interpolation between two unit dual quaternions or returns
+    /// `None` if the angle of the rotation between both dual quaternions is approximately 180
+    /// degrees (in which case the interpolation is not well-defined).
+    ///
+    /// # Arguments
+    /// * `self`: the first dual quaternion to interpolate from.
+    /// * `other`: the second dual quaternion to interpolate toward.
+    /// * `t`: the interpolation parameter. Should be between 0 and 1.
+    /// * `epsilon`: the value below which the cosine of half the rotation angle separating both
+    /// dual quaternions must be to return `None`.
+    #[inline]
+    pub fn try_sclerp(&self, other: &Self, t: N, epsilon: N) -> Option<Self> {
+        let two: N = crate::convert(2.0f64);
+        let half: N = crate::convert(0.5f64);
+
+        // The motion from `self` to `other`, choosing the sign that gives the shortest path.
+        let mut diff = self.inverse() * other;
+
+        if diff.as_ref().real.scalar() < N::zero() {
+            diff = -diff;
+        }
+
+        let real = diff.as_ref().real;
+        let dual = diff.as_ref().dual;
+        let scalar = real.scalar();
+
+        if scalar <= epsilon {
+            return None;
+        }
+
+        let sin_squared = real.imag().norm_squared();
+
+        if sin_squared <= N::default_epsilon() * N::default_epsilon() {
+            // Pure translation.
+            let translation = (dual * real.conjugate()).imag() * two;
+            let motion =
+                Self::from_parts(Translation3::from(translation * t), UnitQuaternion::identity());
+            return Some(self * motion);
+        }
+
+        // Screw parameters of the motion: the angle and pitch along the axis `direction` with
+        // moment `moment`.
+        let sin = sin_squared.sqrt();
+        let direction = real.imag() / sin;
+        let mut angle = sin.atan2(scalar) * two;
+        let mut pitch = -two * dual.scalar() / sin;
+        let moment = (dual.imag() - direction * (pitch * half * scalar)) / sin;
+
+        angle *= t;
+        pitch *= t;
+
+        let (sin, cos) = (angle * half).sin_cos();
+        let real = Quaternion::from_parts(cos, direction * sin);
+        let dual = Quaternion::from_parts(
+            -pitch * half * sin,
+            moment * sin + direction * (pitch * half * cos),
+        );
+        let motion = Self::new_unchecked(DualQuaternion::from_real_and_dual(real, dual));
+
+        Some(self * motion)
+    }
+
+    /// The rotation part of this unit dual quaternion.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3};
+    /// let rot = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+    /// let dq = UnitDualQuaternion::from_parts(Translation3::new(1.0, 2.0, 3.0), rot);
+    ///
+    /// assert_relative_eq!(dq.rotation(), rot, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn rotation(&self) -> UnitQuaternion<N> {
+        UnitQuaternion::new_unchecked(self.as_ref().real)
+    }
+
+    /// The translation part of this unit dual quaternion.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3};
+    /// let rot = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+    /// let dq = UnitDualQuaternion::from_parts(Translation3::new(1.0, 2.0, 3.0), rot);
+    ///
+    /// assert_relative_eq!(dq.translation(), Translation3::new(1.0, 2.0, 3.0), epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn translation(&self) -> Translation3<N> {
+        let two: N = crate::convert(2.0f64);
+        let dq = self.as_ref();
+        Translation3::from((dq.dual * dq.real.conjugate()).imag() * two)
+    }
+
+    /// Builds the isometry corresponding to this unit dual quaternion.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, UnitDualQuaternion, UnitQuaternion, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    /// let dq = UnitDualQuaternion::from_isometry(&iso);
+    ///
+    /// assert_relative_eq!(dq.to_isometry(), iso, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn to_isometry(&self) -> Isometry3<N> {
+        Isometry3::from_parts(self.translation(), self.rotation())
+    }
+
+    /// Converts this unit dual quaternion into its equivalent homogeneous transformation matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4, UnitDualQuaternion, UnitQuaternion, Translation3};
+    /// let dq = UnitDualQuaternion::from_parts(Translation3::new(1.0, 3.0, 2.0), UnitQuaternion::identity());
+    /// let expected = Matrix4::new(1.0, 0.0, 0.0, 1.0,
+    ///                             0.0, 1.0, 0.0, 3.0,
+    ///                             0.0, 0.0, 1.0, 2.0,
+    ///                             0.0, 0.0, 0.0, 1.0);
+    ///
+    /// assert_relative_eq!(dq.to_homogeneous(), expected, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn to_homogeneous(&self) -> Matrix4<N> {
+        self.to_isometry().to_homogeneous()
+    }
+
+    /// Transforms the given point by this unit dual quaternion.
+    ///
+    /// This is the same as the multiplication `self * pt`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3, Vector3, Point3};
+    /// let rot = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), f32::consts::FRAC_PI_2);
+    /// let dq = UnitDualQuaternion::from_parts(Translation3::new(1.0, 0.0, 0.0), rot);
+    /// let transformed_point = dq.transform_point(&Point3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_relative_eq!(transformed_point, Point3::new(4.0, 2.0, -1.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, pt: &Point3<N>) -> Point3<N> {
+        self * pt
+    }
+
+    /// Transforms the given vector by this unit dual quaternion, ignoring its translational part.
+    ///
+    /// This is the same as the multiplication `self * v`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3, Vector3};
+    /// let rot = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), f32::consts::FRAC_PI_2);
+    /// let dq = UnitDualQuaternion::from_parts(Translation3::new(1.0, 0.0, 0.0), rot);
+    /// let transformed_vector = dq.transform_vector(&Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_relative_eq!(transformed_vector, Vector3::new(3.0, 2.0, -1.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn transform_vector(&self, v: &Vector3<N>) -> Vector3<N> {
+        self * v
+    }
+
+    /// Transforms the given point by the inverse of this unit dual quaternion. This may be cheaper
+    /// than inverting the unit dual quaternion and transforming the point.
+    #[inline]
+    pub fn inverse_transform_point(&self, pt: &Point3<N>) -> Point3<N> {
+        let rot = self.rotation();
+        Point3::from(rot.inverse_transform_vector(&(pt.coords - self.translation().vector)))
+    }
+
+    /// Transforms the given vector by the inverse of this unit dual quaternion, ignoring its
+    /// translational part. This may be cheaper than inverting the unit dual quaternion and
+    /// transforming the vector.
+    #[inline]
+    pub fn inverse_transform_vector(&self, v: &Vector3<N>) -> Vector3<N> {
+        self.rotation().inverse_transform_vector(v)
+    }
+}
+
+impl<N: RealField + fmt::Display> fmt::Display for UnitDualQuaternion<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let translation = self.translation().vector;
+        write!(
+            f,
+            "UnitDualQuaternion translation: ({}, {}, {}) − rotation: {}",
+            translation[0],
+            translation[1],
+            translation[2],
+            self.rotation()
+        )
+    }
+}
+
+impl<N: RealField + AbsDiffEq<Epsilon = N>> AbsDiffEq for UnitDualQuaternion<N> {
+    type Epsilon = N;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.as_ref().abs_diff_eq(other.as_ref(), epsilon)
+    }
+}
+
+impl<N: RealField + RelativeEq<Epsilon = N>> RelativeEq for UnitDualQuaternion<N> {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    {
+        self.as_ref()
+            .relative_eq(other.as_ref(), epsilon, max_relative)
+    }
+}
+
+impl<N: RealField + UlpsEq<Epsilon = N>> UlpsEq for UnitDualQuaternion<N> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.as_ref().ulps_eq(other.as_ref(), epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,280 @@
+use num::Zero;
+
+use alga::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive, Id, Identity, Module,
+    Multiplicative, RealField, TwoSidedInverse,
+};
+use alga::linear::{
+    AffineTransformation, DirectIsometry, Isometry, NormedSpace, ProjectiveTransformation,
+    Similarity, Transformation, VectorSpace,
+};
+
+use crate::base::Vector3;
+use crate::geometry::{DualQuaternion, Point3, Translation3, UnitDualQuaternion, UnitQuaternion};
+
+impl<N: RealField> Identity<Multiplicative> for DualQuaternion<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::identity()
+    }
+}
+
+impl<N: RealField> Identity<Additive> for DualQuaternion<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<N: RealField> AbstractMagma<Multiplicative> for DualQuaternion<N> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
+
+impl<N: RealField> AbstractMagma<Additive> for DualQuaternion<N> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+}
+
+impl<N: RealField> TwoSidedInverse<Additive> for DualQuaternion<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+macro_rules! impl_structures(
+    ($DualQuaternion: ident; $($marker: ident<$operator: ident>),* $(,)*) => {$(
+        impl<N: RealField> $marker<$operator> for $DualQuaternion<N> { }
+    )*}
+);
+
+impl_structures!(
+    DualQuaternion;
+    AbstractSemigroup<Multiplicative>,
+    AbstractMonoid<Multiplicative>,
+
+    AbstractSemigroup<Additive>,
+    AbstractQuasigroup<Additive>,
+    AbstractMonoid<Additive>,
+    AbstractLoop<Additive>,
+    AbstractGroup<Additive>,
+    AbstractGroupAbelian<Additive>
+);
+
+/*
+ *
+ * Vector space.
+ *
+ */
+impl<N: RealField> AbstractModule for DualQuaternion<N> {
+    type AbstractRing = N;
+
+    #[inline]
+    fn multiply_by(&self, n: N) -> Self {
+        self * n
+    }
+}
+
+impl<N: RealField> Module for DualQuaternion<N> {
+    type Ring = N;
+}
+
+impl<N: RealField> VectorSpace for DualQuaternion<N> {
+    type Field = N;
+}
+
+// NOTE: this is the norm of the real part only, so that unit dual quaternions are the ones that
+// represent rigid-body motions.
+impl<N: RealField> NormedSpace for DualQuaternion<N> {
+    type RealField = N;
+    type ComplexField = N;
+
+    #[inline]
+    fn norm_squared(&self) -> N {
+        self.real.norm_squared()
+    }
+
+    #[inline]
+    fn norm(&self) -> N {
+        self.real.norm()
+    }
+
+    #[inline]
+    fn normalize(&self) -> Self {
+        self.normalize()
+    }
+
+    #[inline]
+    fn normalize_mut(&mut self) -> N {
+        self.normalize_mut()
+    }
+
+    #[inline]
+    fn try_normalize(&self, min_norm: N) -> Option<Self> {
+        let mut res = *self;
+        res.try_normalize_mut(min_norm).map(|_| res)
+    }
+
+    #[inline]
+    fn try_normalize_mut(&mut self, min_norm: N) -> Option<N> {
+        if self.real.norm() <= min_norm {
+            None
+        } else {
+            Some(self.normalize_mut())
+        }
+    }
+}
+
+/*
+ *
+ * Implementations for UnitDualQuaternion.
+ *
+ */
+impl<N: RealField> Identity<Multiplicative> for UnitDualQuaternion<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::identity()
+    }
+}
+
+impl<N: RealField> AbstractMagma<Multiplicative> for UnitDualQuaternion<N> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
+
+impl<N: RealField> TwoSidedInverse<Multiplicative> for UnitDualQuaternion<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inverse()
+    }
+
+    #[inline]
+    fn two_sided_inverse_mut(&mut self) {
+        self.inverse_mut()
+    }
+}
+
+impl_structures!(
+    UnitDualQuaternion;
+    AbstractSemigroup<Multiplicative>,
+    AbstractQuasigroup<Multiplicative>,
+    AbstractMonoid<Multiplicative>,
+    AbstractLoop<Multiplicative>,
+    AbstractGroup<Multiplicative>
+);
+
+/*
+ *
+ * Transformation groups.
+ *
+ */
+impl<N: RealField> Transformation<Point3<N>> for UnitDualQuaternion<N> {
+    #[inline]
+    fn transform_point(&self, pt: &Point3<N>) -> Point3<N> {
+        self.transform_point(pt)
+    }
+
+    #[inline]
+    fn transform_vector(&self, v: &Vector3<N>) -> Vector3<N> {
+        self.transform_vector(v)
+    }
+}
+
+impl<N: RealField> ProjectiveTransformation<Point3<N>> for UnitDualQuaternion<N> {
+    #[inline]
+    fn inverse_transform_point(&self, pt: &Point3<N>) -> Point3<N> {
+        self.inverse_transform_point(pt)
+    }
+
+    #[inline]
+    fn inverse_transform_vector(&self, v: &Vector3<N>) -> Vector3<N> {
+        self.inverse_transform_vector(v)
+    }
+}
+
+impl<N: RealField> AffineTransformation<Point3<N>> for UnitDualQuaternion<N> {
+    type Rotation = UnitQuaternion<N>;
+    type NonUniformScaling = Id;
+    type Translation = Translation3<N>;
+
+    #[inline]
+    fn decompose(&self) -> (Translation3<N>, UnitQuaternion<N>, Id, UnitQuaternion<N>) {
+        (
+            self.translation(),
+            self.rotation(),
+            Id::new(),
+            UnitQuaternion::identity(),
+        )
+    }
+
+    #[inline]
+    fn append_translation(&self, t: &Self::Translation) -> Self {
+        t * self
+    }
+
+    #[inline]
+    fn prepend_translation(&self, t: &Self::Translation) -> Self {
+        self * t
+    }
+
+    #[inline]
+    fn append_rotation(&self, r: &Self::Rotation) -> Self {
+        r * self
+    }
+
+    #[inline]
+    fn prepend_rotation(&self, r: &Self::Rotation) -> Self {
+        self * r
+    }
+
+    #[inline]
+    fn append_scaling(&self, _: &Self::NonUniformScaling) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn prepend_scaling(&self, _: &Self::NonUniformScaling) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn append_rotation_wrt_point(&self, r: &Self::Rotation, p: &Point3<N>) -> Option<Self> {
+        let shift = Translation3::from(p.coords);
+        Some(shift * r * shift.inverse() * self)
+    }
+}
+
+impl<N: RealField> Similarity<Point3<N>> for UnitDualQuaternion<N> {
+    type Scaling = Id;
+
+    #[inline]
+    fn translation(&self) -> Translation3<N> {
+        self.translation()
+    }
+
+    #[inline]
+    fn rotation(&self) -> UnitQuaternion<N> {
+        self.rotation()
+    }
+
+    #[inline]
+    fn scaling(&self) -> Id {
+        Id::new()
+    }
+}
+
+macro_rules! marker_impl(
+    ($($Trait: ident),*) => {$(
+        impl<N: RealField> $Trait<Point3<N>> for UnitDualQuaternion<N> { }
+    )*}
+);
+
+marker_impl!(Isometry, DirectIsometry);
@@ -0,0 +1,204 @@
+#[cfg(feature = "arbitrary")]
+use crate::base::dimension::{U3, U4};
+#[cfg(feature = "arbitrary")]
+use crate::base::storage::Owned;
+#[cfg(feature = "arbitrary")]
+use quickcheck::{Arbitrary, Gen};
+
+use num::{One, Zero};
+use rand::distributions::{Distribution, OpenClosed01, Standard};
+use rand::Rng;
+
+use alga::general::RealField;
+
+use crate::geometry::{
+    DualQuaternion, Isometry3, Quaternion, Translation3, UnitDualQuaternion, UnitQuaternion,
+};
+
+impl<N: RealField> DualQuaternion<N> {
+    /// Creates a dual quaternion from its real and dual parts.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DualQuaternion, Quaternion};
+    /// let real = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let dual = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    /// let dq = DualQuaternion::from_real_and_dual(real, dual);
+    ///
+    /// assert_eq!(dq.real.w, 1.0);
+    /// assert_eq!(dq.dual.w, 5.0);
+    /// ```
+    #[inline]
+    pub fn from_real_and_dual(real: Quaternion<N>, dual: Quaternion<N>) -> Self {
+        Self { real, dual }
+    }
+
+    /// Creates a dual quaternion from its real part only, i.e., with a zero dual part.
+    #[inline]
+    pub fn from_real(real: Quaternion<N>) -> Self {
+        Self::from_real_and_dual(real, Quaternion::zero())
+    }
+
+    /// The dual quaternion multiplicative identity.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DualQuaternion, Quaternion};
+    /// let dq1 = DualQuaternion::identity();
+    /// let dq2 = DualQuaternion::from_real_and_dual(
+    ///     Quaternion::new(1.0, 2.0, 3.0, 4.0),
+    ///     Quaternion::new(5.0, 6.0, 7.0, 8.0)
+    /// );
+    ///
+    /// assert_eq!(dq1 * dq2, dq2);
+    /// assert_eq!(dq2 * dq1, dq2);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_real(Quaternion::identity())
+    }
+}
+
+impl<N: RealField> One for DualQuaternion<N> {
+    #[inline]
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl<N: RealField> Zero for DualQuaternion<N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::from_real_and_dual(Quaternion::zero(), Quaternion::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.dual.is_zero()
+    }
+}
+
+impl<N: RealField> Distribution<DualQuaternion<N>> for Standard
+where Standard: Distribution<N>
+{
+    #[inline]
+    fn sample<'a, R: Rng + ?Sized>(&self, rng: &'a mut R) -> DualQuaternion<N> {
+        DualQuaternion::from_real_and_dual(rng.gen::<Quaternion<N>>(), rng.gen::<Quaternion<N>>())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<N: RealField + Arbitrary> Arbitrary for DualQuaternion<N>
+where Owned<N, U4>: Send
+{
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Self::from_real_and_dual(Quaternion::arbitrary(g), Quaternion::arbitrary(g))
+    }
+}
+
+impl<N: RealField> UnitDualQuaternion<N> {
+    /// The unit dual quaternion representing the identity rigid-body motion.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3, Point3};
+    /// let dq1 = UnitDualQuaternion::identity();
+    /// let dq2 = UnitDualQuaternion::from_parts(Translation3::new(1.0, 2.0, 3.0), UnitQuaternion::identity());
+    /// let p = Point3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(dq1 * dq2, dq2);
+    /// assert_eq!(dq2 * dq1, dq2);
+    /// assert_eq!(dq1 * p, p);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new_unchecked(DualQuaternion::identity())
+    }
+
+    /// Creates a unit dual quaternion from a translation and a rotation.
+    ///
+    /// The result represents the rigid-body motion that applies the rotation first, and then the
+    /// translation.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Translation3, Vector3, Point3};
+    /// let tra = Translation3::new(0.0, 0.0, 3.0);
+    /// let rot = UnitQuaternion::from_scaled_axis(Vector3::y() * f32::consts::PI);
+    /// let dq = UnitDualQuaternion::from_parts(tra, rot);
+    ///
+    /// assert_relative_eq!(dq * Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 2.0, 0.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn from_parts(translation: Translation3<N>, rotation: UnitQuaternion<N>) -> Self {
+        let half: N = crate::convert(0.5f64);
+        let real = rotation.into_inner();
+        let dual = Quaternion::from_imag(translation.vector * half) * real;
+
+        Self::new_unchecked(DualQuaternion::from_real_and_dual(real, dual))
+    }
+
+    /// Creates a unit dual quaternion representing a pure rotation.
+    #[inline]
+    pub fn from_rotation(rotation: UnitQuaternion<N>) -> Self {
+        Self::new_unchecked(DualQuaternion::from_real(rotation.into_inner()))
+    }
+
+    /// Creates a unit dual quaternion representing a pure translation.
+    #[inline]
+    pub fn from_translation(translation: Translation3<N>) -> Self {
+        Self::from_parts(translation, UnitQuaternion::identity())
+    }
+
+    /// Creates a unit dual quaternion from an isometry.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, UnitDualQuaternion, Vector3, Point3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    /// let dq = UnitDualQuaternion::from_isometry(&iso);
+    /// let p = Point3::new(4.0, 5.0, 6.0);
+    ///
+    /// assert_relative_eq!(dq * p, iso * p, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn from_isometry(isometry: &Isometry3<N>) -> Self {
+        Self::from_parts(isometry.translation, isometry.rotation)
+    }
+}
+
+impl<N: RealField> One for UnitDualQuaternion<N> {
+    #[inline]
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl<N: RealField> Distribution<UnitDualQuaternion<N>> for Standard
+where
+    OpenClosed01: Distribution<N>,
+    Standard: Distribution<N>,
+{
+    /// Generate a unit dual quaternion with a uniformly distributed random rotation, and a random
+    /// translation.
+    #[inline]
+    fn sample<'a, R: Rng + ?Sized>(&self, rng: &'a mut R) -> UnitDualQuaternion<N> {
+        UnitDualQuaternion::from_parts(rng.gen::<Translation3<N>>(), rng.gen::<UnitQuaternion<N>>())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<N: RealField + Arbitrary> Arbitrary for UnitDualQuaternion<N>
+where
+    Owned<N, U4>: Send,
+    Owned<N, U3>: Send,
+{
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Self::from_parts(Translation3::arbitrary(g), UnitQuaternion::arbitrary(g))
+    }
+}
@@ -0,0 +1,224 @@
+use alga::general::{RealField, SubsetOf, SupersetOf};
+use alga::linear::Rotation as AlgaRotation;
+
+use crate::base::dimension::U3;
+use crate::base::Matrix4;
+use crate::geometry::{
+    DualQuaternion, Isometry, Isometry3, Point3, Quaternion, Similarity, SuperTCategoryOf, TAffine,
+    Transform, Translation3, UnitDualQuaternion, UnitQuaternion,
+};
+
+/*
+ * This file provides the following conversions:
+ * =============================================
+ *
+ * DualQuaternion     -> DualQuaternion
+ * UnitDualQuaternion -> UnitDualQuaternion
+ * UnitDualQuaternion -> Isometry<U3>
+ * UnitDualQuaternion -> Similarity<U3>
+ * UnitDualQuaternion -> Transform<U3>
+ * UnitDualQuaternion -> Matrix<U4> (homogeneous)
+ * UnitQuaternion     -> UnitDualQuaternion
+ *
+ * Isometry<U3>       -> UnitDualQuaternion
+ * Translation<U3>    -> UnitDualQuaternion
+ *
+ * NOTE:
+ * UnitDualQuaternion -> DualQuaternion is already provided by: Unit<T> -> T
+ */
+
+impl<N1, N2> SubsetOf<DualQuaternion<N2>> for DualQuaternion<N1>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+{
+    #[inline]
+    fn to_superset(&self) -> DualQuaternion<N2> {
+        DualQuaternion::from_real_and_dual(self.real.to_superset(), self.dual.to_superset())
+    }
+
+    #[inline]
+    fn is_in_subset(dq: &DualQuaternion<N2>) -> bool {
+        crate::is_convertible::<_, Quaternion<N1>>(&dq.real)
+            && crate::is_convertible::<_, Quaternion<N1>>(&dq.dual)
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(dq: &DualQuaternion<N2>) -> Self {
+        Self::from_real_and_dual(
+            dq.real.to_subset_unchecked(),
+            dq.dual.to_subset_unchecked(),
+        )
+    }
+}
+
+impl<N1, N2> SubsetOf<UnitDualQuaternion<N2>> for UnitDualQuaternion<N1>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+{
+    #[inline]
+    fn to_superset(&self) -> UnitDualQuaternion<N2> {
+        UnitDualQuaternion::new_unchecked(self.as_ref().to_superset())
+    }
+
+    #[inline]
+    fn is_in_subset(dq: &UnitDualQuaternion<N2>) -> bool {
+        crate::is_convertible::<_, DualQuaternion<N1>>(dq.as_ref())
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(dq: &UnitDualQuaternion<N2>) -> Self {
+        Self::new_unchecked(crate::convert_ref_unchecked(dq.as_ref()))
+    }
+}
+
+impl<N1, N2, R> SubsetOf<Isometry<N2, U3, R>> for UnitDualQuaternion<N1>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+    R: AlgaRotation<Point3<N2>> + SupersetOf<UnitQuaternion<N1>>,
+{
+    #[inline]
+    fn to_superset(&self) -> Isometry<N2, U3, R> {
+        Isometry::from_parts(
+            crate::convert_ref(&self.translation()),
+            crate::convert_ref(&self.rotation()),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(iso: &Isometry<N2, U3, R>) -> bool {
+        crate::is_convertible::<_, Translation3<N1>>(&iso.translation)
+            && crate::is_convertible::<_, UnitQuaternion<N1>>(&iso.rotation)
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(iso: &Isometry<N2, U3, R>) -> Self {
+        Self::from_parts(
+            crate::convert_ref_unchecked(&iso.translation),
+            crate::convert_ref_unchecked(&iso.rotation),
+        )
+    }
+}
+
+impl<N1, N2, R> SubsetOf<Similarity<N2, U3, R>> for UnitDualQuaternion<N1>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+    R: AlgaRotation<Point3<N2>> + SupersetOf<UnitQuaternion<N1>>,
+{
+    #[inline]
+    fn to_superset(&self) -> Similarity<N2, U3, R> {
+        Similarity::from_isometry(crate::convert_ref(self), N2::one())
+    }
+
+    #[inline]
+    fn is_in_subset(sim: &Similarity<N2, U3, R>) -> bool {
+        crate::is_convertible::<_, Self>(&sim.isometry) && sim.scaling() == N2::one()
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(sim: &Similarity<N2, U3, R>) -> Self {
+        crate::convert_ref_unchecked(&sim.isometry)
+    }
+}
+
+impl<N1, N2, C> SubsetOf<Transform<N2, U3, C>> for UnitDualQuaternion<N1>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+    C: SuperTCategoryOf<TAffine>,
+{
+    #[inline]
+    fn to_superset(&self) -> Transform<N2, U3, C> {
+        Transform::from_matrix_unchecked(self.to_homogeneous().to_superset())
+    }
+
+    #[inline]
+    fn is_in_subset(t: &Transform<N2, U3, C>) -> bool {
+        <Self as SubsetOf<_>>::is_in_subset(t.matrix())
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(t: &Transform<N2, U3, C>) -> Self {
+        Self::from_superset_unchecked(t.matrix())
+    }
+}
+
+impl<N1: RealField, N2: RealField + SupersetOf<N1>> SubsetOf<Matrix4<N2>>
+    for UnitDualQuaternion<N1>
+{
+    #[inline]
+    fn to_superset(&self) -> Matrix4<N2> {
+        self.to_homogeneous().to_superset()
+    }
+
+    #[inline]
+    fn is_in_subset(m: &Matrix4<N2>) -> bool {
+        crate::is_convertible::<_, Isometry3<N1>>(m)
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(m: &Matrix4<N2>) -> Self {
+        let iso: Isometry3<N1> = crate::convert_ref_unchecked(m);
+        Self::from_isometry(&iso)
+    }
+}
+
+impl<N1, N2> SubsetOf<UnitDualQuaternion<N2>> for UnitQuaternion<N1>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+{
+    #[inline]
+    fn to_superset(&self) -> UnitDualQuaternion<N2> {
+        UnitDualQuaternion::from_rotation(self.to_superset())
+    }
+
+    #[inline]
+    fn is_in_subset(dq: &UnitDualQuaternion<N2>) -> bool {
+        dq.as_ref().dual.coords.iter().all(|e| e.is_zero())
+            && crate::is_convertible::<_, Quaternion<N1>>(&dq.as_ref().real)
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(dq: &UnitDualQuaternion<N2>) -> Self {
+        Self::new_unchecked(dq.as_ref().real.to_subset_unchecked())
+    }
+}
+
+impl<N: RealField> From<UnitDualQuaternion<N>> for Matrix4<N> {
+    #[inline]
+    fn from(dq: UnitDualQuaternion<N>) -> Self {
+        dq.to_homogeneous()
+    }
+}
+
+impl<N: RealField> From<UnitDualQuaternion<N>> for Isometry3<N> {
+    #[inline]
+    fn from(dq: UnitDualQuaternion<N>) -> Self {
+        dq.to_isometry()
+    }
+}
+
+impl<N: RealField> From<Isometry3<N>> for UnitDualQuaternion<N> {
+    #[inline]
+    fn from(iso: Isometry3<N>) -> Self {
+        Self::from_isometry(&iso)
+    }
+}
+
+impl<N: RealField> From<UnitQuaternion<N>> for UnitDualQuaternion<N> {
+    #[inline]
+    fn from(rotation: UnitQuaternion<N>) -> Self {
+        Self::from_rotation(rotation)
+    }
+}
+
+impl<N: RealField> From<Translation3<N>> for UnitDualQuaternion<N> {
+    #[inline]
+    fn from(translation: Translation3<N>) -> Self {
+        Self::from_translation(translation)
+    }
+}
@@ -0,0 +1,415 @@
+/*
+ * This file provides:
+ * ===================
+ *
+ *
+ * (DualQuaternion)
+ *
+ * DualQuaternion × DualQuaternion
+ * DualQuaternion + DualQuaternion
+ * DualQuaternion - DualQuaternion
+ * -DualQuaternion
+ * DualQuaternion × Scalar
+ * DualQuaternion ÷ Scalar
+ * Scalar × DualQuaternion
+ *
+ * (Unit DualQuaternion)
+ * UnitDualQuaternion × UnitDualQuaternion
+ * UnitDualQuaternion ÷ UnitDualQuaternion
+ *
+ * UnitDualQuaternion × UnitQuaternion     -> UnitDualQuaternion
+ * UnitQuaternion     × UnitDualQuaternion -> UnitDualQuaternion
+ * UnitDualQuaternion × Translation        -> UnitDualQuaternion
+ * Translation        × UnitDualQuaternion -> UnitDualQuaternion
+ * UnitDualQuaternion × Isometry           -> UnitDualQuaternion
+ * Isometry           × UnitDualQuaternion -> UnitDualQuaternion
+ *
+ * UnitDualQuaternion × Point
+ * UnitDualQuaternion × Vector
+ * UnitDualQuaternion × Unit<Vector>
+ *
+ * NOTE: -UnitDualQuaternion is already provided by `Unit<T>`.
+ *
+ *
+ * (Assignment Operators)
+ *
+ * DualQuaternion ×= Scalar
+ * DualQuaternion ÷= Scalar
+ * DualQuaternion ×= DualQuaternion
+ * DualQuaternion += DualQuaternion
+ * DualQuaternion -= DualQuaternion
+ *
+ * UnitDualQuaternion ×= UnitDualQuaternion
+ * UnitDualQuaternion ÷= UnitDualQuaternion
+ * UnitDualQuaternion ×= UnitQuaternion
+ * UnitDualQuaternion ×= Translation
+ *
+ */
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use alga::general::RealField;
+
+use crate::base::{Unit, Vector3};
+
+use crate::geometry::{
+    DualQuaternion, Isometry3, Point3, Translation3, UnitDualQuaternion, UnitQuaternion,
+};
+
+macro_rules! dual_quaternion_op_impl(
+    ($Op: ident, $op: ident;
+     $lhs: ident: $Lhs: ty, $rhs: ident: $Rhs: ty, Output = $Output: ty;
+     $action: expr; $($lives: tt),*) => {
+        impl<$($lives ,)* N: RealField> $Op<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn $op($lhs, $rhs: $Rhs) -> Self::Output {
+                $action
+            }
+        }
+    }
+);
+
+macro_rules! dual_quaternion_op_impl_all(
+    ($Op: ident, $op: ident;
+     $lhs: ident: $Lhs: ty, $rhs: ident: $Rhs: ty, Output = $Output: ty;
+     [val val] => $action_val_val: expr;
+     [ref val] => $action_ref_val: expr;
+     [val ref] => $action_val_ref: expr;
+     [ref ref] => $action_ref_ref: expr;) => {
+        dual_quaternion_op_impl!(
+            $Op, $op;
+            $lhs: $Lhs, $rhs: $Rhs, Output = $Output;
+            $action_val_val; );
+
+        dual_quaternion_op_impl!(
+            $Op, $op;
+            $lhs: &'a $Lhs, $rhs: $Rhs, Output = $Output;
+            $action_ref_val; 'a);
+
+        dual_quaternion_op_impl!(
+            $Op, $op;
+            $lhs: $Lhs, $rhs: &'b $Rhs, Output = $Output;
+            $action_val_ref; 'b);
+
+        dual_quaternion_op_impl!(
+            $Op, $op;
+            $lhs: &'a $Lhs, $rhs: &'b $Rhs, Output = $Output;
+            $action_ref_ref; 'a, 'b);
+    }
+);
+
+macro_rules! dual_quaternion_op_assign_impl_all(
+    ($OpAssign: ident, $op_assign: ident;
+     $lhs: ident: $Lhs: ty, $rhs: ident: $Rhs: ty;
+     [val] => $action_val: expr;
+     [ref] => $action_ref: expr;) => {
+        impl<N: RealField> $OpAssign<$Rhs> for $Lhs {
+            #[inline]
+            fn $op_assign(&mut $lhs, $rhs: $Rhs) {
+                $action_val
+            }
+        }
+
+        impl<'b, N: RealField> $OpAssign<&'b $Rhs> for $Lhs {
+            #[inline]
+            fn $op_assign(&mut $lhs, $rhs: &'b $Rhs) {
+                $action_ref
+            }
+        }
+    }
+);
+
+// DualQuaternion + DualQuaternion
+dual_quaternion_op_impl_all!(
+    Add, add;
+    self: DualQuaternion<N>, rhs: DualQuaternion<N>, Output = DualQuaternion<N>;
+    [val val] => &self + &rhs;
+    [ref val] => self + &rhs;
+    [val ref] => &self + rhs;
+    [ref ref] => DualQuaternion::from_real_and_dual(self.real + rhs.real, self.dual + rhs.dual);
+);
+
+// DualQuaternion - DualQuaternion
+dual_quaternion_op_impl_all!(
+    Sub, sub;
+    self: DualQuaternion<N>, rhs: DualQuaternion<N>, Output = DualQuaternion<N>;
+    [val val] => &self - &rhs;
+    [ref val] => self - &rhs;
+    [val ref] => &self - rhs;
+    [ref ref] => DualQuaternion::from_real_and_dual(self.real - rhs.real, self.dual - rhs.dual);
+);
+
+// DualQuaternion × DualQuaternion
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: DualQuaternion<N>, rhs: DualQuaternion<N>, Output = DualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => DualQuaternion::from_real_and_dual(
+        self.real * rhs.real,
+        self.real * rhs.dual + self.dual * rhs.real,
+    );
+);
+
+// UnitDualQuaternion × UnitDualQuaternion
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitDualQuaternion<N>, rhs: UnitDualQuaternion<N>, Output = UnitDualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => UnitDualQuaternion::new_unchecked(self.dual_quaternion() * rhs.dual_quaternion());
+);
+
+// UnitDualQuaternion ÷ UnitDualQuaternion
+dual_quaternion_op_impl_all!(
+    Div, div;
+    self: UnitDualQuaternion<N>, rhs: UnitDualQuaternion<N>, Output = UnitDualQuaternion<N>;
+    [val val] => self * rhs.inverse();
+    [ref val] => self * rhs.inverse();
+    [val ref] => self * rhs.inverse();
+    [ref ref] => self * rhs.inverse();
+);
+
+// UnitDualQuaternion × UnitQuaternion
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitDualQuaternion<N>, rhs: UnitQuaternion<N>, Output = UnitDualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => UnitDualQuaternion::new_unchecked(
+        self.dual_quaternion() * DualQuaternion::from_real(rhs.into_inner())
+    );
+);
+
+// UnitQuaternion × UnitDualQuaternion
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitQuaternion<N>, rhs: UnitDualQuaternion<N>, Output = UnitDualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => UnitDualQuaternion::new_unchecked(
+        DualQuaternion::from_real(self.into_inner()) * rhs.dual_quaternion()
+    );
+);
+
+// UnitDualQuaternion × Translation
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitDualQuaternion<N>, rhs: Translation3<N>, Output = UnitDualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => self * UnitDualQuaternion::from_translation(rhs.clone());
+);
+
+// Translation × UnitDualQuaternion
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: Translation3<N>, rhs: UnitDualQuaternion<N>, Output = UnitDualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => UnitDualQuaternion::from_translation(self.clone()) * rhs;
+);
+
+// UnitDualQuaternion × Isometry
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitDualQuaternion<N>, rhs: Isometry3<N>, Output = UnitDualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => self * UnitDualQuaternion::from_isometry(rhs);
+);
+
+// Isometry × UnitDualQuaternion
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: Isometry3<N>, rhs: UnitDualQuaternion<N>, Output = UnitDualQuaternion<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => UnitDualQuaternion::from_isometry(self) * rhs;
+);
+
+// UnitDualQuaternion × Vector
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitDualQuaternion<N>, rhs: Vector3<N>, Output = Vector3<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => UnitQuaternion::new_unchecked(self.as_ref().real) * rhs;
+);
+
+// UnitDualQuaternion × Point
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitDualQuaternion<N>, rhs: Point3<N>, Output = Point3<N>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => {
+        let two: N = crate::convert(2.0f64);
+        let dq = self.as_ref();
+        let shift = (dq.dual * dq.real.conjugate()).imag() * two;
+
+        Point3::from(UnitQuaternion::new_unchecked(dq.real) * rhs.coords + shift)
+    };
+);
+
+// UnitDualQuaternion × Unit<Vector>
+dual_quaternion_op_impl_all!(
+    Mul, mul;
+    self: UnitDualQuaternion<N>, rhs: Unit<Vector3<N>>, Output = Unit<Vector3<N>>;
+    [val val] => &self * &rhs;
+    [ref val] => self * &rhs;
+    [val ref] => &self * rhs;
+    [ref ref] => Unit::new_unchecked(self * rhs.as_ref());
+);
+
+macro_rules! scalar_op_impl(
+    ($($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident);* $(;)*) => {$(
+        impl<N: RealField> $Op<N> for DualQuaternion<N> {
+            type Output = DualQuaternion<N>;
+
+            #[inline]
+            fn $op(self, n: N) -> Self::Output {
+                DualQuaternion::from_real_and_dual(self.real.$op(n), self.dual.$op(n))
+            }
+        }
+
+        impl<'a, N: RealField> $Op<N> for &'a DualQuaternion<N> {
+            type Output = DualQuaternion<N>;
+
+            #[inline]
+            fn $op(self, n: N) -> Self::Output {
+                DualQuaternion::from_real_and_dual(self.real.$op(n), self.dual.$op(n))
+            }
+        }
+
+        impl<N: RealField> $OpAssign<N> for DualQuaternion<N> {
+
+            #[inline]
+            fn $op_assign(&mut self, n: N) {
+                self.real.$op_assign(n);
+                self.dual.$op_assign(n);
+            }
+        }
+    )*}
+);
+
+scalar_op_impl!(
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+);
+
+macro_rules! left_scalar_mul_impl(
+    ($($T: ty),* $(,)*) => {$(
+        impl Mul<DualQuaternion<$T>> for $T {
+            type Output = DualQuaternion<$T>;
+
+            #[inline]
+            fn mul(self, right: DualQuaternion<$T>) -> Self::Output {
+                right * self
+            }
+        }
+
+        impl<'b> Mul<&'b DualQuaternion<$T>> for $T {
+            type Output = DualQuaternion<$T>;
+
+            #[inline]
+            fn mul(self, right: &'b DualQuaternion<$T>) -> Self::Output {
+                right * self
+            }
+        }
+    )*}
+);
+
+left_scalar_mul_impl!(f32, f64);
+
+impl<N: RealField> Neg for DualQuaternion<N> {
+    type Output = DualQuaternion<N>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DualQuaternion::from_real_and_dual(-self.real, -self.dual)
+    }
+}
+
+impl<'a, N: RealField> Neg for &'a DualQuaternion<N> {
+    type Output = DualQuaternion<N>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DualQuaternion::from_real_and_dual(-self.real, -self.dual)
+    }
+}
+
+// DualQuaternion += DualQuaternion
+dual_quaternion_op_assign_impl_all!(
+    AddAssign, add_assign;
+    self: DualQuaternion<N>, rhs: DualQuaternion<N>;
+    [val] => *self += &rhs;
+    [ref] => {
+        self.real += rhs.real;
+        self.dual += rhs.dual;
+    };
+);
+
+// DualQuaternion -= DualQuaternion
+dual_quaternion_op_assign_impl_all!(
+    SubAssign, sub_assign;
+    self: DualQuaternion<N>, rhs: DualQuaternion<N>;
+    [val] => *self -= &rhs;
+    [ref] => {
+        self.real -= rhs.real;
+        self.dual -= rhs.dual;
+    };
+);
+
+// DualQuaternion ×= DualQuaternion
+dual_quaternion_op_assign_impl_all!(
+    MulAssign, mul_assign;
+    self: DualQuaternion<N>, rhs: DualQuaternion<N>;
+    [val] => *self *= &rhs;
+    [ref] => *self = &*self * rhs;
+);
+
+// UnitDualQuaternion ×= UnitDualQuaternion
+// UnitDualQuaternion ÷= UnitDualQuaternion
+dual_quaternion_op_assign_impl_all!(
+    MulAssign, mul_assign;
+    self: UnitDualQuaternion<N>, rhs: UnitDualQuaternion<N>;
+    [val] => *self *= &rhs;
+    [ref] => *self = &*self * rhs;
+);
+
+dual_quaternion_op_assign_impl_all!(
+    DivAssign, div_assign;
+    self: UnitDualQuaternion<N>, rhs: UnitDualQuaternion<N>;
+    [val] => *self /= &rhs;
+    [ref] => *self = &*self / rhs;
+);
+
+// UnitDualQuaternion ×= UnitQuaternion
+dual_quaternion_op_assign_impl_all!(
+    MulAssign, mul_assign;
+    self: UnitDualQuaternion<N>, rhs: UnitQuaternion<N>;
+    [val] => *self *= &rhs;
+    [ref] => *self = &*self * rhs;
+);
+
+// UnitDualQuaternion ×= Translation
+dual_quaternion_op_assign_impl_all!(
+    MulAssign, mul_assign;
+    self: UnitDualQuaternion<N>, rhs: Translation3<N>;
+    [val] => *self *= &rhs;
+    [ref] => *self = &*self * rhs;
+);
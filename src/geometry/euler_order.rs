@@ -0,0 +1,185 @@
+use alga::general::RealField;
+
+use crate::base::Matrix3;
+
+/// A sequence of three rotation axes used to parametrize a 3D rotation with Euler angles.
+///
+/// The first six sequences involve three distinct axes and are usually called Tait–Bryan angles.
+/// The last six sequences have the same first and third axis and are usually called proper Euler
+/// angles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerAxes {
+    /// Rotations around the `x`, `y`, then `z` axis.
+    XYZ,
+    /// Rotations around the `x`, `z`, then `y` axis.
+    XZY,
+    /// Rotations around the `y`, `x`, then `z` axis.
+    YXZ,
+    /// Rotations around the `y`, `z`, then `x` axis.
+    YZX,
+    /// Rotations around the `z`, `x`, then `y` axis.
+    ZXY,
+    /// Rotations around the `z`, `y`, then `x` axis.
+    ZYX,
+    /// Rotations around the `x`, `y`, then `x` axis.
+    XYX,
+    /// Rotations around the `x`, `z`, then `x` axis.
+    XZX,
+    /// Rotations around the `y`, `x`, then `y` axis.
+    YXY,
+    /// Rotations around the `y`, `z`, then `y` axis.
+    YZY,
+    /// Rotations around the `z`, `x`, then `z` axis.
+    ZXZ,
+    /// Rotations around the `z`, `y`, then `z` axis.
+    ZYZ,
+}
+
+impl EulerAxes {
+    /// The indices of the three rotation axes of this sequence, `0` being the `x` axis.
+    #[inline]
+    pub fn indices(self) -> [usize; 3] {
+        match self {
+            EulerAxes::XYZ => [0, 1, 2],
+            EulerAxes::XZY => [0, 2, 1],
+            EulerAxes::YXZ => [1, 0, 2],
+            EulerAxes::YZX => [1, 2, 0],
+            EulerAxes::ZXY => [2, 0, 1],
+            EulerAxes::ZYX => [2, 1, 0],
+            EulerAxes::XYX => [0, 1, 0],
+            EulerAxes::XZX => [0, 2, 0],
+            EulerAxes::YXY => [1, 0, 1],
+            EulerAxes::YZY => [1, 2, 1],
+            EulerAxes::ZXZ => [2, 0, 2],
+            EulerAxes::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Whether this sequence involves three distinct axes, i.e., parametrizes Tait–Bryan angles.
+    #[inline]
+    pub fn is_tait_bryan(self) -> bool {
+        let [i, _, k] = self.indices();
+        i != k
+    }
+}
+
+/// A convention of Euler angles: a sequence of rotation axes that are either fixed or attached
+/// to the rotating body.
+///
+/// For a sequence of axes `(i, j, k)` and angles `(a, b, c)`:
+///
+/// * `EulerOrder::Extrinsic` rotates by `a` around the fixed axis `i`, then by `b` around the fixed
+///   axis `j`, then by `c` around the fixed axis `k`. The resulting rotation is
+///   `R_k(c) * R_j(b) * R_i(a)`.
+/// * `EulerOrder::Intrinsic` rotates by `a` around the axis `i`, then by `b` around the rotated axis
+///   `j`, then by `c` around the twice-rotated axis `k`. The resulting rotation is
+///   `R_i(a) * R_j(b) * R_k(c)`.
+///
+/// Thus, the intrinsic sequence `(i, j, k)` with angles `(a, b, c)` is the same rotation as the
+/// extrinsic sequence `(k, j, i)` with angles `(c, b, a)`. The roll-pitch-yaw convention used by
+/// `Rotation3::from_euler_angles` is `EulerOrder::Extrinsic(EulerAxes::XYZ)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// Rotations around axes fixed in space.
+    Extrinsic(EulerAxes),
+    /// Rotations around axes attached to the rotating body.
+    Intrinsic(EulerAxes),
+}
+
+impl EulerOrder {
+    /// The sequence of rotation axes of this convention.
+    #[inline]
+    pub fn axes(self) -> EulerAxes {
+        match self {
+            EulerOrder::Extrinsic(axes) | EulerOrder::Intrinsic(axes) => axes,
+        }
+    }
+
+    /// Whether the rotation axes of this convention are attached to the rotating body.
+    #[inline]
+    pub fn is_intrinsic(self) -> bool {
+        match self {
+            EulerOrder::Extrinsic(_) => false,
+            EulerOrder::Intrinsic(_) => true,
+        }
+    }
+
+    /// The axis indices of the equivalent extrinsic sequence, in the order the rotations are
+    /// applied.
+    #[inline]
+    pub(crate) fn extrinsic_indices(self) -> [usize; 3] {
+        let [i, j, k] = self.axes().indices();
+
+        if self.is_intrinsic() {
+            [k, j, i]
+        } else {
+            [i, j, k]
+        }
+    }
+
+    /// Reorders angles given for this convention into angles of the equivalent extrinsic sequence,
+    /// and conversely.
+    #[inline]
+    pub(crate) fn reorder_angles<N: RealField>(self, angles: (N, N, N)) -> (N, N, N) {
+        if self.is_intrinsic() {
+            (angles.2, angles.1, angles.0)
+        } else {
+            angles
+        }
+    }
+}
+
+/// The rotation matrix of angle `angle` around the `axis`-th canonical axis.
+#[inline]
+pub(crate) fn elementary_rotation<N: RealField>(axis: usize, angle: N) -> Matrix3<N> {
+    let (sin, cos) = angle.sin_cos();
+    let j = (axis + 1) % 3;
+    let k = (axis + 2) % 3;
+
+    let mut res = Matrix3::identity();
+    res[(j, j)] = cos;
+    res[(j, k)] = -sin;
+    res[(k, j)] = sin;
+    res[(k, k)] = cos;
+
+    res
+}
+
+/// Extracts the angles `(a, b, c)` of the extrinsic sequence `axes` from a rotation matrix.
+///
+/// In case of gimbal lock, the third angle is set to zero.
+pub(crate) fn extrinsic_euler_angles<N: RealField>(m: &Matrix3<N>, axes: [usize; 3]) -> (N, N, N) {
+    let [i, j, k] = axes;
+    let eps = N::default_epsilon().sqrt();
+
+    if i != k {
+        // Tait–Bryan angles: m = R_k(c) * R_j(b) * R_i(a).
+        let sign = if j == (i + 1) % 3 { N::one() } else { -N::one() };
+        let cos_b = (m[(i, i)] * m[(i, i)] + m[(j, i)] * m[(j, i)]).sqrt();
+        let b = (-sign * m[(k, i)]).atan2(cos_b);
+
+        if cos_b > eps {
+            let a = (sign * m[(k, j)]).atan2(m[(k, k)]);
+            let c = (sign * m[(j, i)]).atan2(m[(i, i)]);
+            (a, b, c)
+        } else {
+            let a = (-sign * m[(j, k)]).atan2(m[(j, j)]);
+            (a, b, N::zero())
+        }
+    } else {
+        // Proper Euler angles: m = R_i(c) * R_j(b) * R_i(a).
+        let k = 3 - i - j;
+        let sign = if j == (i + 1) % 3 { N::one() } else { -N::one() };
+        let sin_b = (m[(i, j)] * m[(i, j)] + m[(i, k)] * m[(i, k)]).sqrt();
+        let b = sin_b.atan2(m[(i, i)]);
+
+        if sin_b > eps {
+            let a = m[(i, j)].atan2(sign * m[(i, k)]);
+            let c = m[(j, i)].atan2(-sign * m[(k, i)]);
+            (a, b, c)
+        } else {
+            let a = (-sign * m[(j, k)]).atan2(m[(j, j)]);
+            (a, b, N::zero())
+        }
+    }
+}
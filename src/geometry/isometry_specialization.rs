@@ -0,0 +1,457 @@
+use alga::general::RealField;
+
+use crate::base::dimension::{U1, U2, U3};
+use crate::base::{Matrix2, Matrix3, Matrix4, Matrix6, Vector3, Vector6};
+
+use crate::geometry::registration::total_weight;
+use crate::geometry::rotation_specialization::{
+    angle_minus_sin_over_cubed_angle, is_small_angle, one_minus_cos_over_squared_angle,
+};
+use crate::geometry::{
+    Isometry2, Isometry3, Rotation3, Translation2, Translation3, UnitComplex, UnitQuaternion,
+};
+
+/*
+ *
+ * Lie group SE(2).
+ *
+ * Twists are 3-dimensional vectors `[v_x, v_y, w]` where `[v_x, v_y]` is the translational part
+ * and `w` the rotation angle.
+ *
+ */
+impl<N: RealField> Isometry2<N> {
+    /// Builds the isometry that is the exponential of the given twist.
+    ///
+    /// The first two components of `twist` are its translational part, and its last component is
+    /// the rotation angle.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry2, Point2, Vector3};
+    /// // A quarter turn around the point (1.0, 0.0).
+    /// let twist = Vector3::new(0.0, -f32::consts::FRAC_PI_2, f32::consts::FRAC_PI_2);
+    /// let iso = Isometry2::exp(&twist);
+    ///
+    /// assert_relative_eq!(iso * Point2::new(1.0, 0.0), Point2::new(1.0, 0.0), epsilon = 1.0e-6);
+    /// assert_relative_eq!(iso * Point2::new(2.0, 0.0), Point2::new(1.0, 1.0), epsilon = 1.0e-6);
+    /// assert_relative_eq!(iso.ln(), twist, epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn exp(twist: &Vector3<N>) -> Self {
+        let angle = twist[2];
+        let translation = se2_v_matrix(angle) * twist.fixed_rows::<U2>(0);
+
+        Self::from_parts(Translation2::from(translation), UnitComplex::new(angle))
+    }
+
+    /// The twist that is the logarithm of this isometry.
+    ///
+    /// This is the inverse of `Isometry2::exp`. The rotation angle of the result is in `]-pi, pi]`.
+    #[inline]
+    pub fn ln(&self) -> Vector3<N> {
+        let angle = self.rotation.angle();
+        let v = se2_v_matrix_inverse(angle) * self.translation.vector;
+
+        Vector3::new(v.x, v.y, angle)
+    }
+
+    /// The matrix of the Lie algebra se(2) associated to the given twist.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Isometry2, Matrix3, Vector3};
+    /// let twist = Vector3::new(1.0, 2.0, 3.0);
+    /// let expected = Matrix3::new(0.0, -3.0, 1.0,
+    ///                             3.0,  0.0, 2.0,
+    ///                             0.0,  0.0, 0.0);
+    ///
+    /// assert_eq!(Isometry2::hat(&twist), expected);
+    /// assert_eq!(Isometry2::vee(&expected), twist);
+    /// ```
+    #[inline]
+    pub fn hat(twist: &Vector3<N>) -> Matrix3<N> {
+        let mut res = Matrix3::zeros();
+        res[(0, 1)] = -twist[2];
+        res[(1, 0)] = twist[2];
+        res[(0, 2)] = twist[0];
+        res[(1, 2)] = twist[1];
+
+        res
+    }
+
+    /// The twist encoded by the given element of the Lie algebra se(2).
+    ///
+    /// This is the inverse of `Isometry2::hat`. Only the lower-left component of the rotational
+    /// part of `m` is read.
+    #[inline]
+    pub fn vee(m: &Matrix3<N>) -> Vector3<N> {
+        Vector3::new(m[(0, 2)], m[(1, 2)], m[(1, 0)])
+    }
+
+    /// The adjoint matrix of this isometry.
+    ///
+    /// This is the matrix that maps a twist `xi` to the twist of `self * Isometry2::exp(&xi) *
+    /// self.inverse()`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry2, Vector2, Vector3};
+    /// let iso = Isometry2::new(Vector2::new(1.0, 2.0), 0.4);
+    /// let twist = Vector3::new(0.3, -0.2, 0.5);
+    ///
+    /// assert_relative_eq!(
+    ///     Isometry2::exp(&(iso.adjoint() * twist)),
+    ///     iso * Isometry2::exp(&twist) * iso.inverse(),
+    ///     epsilon = 1.0e-7
+    /// );
+    /// ```
+    #[inline]
+    pub fn adjoint(&self) -> Matrix3<N> {
+        let mut res = self.rotation.to_homogeneous();
+        res[(0, 2)] = self.translation.vector.y;
+        res[(1, 2)] = -self.translation.vector.x;
+
+        res
+    }
+
+    /// The left Jacobian of SE(2) at the given twist.
+    ///
+    /// This is the matrix `J` such that, for a small `delta`,
+    /// `Isometry2::exp(&(twist + delta)) ≈ Isometry2::exp(&(J * delta)) * Isometry2::exp(&twist)`.
+    #[inline]
+    pub fn left_jacobian(twist: &Vector3<N>) -> Matrix3<N> {
+        let angle = twist[2];
+        let a = angle_minus_sin_over_cubed_angle(angle) * angle;
+        let b = one_minus_cos_over_squared_angle(angle);
+
+        let mut res = Matrix3::identity();
+        res.fixed_slice_mut::<U2, U2>(0, 0)
+            .copy_from(&se2_v_matrix(angle));
+        res[(0, 2)] = a * twist[0] + b * twist[1];
+        res[(1, 2)] = a * twist[1] - b * twist[0];
+
+        res
+    }
+
+    /// The right Jacobian of SE(2) at the given twist.
+    ///
+    /// This is the matrix `J` such that, for a small `delta`,
+    /// `Isometry2::exp(&(twist + delta)) ≈ Isometry2::exp(&twist) * Isometry2::exp(&(J * delta))`.
+    /// It is equal to `Isometry2::left_jacobian(&-twist)`.
+    #[inline]
+    pub fn right_jacobian(twist: &Vector3<N>) -> Matrix3<N> {
+        Self::left_jacobian(&-twist)
+    }
+
+    /// The inverse of the left Jacobian of SE(2) at the given twist.
+    ///
+    /// The result is not defined if the rotation angle of `twist` is a non-zero multiple of `2 * pi`.
+    #[inline]
+    pub fn left_jacobian_inverse(twist: &Vector3<N>) -> Matrix3<N> {
+        let jac = Self::left_jacobian(twist);
+        let inv_v = se2_v_matrix_inverse(twist[2]);
+        let t = -(inv_v * jac.fixed_slice::<U2, U1>(0, 2));
+
+        let mut res = Matrix3::identity();
+        res.fixed_slice_mut::<U2, U2>(0, 0).copy_from(&inv_v);
+        res.fixed_slice_mut::<U2, U1>(0, 2).copy_from(&t);
+
+        res
+    }
+
+    /// The inverse of the right Jacobian of SE(2) at the given twist.
+    ///
+    /// It is equal to `Isometry2::left_jacobian_inverse(&-twist)`.
+    #[inline]
+    pub fn right_jacobian_inverse(twist: &Vector3<N>) -> Matrix3<N> {
+        Self::left_jacobian_inverse(&-twist)
+    }
+}
+
+/// The matrix `V` such that the translational part of `Isometry2::exp(&[v; angle])` is `V * v`.
+#[inline]
+fn se2_v_matrix<N: RealField>(angle: N) -> Matrix2<N> {
+    // sin(angle) / angle = 1 - angle² * (angle - sin(angle)) / angle³
+    let a = N::one() - angle_minus_sin_over_cubed_angle(angle) * angle * angle;
+    let b = one_minus_cos_over_squared_angle(angle) * angle;
+
+    Matrix2::new(a, -b, b, a)
+}
+
+/// The inverse of `se2_v_matrix(angle)`.
+#[inline]
+fn se2_v_matrix_inverse<N: RealField>(angle: N) -> Matrix2<N> {
+    let a = N::one() - angle_minus_sin_over_cubed_angle(angle) * angle * angle;
+    let b = one_minus_cos_over_squared_angle(angle) * angle;
+    // a² + b² = 2 * (1 - cos(angle)) / angle²
+    let det = one_minus_cos_over_squared_angle(angle) * crate::convert(2.0);
+
+    Matrix2::new(a, b, -b, a) / det
+}
+
+/*
+ *
+ * Lie group SE(3).
+ *
+ * Twists are 6-dimensional vectors `[v; w]` where `v` is the translational part and `w` the
+ * rotation axis multiplied by the rotation angle.
+ *
+ */
+impl<N: RealField> Isometry3<N> {
+    /// Builds the isometry that is the exponential of the given twist.
+    ///
+    /// The first three components of `twist` are its translational part, and its last three
+    /// components are the rotation axis multiplied by the rotation angle.
+    ///
+    /// Contrary to `Isometry3::new`, the translational part of the result is not the translational
+    /// part of `twist` unless the rotation is the identity.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry3, Point3, Vector6};
+    /// // A screw motion with a quarter turn around the `z` axis, along which it translates by 2.0.
+    /// let twist = Vector6::new(0.0, 0.0, 2.0, 0.0, 0.0, f32::consts::FRAC_PI_2);
+    /// let iso = Isometry3::exp(&twist);
+    ///
+    /// assert_relative_eq!(iso * Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 2.0), epsilon = 1.0e-6);
+    /// assert_relative_eq!(iso.ln(), twist, epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn exp(twist: &Vector6<N>) -> Self {
+        let v = twist.fixed_rows::<U3>(0);
+        let w = twist.fixed_rows::<U3>(3).into_owned();
+        let translation = Rotation3::left_jacobian(&w) * v;
+
+        Self::from_parts(
+            Translation3::from(translation),
+            UnitQuaternion::from_scaled_axis(w),
+        )
+    }
+
+    /// The twist that is the logarithm of this isometry.
+    ///
+    /// This is the inverse of `Isometry3::exp`. The rotation angle of the result is in `[0, pi]`.
+    #[inline]
+    pub fn ln(&self) -> Vector6<N> {
+        let w = self.rotation.scaled_axis();
+        let v = Rotation3::left_jacobian_inverse(&w) * self.translation.vector;
+
+        Vector6::new(v.x, v.y, v.z, w.x, w.y, w.z)
+    }
+
+    /// The matrix of the Lie algebra se(3) associated to the given twist.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Isometry3, Matrix4, Vector6};
+    /// let twist = Vector6::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    /// let expected = Matrix4::new( 0.0, -6.0,  5.0, 1.0,
+    ///                              6.0,  0.0, -4.0, 2.0,
+    ///                             -5.0,  4.0,  0.0, 3.0,
+    ///                              0.0,  0.0,  0.0, 0.0);
+    ///
+    /// assert_eq!(Isometry3::hat(&twist), expected);
+    /// assert_eq!(Isometry3::vee(&expected), twist);
+    /// ```
+    #[inline]
+    pub fn hat(twist: &Vector6<N>) -> Matrix4<N> {
+        let w = twist.fixed_rows::<U3>(3).into_owned();
+        let mut res = Matrix4::zeros();
+        res.fixed_slice_mut::<U3, U3>(0, 0)
+            .copy_from(&w.cross_matrix());
+        res.fixed_slice_mut::<U3, U1>(0, 3)
+            .copy_from(&twist.fixed_rows::<U3>(0));
+
+        res
+    }
+
+    /// The twist encoded by the given element of the Lie algebra se(3).
+    ///
+    /// This is the inverse of `Isometry3::hat`. Only the lower-triangular part of the rotational part
+    /// of `m` is read.
+    #[inline]
+    pub fn vee(m: &Matrix4<N>) -> Vector6<N> {
+        Vector6::new(
+            m[(0, 3)],
+            m[(1, 3)],
+            m[(2, 3)],
+            m[(2, 1)],
+            -m[(2, 0)],
+            m[(1, 0)],
+        )
+    }
+
+    /// The adjoint matrix of this isometry.
+    ///
+    /// This is the matrix that maps a twist `xi` to the twist of `self * Isometry3::exp(&xi) *
+    /// self.inverse()`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector3, Vector6};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    /// let twist = Vector6::new(0.3, -0.2, 0.5, 0.4, 0.0, -0.1);
+    ///
+    /// assert_relative_eq!(
+    ///     Isometry3::exp(&(iso.adjoint() * twist)),
+    ///     iso * Isometry3::exp(&twist) * iso.inverse(),
+    ///     epsilon = 1.0e-7
+    /// );
+    /// ```
+    #[inline]
+    pub fn adjoint(&self) -> Matrix6<N> {
+        let rot = self.rotation.to_rotation_matrix().into_inner();
+        let mut res = Matrix6::zeros();
+        res.fixed_slice_mut::<U3, U3>(0, 0).copy_from(&rot);
+        res.fixed_slice_mut::<U3, U3>(3, 3).copy_from(&rot);
+        res.fixed_slice_mut::<U3, U3>(0, 3)
+            .copy_from(&(self.translation.vector.cross_matrix() * rot));
+
+        res
+    }
+
+    /// The left Jacobian of SE(3) at the given twist.
+    ///
+    /// This is the matrix `J` such that, for a small `delta`,
+    /// `Isometry3::exp(&(twist + delta)) ≈ Isometry3::exp(&(J * delta)) * Isometry3::exp(&twist)`.
+    #[inline]
+    pub fn left_jacobian(twist: &Vector6<N>) -> Matrix6<N> {
+        let w = twist.fixed_rows::<U3>(3).into_owned();
+        let jac = Rotation3::left_jacobian(&w);
+
+        let mut res = Matrix6::zeros();
+        res.fixed_slice_mut::<U3, U3>(0, 0).copy_from(&jac);
+        res.fixed_slice_mut::<U3, U3>(3, 3).copy_from(&jac);
+        res.fixed_slice_mut::<U3, U3>(0, 3)
+            .copy_from(&se3_q_matrix(twist));
+
+        res
+    }
+
+    /// The right Jacobian of SE(3) at the given twist.
+    ///
+    /// This is the matrix `J` such that, for a small `delta`,
+    /// `Isometry3::exp(&(twist + delta)) ≈ Isometry3::exp(&twist) * Isometry3::exp(&(J * delta))`.
+    /// It is equal to `Isometry3::left_jacobian(&-twist)`.
+    #[inline]
+    pub fn right_jacobian(twist: &Vector6<N>) -> Matrix6<N> {
+        Self::left_jacobian(&-twist)
+    }
+
+    /// The inverse of the left Jacobian of SE(3) at the given twist.
+    ///
+    /// The result is not defined if the rotation angle of `twist` is a non-zero multiple of `2 * pi`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Matrix6, Vector6};
+    /// let twist = Vector6::new(0.3, -0.2, 0.5, 0.4, 1.0, -0.1);
+    /// let jac = Isometry3::left_jacobian(&twist);
+    /// let inv = Isometry3::left_jacobian_inverse(&twist);
+    ///
+    /// assert_relative_eq!(jac * inv, Matrix6::identity(), epsilon = 1.0e-9);
+    /// ```
+    #[inline]
+    pub fn left_jacobian_inverse(twist: &Vector6<N>) -> Matrix6<N> {
+        let w = twist.fixed_rows::<U3>(3).into_owned();
+        let inv = Rotation3::left_jacobian_inverse(&w);
+
+        let mut res = Matrix6::zeros();
+        res.fixed_slice_mut::<U3, U3>(0, 0).copy_from(&inv);
+        res.fixed_slice_mut::<U3, U3>(3, 3).copy_from(&inv);
+        res.fixed_slice_mut::<U3, U3>(0, 3)
+            .copy_from(&-(inv * se3_q_matrix(twist) * inv));
+
+        res
+    }
+
+    /// The inverse of the right Jacobian of SE(3) at the given twist.
+    ///
+    /// It is equal to `Isometry3::left_jacobian_inverse(&-twist)`.
+    #[inline]
+    pub fn right_jacobian_inverse(twist: &Vector6<N>) -> Matrix6<N> {
+        Self::left_jacobian_inverse(&-twist)
+    }
+
+    /// The weighted average of the given isometries.
+    ///
+    /// The rotational part of the result is the average of the rotational parts computed by
+    /// `UnitQuaternion::mean`, and its translational part is the weighted average of the
+    /// translational parts.
+    ///
+    /// If `weights` is `None`, all isometries have a weight equal to 1. Returns `None` if
+    /// `isometries` is empty, if `weights` does not have the same length as `isometries`, if a
+    /// weight is negative, or if all the weights are zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector3};
+    /// let iso1 = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.2));
+    /// let iso2 = Isometry3::new(Vector3::new(0.0, 4.0, 0.0), Vector3::new(0.0, 0.0, 0.8));
+    ///
+    /// let mean = Isometry3::mean(&[iso1, iso2], Some(&[3.0, 1.0])).unwrap();
+    /// assert_relative_eq!(mean.translation.vector, Vector3::new(0.75, 1.0, 0.0), epsilon = 1.0e-7);
+    /// // The rotation angle is close to the weighted average of the angles.
+    /// assert_relative_eq!(mean.rotation.angle(), 0.35, epsilon = 1.0e-2);
+    /// ```
+    pub fn mean(isometries: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        let total_weight = total_weight(isometries.len(), weights)?;
+        let rotation = UnitQuaternion::mean_unchecked(isometries.iter().map(|iso| iso.rotation), weights);
+        let mut translation = Vector3::zeros();
+
+        for (i, iso) in isometries.iter().enumerate() {
+            let weight = weights.map_or(N::one(), |w| w[i]);
+            translation.axpy(weight, &iso.translation.vector, N::one());
+        }
+
+        Some(Self::from_parts(Translation3::from(translation / total_weight), rotation))
+    }
+}
+
+/// The upper-right block of the left Jacobian of SE(3).
+///
+/// See "State Estimation for Robotics", T. D. Barfoot, eq. 7.86b.
+fn se3_q_matrix<N: RealField>(twist: &Vector6<N>) -> Matrix3<N> {
+    let vx = twist.fixed_rows::<U3>(0).cross_matrix();
+    let wx = twist.fixed_rows::<U3>(3).cross_matrix();
+    let angle = twist.fixed_rows::<U3>(3).norm();
+
+    let (c2, c3) = if is_small_angle(angle) {
+        let sq = angle * angle;
+        (
+            N::one() / crate::convert(24.0) - sq / crate::convert(720.0)
+                + sq * sq / crate::convert(40320.0),
+            N::one() / crate::convert(120.0) - sq / crate::convert(2520.0)
+                + sq * sq / crate::convert(120960.0),
+        )
+    } else {
+        let (sin, cos) = angle.sin_cos();
+        let sq = angle * angle;
+        let two: N = crate::convert(2.0);
+        let three: N = crate::convert(3.0);
+        (
+            (sq + two * cos - two) / (two * sq * sq),
+            (two * angle - three * sin + angle * cos) / (two * sq * sq * angle),
+        )
+    };
+    let c1 = angle_minus_sin_over_cubed_angle(angle);
+
+    let wv = wx * vx;
+    let vw = vx * wx;
+    let wvw = wv * wx;
+    let wwv = wx * wv;
+    let vww = vw * wx;
+
+    vx * crate::convert::<_, N>(0.5)
+        + (wv + vw + wvw) * c1
+        + (wwv + vww - wvw * crate::convert::<_, N>(3.0)) * c2
+        + (wvw * wx + wx * wvw) * c3
+}
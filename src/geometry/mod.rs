@@ -26,6 +26,12 @@ mod quaternion_conversion;
 mod quaternion_coordinates;
 mod quaternion_ops;
 
+mod dual_quaternion;
+mod dual_quaternion_alga;
+mod dual_quaternion_construction;
+mod dual_quaternion_conversion;
+mod dual_quaternion_ops;
+
 mod unit_complex;
 mod unit_complex_alga;
 mod unit_complex_construction;
@@ -46,6 +52,7 @@ mod isometry_alias;
 mod isometry_construction;
 mod isometry_conversion;
 mod isometry_ops;
+mod isometry_specialization;
 
 mod similarity;
 mod similarity_alga;
@@ -64,6 +71,9 @@ mod transform_conversion;
 mod transform_ops;
 
 mod reflection;
+mod registration;
+
+mod euler_order;
 
 mod orthographic;
 mod perspective;
@@ -76,6 +86,8 @@ pub use self::rotation_alias::*;
 
 pub use self::quaternion::*;
 
+pub use self::dual_quaternion::*;
+
 pub use self::unit_complex::*;
 
 pub use self::translation::*;
@@ -91,6 +103,9 @@ pub use self::transform::*;
 pub use self::transform_alias::*;
 
 pub use self::reflection::*;
+pub use self::registration::*;
+
+pub use self::euler_order::*;
 
 pub use self::orthographic::Orthographic3;
 pub use self::perspective::Perspective3;
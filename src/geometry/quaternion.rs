@@ -19,7 +19,7 @@ use crate::base::dimension::{U1, U3, U4};
 use crate::base::storage::{CStride, RStride};
 use crate::base::{Matrix3, Matrix4, MatrixSlice, MatrixSliceMut, Unit, Vector3, Vector4};
 
-use crate::geometry::{Point3, Rotation};
+use crate::geometry::{EulerOrder, Point3, Rotation};
 
 /// A quaternion. See the type alias `UnitQuaternion = Unit<Quaternion>` for a quaternion
 /// that may be used as a rotation.
@@ -1318,6 +1318,28 @@ impl<N: RealField> UnitQuaternion<N> {
         self.to_rotation_matrix().euler_angles()
     }
 
+    /// Retrieves the Euler angles `(a, b, c)` of this unit quaternion following the given
+    /// convention.
+    ///
+    /// See `Rotation3::euler_angles_ordered` for the range of the returned angles and the handling
+    /// of gimbal lock.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, EulerAxes, EulerOrder};
+    /// let order = EulerOrder::Extrinsic(EulerAxes::YZY);
+    /// let rot = UnitQuaternion::from_euler_angles_ordered(0.1, 0.2, 0.3, order);
+    /// let (a, b, c) = rot.euler_angles_ordered(order);
+    /// assert_relative_eq!(a, 0.1, epsilon = 1.0e-6);
+    /// assert_relative_eq!(b, 0.2, epsilon = 1.0e-6);
+    /// assert_relative_eq!(c, 0.3, epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn euler_angles_ordered(&self, order: EulerOrder) -> (N, N, N) {
+        self.to_rotation_matrix().euler_angles_ordered(order)
+    }
+
     /// Converts this unit quaternion into its equivalent homogeneous transformation matrix.
     ///
     /// # Example
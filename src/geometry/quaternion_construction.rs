@@ -13,9 +13,10 @@ use alga::general::RealField;
 
 use crate::base::dimension::U3;
 use crate::base::storage::Storage;
-use crate::base::{Unit, Vector, Vector3, Vector4, Matrix3};
+use crate::base::{Unit, Vector, Vector3, Vector4, Matrix3, Matrix4};
 
-use crate::geometry::{Quaternion, Rotation3, UnitQuaternion};
+use crate::geometry::registration::total_weight;
+use crate::geometry::{EulerOrder, Quaternion, Rotation3, UnitQuaternion};
 
 impl<N: RealField> Quaternion<N> {
     /// Creates a quaternion from a 4D vector. The quaternion scalar part corresponds to the `w`
@@ -239,6 +240,37 @@ impl<N: RealField> UnitQuaternion<N> {
         Self::new_unchecked(q)
     }
 
+    /// Creates a new unit quaternion from Euler angles following the given convention.
+    ///
+    /// See the documentation of `EulerOrder` for the meaning of the angles `(a, b, c)`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, EulerAxes, EulerOrder, Vector3};
+    /// let order = EulerOrder::Intrinsic(EulerAxes::ZXZ);
+    /// let rot = UnitQuaternion::from_euler_angles_ordered(0.1, 0.2, 0.3, order);
+    /// let expected = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.1)
+    ///     * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.2)
+    ///     * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.3);
+    ///
+    /// assert_relative_eq!(rot, expected, epsilon = 1.0e-6);
+    /// ```
+    pub fn from_euler_angles_ordered(a: N, b: N, c: N, order: EulerOrder) -> Self {
+        let [i, j, k] = order.extrinsic_indices();
+        let (a, b, c) = order.reorder_angles((a, b, c));
+        let elementary_rotation = |axis: usize, angle: N| {
+            let (sin, cos) = (angle * crate::convert(0.5f64)).sin_cos();
+            let mut imag = Vector3::zeros();
+            imag[axis] = sin;
+            Quaternion::from_parts(cos, imag)
+        };
+
+        Self::new_unchecked(
+            elementary_rotation(k, c) * elementary_rotation(j, b) * elementary_rotation(i, a),
+        )
+    }
+
     /// Builds an unit quaternion from a rotation matrix.
     ///
     /// # Example
@@ -328,6 +360,62 @@ impl<N: RealField> UnitQuaternion<N> {
         Rotation3::from_matrix_eps(m, eps, max_iter, guess).into()
     }
 
+    /// The weighted average of the given unit quaternions.
+    ///
+    /// This implements "Averaging Quaternions" by Markley et al.: the result is the unit quaternion
+    /// `q` maximizing `Σ w_i (q · q_i)²`, i.e., the eigenvector associated to the largest eigenvalue
+    /// of `Σ w_i q_i q_iᵀ`. It minimizes the weighted sum of squared chordal distances between the
+    /// attitude matrices, and does not depend on the signs of the input quaternions. The sign of the
+    /// result is chosen so that its dot product with the first quaternion is non-negative.
+    ///
+    /// If `weights` is `None`, all quaternions have a weight equal to 1. Returns `None` if
+    /// `quaternions` is empty, if `weights` does not have the same length as `quaternions`, if a
+    /// weight is negative, or if all the weights are zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let q1 = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.2);
+    /// let q2 = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.6);
+    /// let q3 = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 1.0);
+    ///
+    /// // The sign of each quaternion does not matter.
+    /// let mean = UnitQuaternion::mean(&[q1, q2, -q3], None).unwrap();
+    /// assert_relative_eq!(mean, q2, epsilon = 1.0e-7);
+    ///
+    /// let mean = UnitQuaternion::mean(&[q1, q3], Some(&[1.0, 1.0])).unwrap();
+    /// assert_relative_eq!(mean, q1.slerp(&q3, 0.5), epsilon = 1.0e-7);
+    /// ```
+    pub fn mean(quaternions: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        let _ = total_weight(quaternions.len(), weights)?;
+        Some(Self::mean_unchecked(quaternions.iter().cloned(), weights))
+    }
+
+    // Same as `mean` but on an iterator, assuming `weights` has already been validated.
+    pub(crate) fn mean_unchecked<I>(quaternions: I, weights: Option<&[N]>) -> Self
+    where I: Iterator<Item = Self> {
+        let mut m = Matrix4::zeros();
+        let mut first = None;
+
+        for (i, q) in quaternions.enumerate() {
+            let weight = weights.map_or(N::one(), |w| w[i]);
+            m.ger(weight, &q.coords, &q.coords, N::one());
+            first = first.or(Some(q));
+        }
+
+        let eigen = m.symmetric_eigen();
+        let mut res = eigen.eigenvectors.column(eigen.eigenvalues.imax()).into_owned();
+
+        if let Some(first) = first {
+            if res.dot(&first.coords) < N::zero() {
+                res.neg_mut();
+            }
+        }
+
+        Self::new_normalize(Quaternion::from(res))
+    }
+
     /// The unit quaternion needed to make `a` and `b` be collinear and point toward the same
     /// direction.
     ///
@@ -0,0 +1,291 @@
+use alga::general::RealField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixN, VectorN};
+use crate::dimension::{DimDiff, DimMin, DimName, DimSub, U1};
+
+use crate::geometry::{Isometry, Point, Rotation, Similarity, Translation};
+
+/// Computes the rotation `R` minimizing the weighted sum of squared distances `Σ w_i |R * from_i - to_i|²`.
+///
+/// This is the Kabsch algorithm: the optimal rotation is computed from the SVD of the
+/// cross-covariance matrix of both point sets, and is guaranteed to be a proper rotation, i.e., a
+/// reflection is never returned even if it would fit the points better. The rotation is around the
+/// origin: use `fit_isometry` to also estimate a translation.
+///
+/// Returns the optimal rotation together with the weighted root-mean-square deviation
+/// `sqrt(Σ w_i |R * from_i - to_i|² / Σ w_i)` of the aligned points. If `weights` is `None`,
+/// all points have a weight equal to 1.
+///
+/// Returns `None` if `from` is empty, if `from`, `to` and `weights` do not have the same length,
+/// if a weight is negative, or if all the weights are zero.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Point3, Rotation3, Vector3};
+/// let rot = Rotation3::from_axis_angle(&Vector3::y_axis(), 1.2);
+/// let from = [Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 0.0, 1.0), Point3::new(0.0, 4.0, -2.0)];
+/// let to: Vec<_> = from.iter().map(|p| rot * p).collect();
+///
+/// let (fitted, rmsd) = nalgebra::fit_rotation(&from, &to, None).unwrap();
+/// assert_relative_eq!(fitted, rot, epsilon = 1.0e-7);
+/// assert_relative_eq!(rmsd, 0.0, epsilon = 1.0e-7);
+/// ```
+pub fn fit_rotation<N, D>(
+    from: &[Point<N, D>],
+    to: &[Point<N, D>],
+    weights: Option<&[N]>,
+) -> Option<(Rotation<N, D>, N)>
+where
+    N: RealField,
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    let moments = Moments::new(from, to, weights, false)?;
+    let (rotation, _) = optimal_rotation(moments.covariance);
+    let rmsd = weighted_rmsd(from, to, weights, moments.total_weight, |p| &rotation * p);
+
+    Some((rotation, rmsd))
+}
+
+/// Computes the isometry `T` minimizing the weighted sum of squared distances `Σ w_i |T * from_i - to_i|²`.
+///
+/// This is the Kabsch algorithm applied to the point sets translated to their weighted centroids.
+/// The rotational part of the result is guaranteed to be a proper rotation, i.e., a reflection is
+/// never returned even if it would fit the points better.
+///
+/// Returns the optimal isometry together with the weighted root-mean-square deviation
+/// `sqrt(Σ w_i |T * from_i - to_i|² / Σ w_i)` of the aligned points. If `weights` is `None`,
+/// all points have a weight equal to 1.
+///
+/// Returns `None` if `from` is empty, if `from`, `to` and `weights` do not have the same length,
+/// if a weight is negative, or if all the weights are zero.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{IsometryMatrix3, Point3, Vector3};
+/// let iso = IsometryMatrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+/// let from = [Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 0.0, 1.0), Point3::new(0.0, 4.0, -2.0)];
+/// let to: Vec<_> = from.iter().map(|p| iso * p).collect();
+///
+/// let (fitted, rmsd) = nalgebra::fit_isometry(&from, &to, None).unwrap();
+/// assert_relative_eq!(fitted, iso, epsilon = 1.0e-7);
+/// assert_relative_eq!(rmsd, 0.0, epsilon = 1.0e-7);
+/// ```
+pub fn fit_isometry<N, D>(
+    from: &[Point<N, D>],
+    to: &[Point<N, D>],
+    weights: Option<&[N]>,
+) -> Option<(Isometry<N, D, Rotation<N, D>>, N)>
+where
+    N: RealField,
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    let moments = Moments::new(from, to, weights, true)?;
+    let (rotation, _) = optimal_rotation(moments.covariance);
+    let translation = &moments.to_centroid - &rotation * &moments.from_centroid;
+    let isometry = Isometry::from_parts(Translation::from(translation), rotation);
+    let rmsd = weighted_rmsd(from, to, weights, moments.total_weight, |p| &isometry * p);
+
+    Some((isometry, rmsd))
+}
+
+/// Computes the similarity `T` minimizing the weighted sum of squared distances `Σ w_i |T * from_i - to_i|²`.
+///
+/// This is the Umeyama algorithm: it extends the Kabsch algorithm with the estimation of a
+/// uniform scaling factor. The rotational part of the result is guaranteed to be a proper rotation,
+/// i.e., a reflection is never returned even if it would fit the points better.
+///
+/// Returns the optimal similarity together with the weighted root-mean-square deviation
+/// `sqrt(Σ w_i |T * from_i - to_i|² / Σ w_i)` of the aligned points. If `weights` is `None`,
+/// all points have a weight equal to 1.
+///
+/// Returns `None` if `from` is empty, if `from`, `to` and `weights` do not have the same length,
+/// if a weight is negative, if all the weights are zero, or if all the points of `from` with
+/// a non-zero weight coincide.
+///
+/// See "Least-squares estimation of transformation parameters between two point patterns",
+/// S. Umeyama, IEEE Transactions on Pattern Analysis and Machine Intelligence, 1991.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{SimilarityMatrix2, Point2, Vector2};
+/// let sim = SimilarityMatrix2::new(Vector2::new(1.0, 2.0), 0.3, 2.5);
+/// let from = [Point2::new(1.0, 2.0), Point2::new(-1.0, 0.0), Point2::new(0.0, 4.0)];
+/// let to: Vec<_> = from.iter().map(|p| sim * p).collect();
+///
+/// let (fitted, rmsd) = nalgebra::fit_similarity(&from, &to, None).unwrap();
+/// assert_relative_eq!(fitted, sim, epsilon = 1.0e-7);
+/// assert_relative_eq!(rmsd, 0.0, epsilon = 1.0e-7);
+/// ```
+pub fn fit_similarity<N, D>(
+    from: &[Point<N, D>],
+    to: &[Point<N, D>],
+    weights: Option<&[N]>,
+) -> Option<(Similarity<N, D, Rotation<N, D>>, N)>
+where
+    N: RealField,
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    let moments = Moments::new(from, to, weights, true)?;
+
+    if moments.from_variance.is_zero() {
+        return None;
+    }
+
+    let (rotation, trace) = optimal_rotation(moments.covariance);
+    let scaling = trace / moments.from_variance;
+    let translation = &moments.to_centroid - &rotation * &moments.from_centroid * scaling;
+    let similarity = Similarity::from_parts(Translation::from(translation), rotation, scaling);
+    let rmsd = weighted_rmsd(from, to, weights, moments.total_weight, |p| &similarity * p);
+
+    Some((similarity, rmsd))
+}
+
+/// The weighted first and second moments of two corresponding point sets.
+struct Moments<N: RealField, D: DimName>
+where DefaultAllocator: Allocator<N, D> + Allocator<N, D, D>
+{
+    from_centroid: VectorN<N, D>,
+    to_centroid: VectorN<N, D>,
+    // Σ w_i (to_i - to_centroid) * (from_i - from_centroid)ᵀ / Σ w_i
+    covariance: MatrixN<N, D>,
+    // Σ w_i |from_i - from_centroid|² / Σ w_i
+    from_variance: N,
+    total_weight: N,
+}
+
+impl<N: RealField, D: DimName> Moments<N, D>
+where DefaultAllocator: Allocator<N, D> + Allocator<N, D, D>
+{
+    fn new(
+        from: &[Point<N, D>],
+        to: &[Point<N, D>],
+        weights: Option<&[N]>,
+        centered: bool,
+    ) -> Option<Self>
+    {
+        if from.len() != to.len() {
+            return None;
+        }
+
+        let total_weight = total_weight(from.len(), weights)?;
+        let weight = |i: usize| weights.map_or(N::one(), |w| w[i]);
+
+        let mut from_centroid = VectorN::zeros();
+        let mut to_centroid = VectorN::zeros();
+
+        if centered {
+            for i in 0..from.len() {
+                from_centroid.axpy(weight(i), &from[i].coords, N::one());
+                to_centroid.axpy(weight(i), &to[i].coords, N::one());
+            }
+
+            from_centroid /= total_weight;
+            to_centroid /= total_weight;
+        }
+
+        let mut covariance = MatrixN::zeros();
+        let mut from_variance = N::zero();
+
+        for i in 0..from.len() {
+            let p = &from[i].coords - &from_centroid;
+            let q = &to[i].coords - &to_centroid;
+            covariance.ger(weight(i), &q, &p, N::one());
+            from_variance += p.norm_squared() * weight(i);
+        }
+
+        Some(Moments {
+            from_centroid,
+            to_centroid,
+            covariance: covariance / total_weight,
+            from_variance: from_variance / total_weight,
+            total_weight,
+        })
+    }
+}
+
+/// The sum of the weights of `len` elements, all equal to 1 if `weights` is `None`.
+///
+/// Returns `None` if `len` is zero, if `weights` does not have `len` elements, if a weight is
+/// negative, or if all the weights are zero.
+pub(crate) fn total_weight<N: RealField>(len: usize, weights: Option<&[N]>) -> Option<N> {
+    let total = match weights {
+        Some(weights) => {
+            if weights.len() != len || weights.iter().any(|w| *w < N::zero()) {
+                return None;
+            }
+
+            weights.iter().fold(N::zero(), |acc, w| acc + *w)
+        }
+        None => crate::convert(len as f64),
+    };
+
+    if total > N::zero() {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+/// The rotation `R` maximizing `tr(Rᵀ * covariance)`, together with this maximum.
+fn optimal_rotation<N, D>(covariance: MatrixN<N, D>) -> (Rotation<N, D>, N)
+where
+    N: RealField,
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    let svd = covariance.svd(true, true);
+    let mut u = svd.u.unwrap();
+    let v_t = svd.v_t.unwrap();
+    let mut trace = svd.singular_values.iter().fold(N::zero(), |acc, s| acc + *s);
+
+    if (&u * &v_t).determinant() < N::zero() {
+        // The best orthogonal matrix is a reflection: flip the singular vector associated to the
+        // smallest singular value to obtain the best rotation.
+        let imin = svd.singular_values.imin();
+        u.column_mut(imin).neg_mut();
+        trace -= svd.singular_values[imin] * crate::convert(2.0);
+    }
+
+    (Rotation::from_matrix_unchecked(u * v_t), trace)
+}
+
+/// The weighted root-mean-square deviation between the transformed `from` points and `to`.
+fn weighted_rmsd<N, D, F>(
+    from: &[Point<N, D>],
+    to: &[Point<N, D>],
+    weights: Option<&[N]>,
+    total_weight: N,
+    transform: F,
+) -> N
+where
+    N: RealField,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D>,
+    F: Fn(&Point<N, D>) -> Point<N, D>,
+{
+    let sum = from.iter().zip(to.iter()).enumerate().fold(N::zero(), |acc, (i, (p, q))| {
+        let weight = weights.map_or(N::one(), |w| w[i]);
+        acc + (transform(p) - q).norm_squared() * weight
+    });
+
+    (sum / total_weight).sqrt()
+}
@@ -13,7 +13,9 @@ use crate::base::dimension::{U1, U2, U3};
 use crate::base::storage::Storage;
 use crate::base::{Matrix2, Matrix3, MatrixN, Unit, Vector, Vector1, Vector3, VectorN};
 
-use crate::geometry::{Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+use crate::geometry::euler_order::{elementary_rotation, extrinsic_euler_angles};
+use crate::geometry::registration::total_weight;
+use crate::geometry::{EulerOrder, Rotation2, Rotation3, UnitComplex, UnitQuaternion};
 
 /*
  *
@@ -228,6 +230,34 @@ impl<N: RealField> Rotation2<N> {
     pub fn scaled_axis(&self) -> VectorN<N, U1> {
         Vector1::new(self.angle())
     }
+
+    /// The skew-symmetric matrix of the Lie algebra so(2) associated to the rotation angle `angle`.
+    ///
+    /// Its matrix exponential is `Rotation2::new(angle)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Rotation2, Matrix2};
+    /// assert_eq!(Rotation2::hat(2.0), Matrix2::new(0.0, -2.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn hat(angle: N) -> Matrix2<N> {
+        Matrix2::new(N::zero(), -angle, angle, N::zero())
+    }
+
+    /// The rotation angle encoded by the skew-symmetric matrix `m`.
+    ///
+    /// This is the inverse of `Rotation2::hat`. Only the lower-left component of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Rotation2;
+    /// assert_eq!(Rotation2::vee(&Rotation2::hat(2.0)), 2.0);
+    /// ```
+    #[inline]
+    pub fn vee(m: &Matrix2<N>) -> N {
+        m[(1, 0)]
+    }
 }
 
 impl<N: RealField> Distribution<Rotation2<N>> for Standard
@@ -333,6 +363,139 @@ impl<N: RealField> Rotation3<N> {
         Self::from_matrix_unchecked(rot)
     }
 
+    /// Builds the rotation matrix closest to `m` in the Frobenius norm.
+    ///
+    /// Contrary to `.from_matrix_eps`, this is not iterative: it computes the singular value
+    /// decomposition `m = U * Σ * Vᵀ` and returns `U * diag(1, 1, ±1) * Vᵀ`, where the sign of the
+    /// last term ensures the result is a proper rotation (with a determinant equal to 1) even if
+    /// `m` is a reflection. The result is always orthogonal up to rounding errors.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let rot = Rotation3::from_scaled_axis(Vector3::new(0.1, 0.2, 0.3));
+    /// let noisy = rot.matrix() + Vector3::new(0.01, -0.02, 0.0) * Vector3::new(0.0, 0.01, 0.03).transpose();
+    /// let nearest = Rotation3::from_matrix_nearest(&noisy);
+    ///
+    /// assert_relative_eq!(nearest.matrix().determinant(), 1.0, epsilon = 1.0e-7);
+    /// assert_relative_eq!(nearest, rot, epsilon = 1.0e-3);
+    /// ```
+    pub fn from_matrix_nearest(m: &Matrix3<N>) -> Self {
+        let svd = m.svd(true, true);
+        let mut u = svd.u.unwrap();
+        let v_t = svd.v_t.unwrap();
+
+        if (u * v_t).determinant() < N::zero() {
+            // Flip the singular vector associated to the smallest singular value, which is the
+            // least costly correction.
+            let imin = svd.singular_values.imin();
+            u.column_mut(imin).neg_mut();
+        }
+
+        Self::from_matrix_unchecked(u * v_t)
+    }
+
+    /// The weighted chordal L2 mean of the given rotations.
+    ///
+    /// This is the rotation minimizing the weighted sum of squared Frobenius distances
+    /// `Σ w_i |R - R_i|²`, i.e., the rotation nearest to the weighted average of the rotation
+    /// matrices as computed by `Rotation3::from_matrix_nearest`. It is not iterative and is a good
+    /// approximation of the geodesic mean when the rotations are close to each other.
+    ///
+    /// If `weights` is `None`, all rotations have a weight equal to 1. Returns `None` if `rotations`
+    /// is empty, if `weights` does not have the same length as `rotations`, if a weight is negative,
+    /// or if all the weights are zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let rot = Rotation3::from_scaled_axis(Vector3::new(0.1, 0.2, 0.3));
+    /// let delta = Rotation3::from_scaled_axis(Vector3::new(0.3, -0.1, 0.2));
+    ///
+    /// let mean = Rotation3::chordal_mean(&[rot * delta, rot * delta.inverse()], None).unwrap();
+    /// assert_relative_eq!(mean, rot, epsilon = 1.0e-7);
+    /// ```
+    pub fn chordal_mean(rotations: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        let total_weight = total_weight(rotations.len(), weights)?;
+        let mut m = Matrix3::zeros();
+
+        for (i, r) in rotations.iter().enumerate() {
+            let weight = weights.map_or(N::one(), |w| w[i]);
+            m += r.matrix() * weight;
+        }
+
+        Some(Self::from_matrix_nearest(&(m / total_weight)))
+    }
+
+    /// The weighted geodesic L2 mean of the given rotations.
+    ///
+    /// This is the rotation minimizing the weighted sum of squared rotation angles
+    /// `Σ w_i angle(Rᵀ * R_i)²`, also known as the Karcher mean. It is computed iteratively by
+    /// gradient descent on the rotation group, starting from the chordal mean. The solution is
+    /// unique if all the rotations are within an angle of `pi / 2` of some rotation.
+    ///
+    /// If `weights` is `None`, all rotations have a weight equal to 1. Returns `None` if `rotations`
+    /// is empty, if `weights` does not have the same length as `rotations`, if a weight is negative,
+    /// or if all the weights are zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `rotations`: the rotations to average.
+    /// * `weights`: the weight of each rotation, or `None` to give the same weight to all rotations.
+    /// * `eps`: the angle of the last gradient descent step below which the mean is considered to
+    ///          have converged.
+    /// * `max_iter`: the maximum number of iterations. Loops indefinitely until convergence if set to `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let r1 = Rotation3::from_scaled_axis(Vector3::new(0.1, 0.0, 0.0));
+    /// let r2 = Rotation3::from_scaled_axis(Vector3::new(0.0, 0.5, 0.0));
+    /// let r3 = Rotation3::from_scaled_axis(Vector3::new(0.0, 0.0, 0.9));
+    /// let rotations = [r1, r2, r3];
+    /// let mean = Rotation3::geodesic_mean(&rotations, None, 1.0e-12, 100).unwrap();
+    ///
+    /// // At the geodesic mean, the rotations to each input sum to zero.
+    /// let sum: Vector3<f64> = rotations.iter().map(|r| mean.rotation_to(r).scaled_axis()).sum();
+    /// assert_relative_eq!(sum, Vector3::zeros(), epsilon = 1.0e-7);
+    /// ```
+    pub fn geodesic_mean(
+        rotations: &[Self],
+        weights: Option<&[N]>,
+        eps: N,
+        mut max_iter: usize,
+    ) -> Option<Self>
+    {
+        let total_weight = total_weight(rotations.len(), weights)?;
+        let mut mean = UnitQuaternion::from_rotation_matrix(&Self::chordal_mean(rotations, weights)?);
+
+        if max_iter == 0 {
+            max_iter = usize::max_value();
+        }
+
+        for _ in 0..max_iter {
+            let mut step = Vector3::zeros();
+
+            for (i, r) in rotations.iter().enumerate() {
+                let weight = weights.map_or(N::one(), |w| w[i]);
+                let q = UnitQuaternion::from_rotation_matrix(r);
+                step.axpy(weight, &mean.rotation_to(&q).scaled_axis(), N::one());
+            }
+
+            step /= total_weight;
+            mean = UnitQuaternion::from_scaled_axis(step) * mean;
+
+            if step.norm() <= eps {
+                break;
+            }
+        }
+
+        Some(mean.to_rotation_matrix())
+    }
+
     /// Builds a 3D rotation matrix from an axis scaled by the rotation angle.
     ///
     /// This is the same as `Self::new(axisangle)`.
@@ -481,6 +644,74 @@ impl<N: RealField> Rotation3<N> {
         }
     }
 
+    /// Creates a new rotation from Euler angles following the given convention.
+    ///
+    /// See the documentation of `EulerOrder` for the meaning of the angles `(a, b, c)`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Rotation3, EulerAxes, EulerOrder, Vector3};
+    /// let order = EulerOrder::Intrinsic(EulerAxes::ZYX);
+    /// let rot = Rotation3::from_euler_angles_ordered(f32::consts::FRAC_PI_2, 0.0, 0.3, order);
+    /// let expected = Rotation3::from_axis_angle(&Vector3::z_axis(), f32::consts::FRAC_PI_2)
+    ///     * Rotation3::from_axis_angle(&Vector3::x_axis(), 0.3);
+    ///
+    /// assert_relative_eq!(rot, expected, epsilon = 1.0e-6);
+    ///
+    /// // The roll-pitch-yaw convention of `from_euler_angles`.
+    /// let order = EulerOrder::Extrinsic(EulerAxes::XYZ);
+    /// assert_relative_eq!(
+    ///     Rotation3::from_euler_angles_ordered(0.1, 0.2, 0.3, order),
+    ///     Rotation3::from_euler_angles(0.1, 0.2, 0.3),
+    ///     epsilon = 1.0e-6
+    /// );
+    /// ```
+    pub fn from_euler_angles_ordered(a: N, b: N, c: N, order: EulerOrder) -> Self {
+        let [i, j, k] = order.extrinsic_indices();
+        let (a, b, c) = order.reorder_angles((a, b, c));
+
+        Self::from_matrix_unchecked(
+            elementary_rotation(k, c) * elementary_rotation(j, b) * elementary_rotation(i, a),
+        )
+    }
+
+    /// Euler angles `(a, b, c)` of this rotation following the given convention.
+    ///
+    /// For Tait–Bryan angles, `a` and `c` are in `[-pi, pi]` and `b` is in `[-pi / 2, pi / 2]`.
+    /// For proper Euler angles, `a` and `c` are in `[-pi, pi]` and `b` is in `[0, pi]`.
+    ///
+    /// In case of gimbal lock, i.e., when the second angle makes the first and third rotation
+    /// axes coincide, only the sum or difference of the first and third angles is determined. The
+    /// angle of the last applied rotation around a fixed axis is then set to zero: this is `c` for
+    /// extrinsic conventions and `a` for intrinsic conventions.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Rotation3, EulerAxes, EulerOrder};
+    /// let order = EulerOrder::Intrinsic(EulerAxes::ZXZ);
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, 0.2, 0.3, order);
+    /// let (a, b, c) = rot.euler_angles_ordered(order);
+    /// assert_relative_eq!(a, 0.1, epsilon = 1.0e-6);
+    /// assert_relative_eq!(b, 0.2, epsilon = 1.0e-6);
+    /// assert_relative_eq!(c, 0.3, epsilon = 1.0e-6);
+    ///
+    /// // Gimbal lock.
+    /// let order = EulerOrder::Extrinsic(EulerAxes::XYZ);
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, f32::consts::FRAC_PI_2, 0.3, order);
+    /// let (a, b, c) = rot.euler_angles_ordered(order);
+    /// assert_relative_eq!(a, -0.2, epsilon = 1.0e-6);
+    /// assert_relative_eq!(b, f32::consts::FRAC_PI_2, epsilon = 1.0e-6);
+    /// assert_eq!(c, 0.0);
+    /// ```
+    pub fn euler_angles_ordered(&self, order: EulerOrder) -> (N, N, N) {
+        let angles = extrinsic_euler_angles(self.matrix(), order.extrinsic_indices());
+        order.reorder_angles(angles)
+    }
+
     /// Ensure this rotation is an orthonormal rotation matrix. This is useful when repeated
     /// computations might cause the matrix from progressively not being orthonormal anymore.
     #[inline]
@@ -815,6 +1046,111 @@ impl<N: RealField> Rotation3<N> {
             Self::identity()
         }
     }
+
+    /// The skew-symmetric matrix of the Lie algebra so(3) associated to the rotation axis scaled by
+    /// the rotation angle `axisangle`.
+    ///
+    /// Its matrix exponential is `Rotation3::new(axisangle)`. This is the same as
+    /// `axisangle.cross_matrix()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let axisangle = Vector3::new(0.1, 0.2, 0.3);
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(Rotation3::hat(&axisangle) * v, axisangle.cross(&v));
+    /// ```
+    #[inline]
+    pub fn hat(axisangle: &Vector3<N>) -> Matrix3<N> {
+        axisangle.cross_matrix()
+    }
+
+    /// The rotation axis scaled by the rotation angle encoded by the skew-symmetric matrix `m`.
+    ///
+    /// This is the inverse of `Rotation3::hat`. Only the lower-triangular part of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let axisangle = Vector3::new(0.1, 0.2, 0.3);
+    /// assert_eq!(Rotation3::vee(&Rotation3::hat(&axisangle)), axisangle);
+    /// ```
+    #[inline]
+    pub fn vee(m: &Matrix3<N>) -> Vector3<N> {
+        Vector3::new(m[(2, 1)], -m[(2, 0)], m[(1, 0)])
+    }
+
+    /// The left Jacobian of SO(3) at the rotation axis scaled by the rotation angle `axisangle`.
+    ///
+    /// This is the matrix `J` such that, for a small `delta`,
+    /// `Rotation3::new(axisangle + delta) ≈ Rotation3::new(J * delta) * Rotation3::new(axisangle)`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let axisangle = Vector3::new(0.1, 0.2, 0.3);
+    /// let delta = Vector3::new(1.0e-5, 2.0e-5, -1.0e-5);
+    /// let jac = Rotation3::left_jacobian(&axisangle);
+    ///
+    /// assert_relative_eq!(
+    ///     Rotation3::new(axisangle + delta),
+    ///     Rotation3::new(jac * delta) * Rotation3::new(axisangle),
+    ///     epsilon = 1.0e-9
+    /// );
+    /// ```
+    #[inline]
+    pub fn left_jacobian(axisangle: &Vector3<N>) -> Matrix3<N> {
+        let angle = axisangle.norm();
+        let hat = axisangle.cross_matrix();
+
+        Matrix3::identity()
+            + hat * one_minus_cos_over_squared_angle(angle)
+            + hat * hat * angle_minus_sin_over_cubed_angle(angle)
+    }
+
+    /// The right Jacobian of SO(3) at the rotation axis scaled by the rotation angle `axisangle`.
+    ///
+    /// This is the matrix `J` such that, for a small `delta`,
+    /// `Rotation3::new(axisangle + delta) ≈ Rotation3::new(axisangle) * Rotation3::new(J * delta)`.
+    /// It is equal to `Rotation3::left_jacobian(&-axisangle)`.
+    #[inline]
+    pub fn right_jacobian(axisangle: &Vector3<N>) -> Matrix3<N> {
+        Self::left_jacobian(&-axisangle)
+    }
+
+    /// The inverse of the left Jacobian of SO(3) at the rotation axis scaled by the rotation angle
+    /// `axisangle`.
+    ///
+    /// The result is not defined if the norm of `axisangle` is a non-zero multiple of `2 * pi`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3, Matrix3};
+    /// let axisangle = Vector3::new(1.0, 2.0, -0.5);
+    /// let jac = Rotation3::left_jacobian(&axisangle);
+    /// let inv = Rotation3::left_jacobian_inverse(&axisangle);
+    ///
+    /// assert_relative_eq!(jac * inv, Matrix3::identity(), epsilon = 1.0e-9);
+    /// ```
+    #[inline]
+    pub fn left_jacobian_inverse(axisangle: &Vector3<N>) -> Matrix3<N> {
+        let angle = axisangle.norm();
+        let hat = axisangle.cross_matrix();
+        let half: N = crate::convert(0.5);
+
+        Matrix3::identity() - hat * half + hat * hat * jacobian_inverse_coefficient(angle)
+    }
+
+    /// The inverse of the right Jacobian of SO(3) at the rotation axis scaled by the rotation angle
+    /// `axisangle`.
+    ///
+    /// It is equal to `Rotation3::left_jacobian_inverse(&-axisangle)`.
+    #[inline]
+    pub fn right_jacobian_inverse(axisangle: &Vector3<N>) -> Matrix3<N> {
+        Self::left_jacobian_inverse(&-axisangle)
+    }
 }
 
 impl<N: RealField> Distribution<Rotation3<N>> for Standard
@@ -867,3 +1203,55 @@ where
         Self::new(VectorN::arbitrary(g))
     }
 }
+
+/*
+ *
+ * Coefficients of the exponential map of SO(3) and SE(3).
+ *
+ * Each coefficient is replaced by its Taylor expansion for small angles, where the closed-form
+ * expression suffers from catastrophic cancellation.
+ *
+ */
+#[inline]
+pub(crate) fn is_small_angle<N: RealField>(angle: N) -> bool {
+    angle.abs() < N::default_epsilon().powf(crate::convert(0.1))
+}
+
+/// Computes `(1 - cos(angle)) / angle²`.
+#[inline]
+pub(crate) fn one_minus_cos_over_squared_angle<N: RealField>(angle: N) -> N {
+    if is_small_angle(angle) {
+        let sq = angle * angle;
+        crate::convert::<_, N>(0.5) - sq / crate::convert(24.0)
+            + sq * sq / crate::convert(720.0)
+    } else {
+        (N::one() - angle.cos()) / (angle * angle)
+    }
+}
+
+/// Computes `(angle - sin(angle)) / angle³`.
+#[inline]
+pub(crate) fn angle_minus_sin_over_cubed_angle<N: RealField>(angle: N) -> N {
+    if is_small_angle(angle) {
+        let sq = angle * angle;
+        N::one() / crate::convert(6.0) - sq / crate::convert(120.0)
+            + sq * sq / crate::convert(5040.0)
+    } else {
+        (angle - angle.sin()) / (angle * angle * angle)
+    }
+}
+
+/// Computes `(1 - angle / 2 * cot(angle / 2)) / angle²`.
+#[inline]
+fn jacobian_inverse_coefficient<N: RealField>(angle: N) -> N {
+    if is_small_angle(angle) {
+        let sq = angle * angle;
+        N::one() / crate::convert(12.0)
+            + sq / crate::convert(720.0)
+            + sq * sq / crate::convert(30240.0)
+    } else {
+        let half_angle = angle * crate::convert(0.5);
+        let (sin, cos) = half_angle.sin_cos();
+        (N::one() - half_angle * cos / sin) / (angle * angle)
+    }
+}
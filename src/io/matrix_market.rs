@@ -1,53 +1,567 @@
-use std::fs;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
 use std::path::Path;
 
+use alga::general::{ClosedAdd, ClosedNeg};
+use num::Zero;
+use num_complex::Complex;
+use pest::error::LineColLocation;
+use pest::iterators::Pair;
 use pest::Parser;
-use crate::sparse::CsMatrix;
-use crate::RealField;
+
+use crate::sparse::{CsMatrix, CsStorage};
+use crate::storage::Storage;
+use crate::{DMatrix, Dim, Matrix, Scalar};
 
 #[derive(Parser)]
 #[grammar = "io/matrix_market.pest"]
 struct MatrixMarketParser;
 
-// FIXME: return an Error instead of an Option.
+/// An error that occurred while reading or writing a Matrix Market file.
+#[derive(Debug)]
+pub enum MatrixMarketError {
+    /// An I/O error occurred while accessing the file.
+    Io(io::Error),
+    /// The file could not be parsed, e.g., because its `%%MatrixMarket` header is missing.
+    Syntax {
+        /// The line where the parsing failed.
+        line: usize,
+    },
+    /// The `%%MatrixMarket` header has an unknown qualifier or an unsupported combination of
+    /// qualifiers.
+    InvalidHeader {
+        /// The line of the header.
+        line: usize,
+    },
+    /// The file contains complex values but the matrix read has real components.
+    IncompatibleField {
+        /// The line of the header.
+        line: usize,
+    },
+    /// The line giving the size of the matrix is missing or invalid, or the matrix is not square
+    /// while its symmetry qualifier requires it.
+    InvalidSize {
+        /// The line where the size was expected.
+        line: usize,
+    },
+    /// An entry has the wrong number of values, or one of its values could not be parsed.
+    InvalidEntry {
+        /// The line of the entry.
+        line: usize,
+    },
+    /// The row or column index of an entry is out of the bounds of the matrix.
+    IndexOutOfBounds {
+        /// The line of the entry.
+        line: usize,
+    },
+    /// The number of entries of the file does not match the number given by its size line.
+    EntryCount {
+        /// The number of entries given by the size line.
+        expected: usize,
+        /// The number of entries actually found.
+        found: usize,
+    },
+}
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MatrixMarketError::Io(ref e) => write!(f, "I/O error: {}", e),
+            MatrixMarketError::Syntax { line } => write!(f, "syntax error at line {}", line),
+            MatrixMarketError::InvalidHeader { line } => {
+                write!(f, "invalid Matrix Market header at line {}", line)
+            }
+            MatrixMarketError::IncompatibleField { line } => write!(
+                f,
+                "the complex values declared at line {} cannot be read into a real matrix",
+                line
+            ),
+            MatrixMarketError::InvalidSize { line } => {
+                write!(f, "invalid matrix size at line {}", line)
+            }
+            MatrixMarketError::InvalidEntry { line } => {
+                write!(f, "invalid matrix entry at line {}", line)
+            }
+            MatrixMarketError::IndexOutOfBounds { line } => {
+                write!(f, "matrix entry index out of bounds at line {}", line)
+            }
+            MatrixMarketError::EntryCount { expected, found } => write!(
+                f,
+                "expected {} matrix entries but found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MatrixMarketError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            MatrixMarketError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MatrixMarketError {
+    fn from(e: io::Error) -> Self {
+        MatrixMarketError::Io(e)
+    }
+}
+
+/// A scalar type that can be read from and written to Matrix Market files.
+pub trait MatrixMarketScalar: Scalar + Zero + ClosedAdd + ClosedNeg {
+    /// Whether this type represents complex numbers, i.e., uses the `complex` field qualifier.
+    fn is_complex() -> bool;
+    /// Creates a scalar from the real and imaginary parts read from a Matrix Market file.
+    ///
+    /// The imaginary part is always zero if `Self::is_complex()` is `false`.
+    fn from_parts(re: f64, im: f64) -> Self;
+    /// The complex conjugate of this scalar.
+    fn conj(&self) -> Self;
+    /// Writes this scalar in the Matrix Market format.
+    fn write_value<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+macro_rules! impl_matrix_market_scalar(
+    ($($T: ty),*) => {$(
+        impl MatrixMarketScalar for $T {
+            #[inline]
+            fn is_complex() -> bool {
+                false
+            }
+
+            #[inline]
+            fn from_parts(re: f64, _: f64) -> Self {
+                re as $T
+            }
+
+            #[inline]
+            fn conj(&self) -> Self {
+                *self
+            }
+
+            #[inline]
+            fn write_value<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                write!(writer, "{:e}", self)
+            }
+        }
+
+        impl MatrixMarketScalar for Complex<$T> {
+            #[inline]
+            fn is_complex() -> bool {
+                true
+            }
+
+            #[inline]
+            fn from_parts(re: f64, im: f64) -> Self {
+                Complex::new(re as $T, im as $T)
+            }
+
+            #[inline]
+            fn conj(&self) -> Self {
+                Complex::conj(self)
+            }
+
+            #[inline]
+            fn write_value<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                write!(writer, "{:e} {:e}", self.re, self.im)
+            }
+        }
+    )*}
+);
+
+impl_matrix_market_scalar!(f32, f64);
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Format {
+    Coordinate,
+    Array,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Field {
+    Real,
+    Integer,
+    Complex,
+    Pattern,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+// The entries of a Matrix Market file, with the entries implied by its symmetry made explicit.
+struct Entries<N> {
+    nrows: usize,
+    ncols: usize,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    vals: Vec<N>,
+}
+
+impl<N: MatrixMarketScalar> Entries<N> {
+    fn push(&mut self, i: usize, j: usize, val: N, symmetry: Symmetry) {
+        self.rows.push(i);
+        self.cols.push(j);
+        self.vals.push(val);
+
+        if i != j {
+            let mirrored = match symmetry {
+                Symmetry::General => return,
+                Symmetry::Symmetric => val,
+                Symmetry::SkewSymmetric => -val,
+                Symmetry::Hermitian => val.conj(),
+            };
+
+            self.rows.push(j);
+            self.cols.push(i);
+            self.vals.push(mirrored);
+        }
+    }
+}
+
+// NOTE: this is linear wrt. the position of `pair` on the input so it should not be called for
+// every entry.
+fn line_of(pair: &Pair<Rule>) -> usize {
+    pair.as_span().start_pos().line_col().0
+}
+
+fn parse_header(header: Pair<Rule>) -> Result<(Format, Field, Symmetry), MatrixMarketError> {
+    let line = line_of(&header);
+    let err = MatrixMarketError::InvalidHeader { line };
+    let tokens: Vec<String> = header
+        .into_inner()
+        .map(|t| t.as_str().to_ascii_lowercase())
+        .collect();
+
+    if tokens.len() != 5 || tokens[0] != "matrixmarket" || tokens[1] != "matrix" {
+        return Err(err);
+    }
+
+    let format = match tokens[2].as_str() {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        _ => return Err(err),
+    };
+
+    let field = match tokens[3].as_str() {
+        "real" | "double" => Field::Real,
+        "integer" => Field::Integer,
+        "complex" => Field::Complex,
+        "pattern" => Field::Pattern,
+        _ => return Err(err),
+    };
+
+    let symmetry = match tokens[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => Symmetry::Hermitian,
+        _ => return Err(err),
+    };
+
+    if field == Field::Pattern && (format == Format::Array || symmetry == Symmetry::SkewSymmetric) {
+        return Err(err);
+    }
+
+    Ok((format, field, symmetry))
+}
+
+fn parse_value<N: MatrixMarketScalar>(tokens: &[&str], field: Field) -> Option<N> {
+    match field {
+        Field::Pattern => Some(N::from_parts(1.0, 0.0)),
+        Field::Real | Field::Integer => Some(N::from_parts(tokens[0].parse().ok()?, 0.0)),
+        Field::Complex => Some(N::from_parts(
+            tokens[0].parse().ok()?,
+            tokens[1].parse().ok()?,
+        )),
+    }
+}
+
+fn parse_entries<N: MatrixMarketScalar>(data: &str) -> Result<Entries<N>, MatrixMarketError> {
+    let document = MatrixMarketParser::parse(Rule::Document, data)
+        .map_err(|e| {
+            let line = match e.line_col {
+                LineColLocation::Pos((line, _)) | LineColLocation::Span((line, _), _) => line,
+            };
+            MatrixMarketError::Syntax { line }
+        })?
+        .next()
+        .unwrap();
+
+    let mut pairs = document.into_inner();
+    let header = pairs.next().unwrap();
+    let header_line = line_of(&header);
+    let (format, field, symmetry) = parse_header(header)?;
+
+    if field == Field::Complex && !N::is_complex() {
+        return Err(MatrixMarketError::IncompatibleField { line: header_line });
+    }
+
+    let mut lines = pairs.filter(|p| p.as_rule() == Rule::Line);
+    let size_line = lines
+        .next()
+        .ok_or(MatrixMarketError::InvalidSize { line: header_line })?;
+    let size_err = MatrixMarketError::InvalidSize {
+        line: line_of(&size_line),
+    };
+    let size: Vec<usize> = match size_line
+        .into_inner()
+        .map(|t| t.as_str().parse())
+        .collect()
+    {
+        Ok(size) => size,
+        Err(_) => return Err(size_err),
+    };
+
+    let expected_len = match format {
+        Format::Coordinate => 3,
+        Format::Array => 2,
+    };
+
+    if size.len() != expected_len || (symmetry != Symmetry::General && size[0] != size[1]) {
+        return Err(size_err);
+    }
+
+    let (nrows, ncols) = (size[0], size[1]);
+    let nvalues = match field {
+        Field::Pattern => 0,
+        Field::Real | Field::Integer => 1,
+        Field::Complex => 2,
+    };
+
+    // For the array format, the index of the first row stored on each column.
+    let first_row = |j: usize| match symmetry {
+        Symmetry::General => 0,
+        Symmetry::Symmetric | Symmetry::Hermitian => j,
+        Symmetry::SkewSymmetric => j + 1,
+    };
+
+    let expected = match format {
+        Format::Coordinate => size[2],
+        Format::Array => (0..ncols).map(|j| nrows - first_row(j).min(nrows)).sum(),
+    };
+
+    let mut entries = Entries {
+        nrows,
+        ncols,
+        rows: Vec::with_capacity(expected),
+        cols: Vec::with_capacity(expected),
+        vals: Vec::with_capacity(expected),
+    };
+
+    let mut found = 0;
+    // The position of the next value for the array format.
+    let (mut i, mut j) = (first_row(0), 0);
+
+    for line in lines {
+        found += 1;
+
+        if found > expected {
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.clone().into_inner().map(|t| t.as_str()).collect();
+        let invalid_entry = || MatrixMarketError::InvalidEntry {
+            line: line_of(&line),
+        };
+
+        match format {
+            Format::Coordinate => {
+                if tokens.len() != 2 + nvalues {
+                    return Err(invalid_entry());
+                }
+
+                let parse_index = |token: &str, dim: usize| match token.parse::<usize>() {
+                    // NOTE: indices are 1-based.
+                    Ok(index) if index >= 1 && index <= dim => Ok(index - 1),
+                    Ok(_) => Err(MatrixMarketError::IndexOutOfBounds {
+                        line: line_of(&line),
+                    }),
+                    Err(_) => Err(invalid_entry()),
+                };
+                let irow = parse_index(tokens[0], nrows)?;
+                let icol = parse_index(tokens[1], ncols)?;
+                let val = parse_value(&tokens[2..], field).ok_or_else(invalid_entry)?;
+
+                entries.push(irow, icol, val, symmetry);
+            }
+            Format::Array => {
+                if tokens.len() != nvalues {
+                    return Err(invalid_entry());
+                }
+
+                let val: N = parse_value(&tokens, field).ok_or_else(invalid_entry)?;
+
+                // Explicit zeros are not stored in sparse matrices.
+                if !val.is_zero() {
+                    entries.push(i, j, val, symmetry);
+                }
+
+                i += 1;
+
+                while j < ncols && i >= nrows {
+                    j += 1;
+                    i = first_row(j);
+                }
+            }
+        }
+    }
+
+    if found != expected {
+        return Err(MatrixMarketError::EntryCount { expected, found });
+    }
+
+    Ok(entries)
+}
+
 /// Parses a Matrix Market file at the given path, and returns the corresponding sparse matrix.
-pub fn cs_matrix_from_matrix_market<N: RealField, P: AsRef<Path>>(path: P) -> Option<CsMatrix<N>> {
-    let file = fs::read_to_string(path).ok()?;
+///
+/// Both the `coordinate` and `array` formats are supported, with all the field and symmetry
+/// qualifiers. Entries implied by the symmetry qualifier are stored explicitly on the result.
+pub fn cs_matrix_from_matrix_market<N: MatrixMarketScalar, P: AsRef<Path>>(
+    path: P,
+) -> Result<CsMatrix<N>, MatrixMarketError> {
+    let file = fs::read_to_string(path)?;
     cs_matrix_from_matrix_market_str(&file)
 }
 
-// FIXME: return an Error instead of an Option.
 /// Parses a Matrix Market file described by the given string, and returns the corresponding sparse matrix.
-pub fn cs_matrix_from_matrix_market_str<N: RealField>(data: &str) -> Option<CsMatrix<N>> {
-    let file = MatrixMarketParser::parse(Rule::Document, data)
-        .unwrap()
-        .next()?;
-    let mut shape = (0, 0, 0);
-    let mut rows: Vec<usize> = Vec::new();
-    let mut cols: Vec<usize> = Vec::new();
-    let mut data: Vec<N> = Vec::new();
-
-    for line in file.into_inner() {
-        match line.as_rule() {
-            Rule::Header => {}
-            Rule::Shape => {
-                let mut inner = line.into_inner();
-                shape.0 = inner.next()?.as_str().parse::<usize>().ok()?;
-                shape.1 = inner.next()?.as_str().parse::<usize>().ok()?;
-                shape.2 = inner.next()?.as_str().parse::<usize>().ok()?;
-            }
-            Rule::Entry => {
-                let mut inner = line.into_inner();
+///
+/// Both the `coordinate` and `array` formats are supported, with all the field and symmetry
+/// qualifiers. Entries implied by the symmetry qualifier are stored explicitly on the result.
+pub fn cs_matrix_from_matrix_market_str<N: MatrixMarketScalar>(
+    data: &str,
+) -> Result<CsMatrix<N>, MatrixMarketError> {
+    let entries = parse_entries(data)?;
+    Ok(CsMatrix::from_triplet(
+        entries.nrows,
+        entries.ncols,
+        &entries.rows,
+        &entries.cols,
+        &entries.vals,
+    ))
+}
+
+/// Parses a Matrix Market file at the given path, and returns the corresponding dense matrix.
+///
+/// Both the `coordinate` and `array` formats are supported, with all the field and symmetry
+/// qualifiers.
+pub fn dmatrix_from_matrix_market<N: MatrixMarketScalar, P: AsRef<Path>>(
+    path: P,
+) -> Result<DMatrix<N>, MatrixMarketError> {
+    let file = fs::read_to_string(path)?;
+    dmatrix_from_matrix_market_str(&file)
+}
+
+/// Parses a Matrix Market file described by the given string, and returns the corresponding dense matrix.
+///
+/// Both the `coordinate` and `array` formats are supported, with all the field and symmetry
+/// qualifiers.
+pub fn dmatrix_from_matrix_market_str<N: MatrixMarketScalar>(
+    data: &str,
+) -> Result<DMatrix<N>, MatrixMarketError> {
+    let entries = parse_entries(data)?;
+    let mut res = DMatrix::zeros(entries.nrows, entries.ncols);
+
+    for ((i, j), val) in entries
+        .rows
+        .iter()
+        .zip(entries.cols.iter())
+        .zip(entries.vals.iter())
+    {
+        res[(*i, *j)] += *val;
+    }
+
+    Ok(res)
+}
+
+/// A matrix that can be written to a Matrix Market file.
+///
+/// Sparse matrices are written with the `coordinate` format and dense matrices with the `array`
+/// format. The symmetry qualifier is always `general`.
+pub trait MatrixMarketExport {
+    /// Writes this matrix into `writer` with the Matrix Market format.
+    fn write_matrix_market<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+fn field_name<N: MatrixMarketScalar>() -> &'static str {
+    if N::is_complex() {
+        "complex"
+    } else {
+        "real"
+    }
+}
+
+impl<N: MatrixMarketScalar, R: Dim, C: Dim, S: CsStorage<N, R, C>> MatrixMarketExport
+    for CsMatrix<N, R, C, S>
+{
+    fn write_matrix_market<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(
+            writer,
+            "%%MatrixMarket matrix coordinate {} general",
+            field_name::<N>()
+        )?;
+        writeln!(writer, "{} {} {}", self.nrows(), self.ncols(), self.len())?;
+
+        for j in 0..self.ncols() {
+            for (i, val) in self.data.column_entries(j) {
                 // NOTE: indices are 1-based.
-                rows.push(inner.next()?.as_str().parse::<usize>().ok()? - 1);
-                cols.push(inner.next()?.as_str().parse::<usize>().ok()? - 1);
-                data.push(crate::convert(inner.next()?.as_str().parse::<f64>().ok()?));
+                write!(writer, "{} {} ", i + 1, j + 1)?;
+                val.write_value(writer)?;
+                writeln!(writer)?;
             }
-            _ => return None, // FIXME: return an Err instead.
         }
+
+        Ok(())
     }
+}
 
-    Some(CsMatrix::from_triplet(
-        shape.0, shape.1, &rows, &cols, &data,
-    ))
+impl<N: MatrixMarketScalar, R: Dim, C: Dim, S: Storage<N, R, C>> MatrixMarketExport
+    for Matrix<N, R, C, S>
+{
+    fn write_matrix_market<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(
+            writer,
+            "%%MatrixMarket matrix array {} general",
+            field_name::<N>()
+        )?;
+        writeln!(writer, "{} {}", self.nrows(), self.ncols())?;
+
+        // NOTE: the array format is column-major.
+        for val in self.iter() {
+            val.write_value(writer)?;
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the given sparse or dense matrix into `writer` with the Matrix Market format.
+pub fn write_matrix_market<M: MatrixMarketExport, W: Write>(
+    writer: &mut W,
+    m: &M,
+) -> Result<(), MatrixMarketError> {
+    m.write_matrix_market(writer)?;
+    Ok(())
+}
+
+/// Writes the given sparse or dense matrix into a Matrix Market file at the given path.
+///
+/// The file is created if it does not exist, and overwritten otherwise.
+pub fn save_matrix_market<M: MatrixMarketExport, P: AsRef<Path>>(
+    path: P,
+    m: &M,
+) -> Result<(), MatrixMarketError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    m.write_matrix_market(&mut writer)?;
+    writer.flush()?;
+    Ok(())
 }
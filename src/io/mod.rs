@@ -1,5 +1,9 @@
-//! Parsers for various matrix formats.
+//! Parsers and writers for various matrix formats.
 
-pub use self::matrix_market::{cs_matrix_from_matrix_market, cs_matrix_from_matrix_market_str};
+pub use self::matrix_market::{
+    cs_matrix_from_matrix_market, cs_matrix_from_matrix_market_str, dmatrix_from_matrix_market,
+    dmatrix_from_matrix_market_str, save_matrix_market, write_matrix_market, MatrixMarketError,
+    MatrixMarketExport, MatrixMarketScalar,
+};
 
 mod matrix_market;
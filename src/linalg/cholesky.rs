@@ -1,14 +1,17 @@
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
+use num::{One, Zero};
 use alga::general::ComplexField;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix, Vector, VectorN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Dim, DimSub, Dynamic};
+use crate::dimension::{Dim, DimAdd, DimDiff, DimSub, DimSum, Dynamic, U1};
 use crate::storage::{Storage, StorageMut};
 
+use crate::linalg::LinalgError;
+
 /// The Cholesky decomposition of a symmetric-definite-positive matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
@@ -82,6 +85,14 @@ where DefaultAllocator: Allocator<N, D, D>
         Some(Cholesky { chol: matrix })
     }
 
+    /// Attempts to compute the Cholesky decomposition of `matrix`.
+    ///
+    /// Returns `LinalgError::NotPositiveDefinite` if the input matrix is not definite-positive.
+    /// The input matrix is assumed to be symmetric and only the lower-triangular part is read.
+    pub fn try_decompose(matrix: MatrixN<N, D>) -> Result<Self, LinalgError> {
+        Self::new(matrix).ok_or(LinalgError::NotPositiveDefinite)
+    }
+
     /// Retrieves the lower-triangular factor of the Cholesky decomposition with its strictly
     /// upper-triangular part filled with zeros.
     pub fn unpack(mut self) -> MatrixN<N, D> {
@@ -146,6 +157,225 @@ where DefaultAllocator: Allocator<N, D, D>
         self.solve_mut(&mut res);
         res
     }
+
+    /// Given the Cholesky decomposition of a matrix `M`, a non-negative scalar `sigma` and a
+    /// vector `x`, updates this decomposition in-place such that it becomes the decomposition of
+    /// `M + sigma * x * x.adjoint()`.
+    ///
+    /// This requires `O(n²)` operations instead of `O(n³)` for a full factorization. Use
+    /// `.rank_one_downdate(...)` to subtract a rank-one term instead.
+    pub fn rank_one_update<R2: Dim, S2>(&mut self, x: &Vector<N, R2, S2>, sigma: N::RealField)
+    where
+        S2: Storage<N, R2>,
+        DefaultAllocator: Allocator<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert!(
+            sigma >= N::RealField::zero(),
+            "Cholesky rank-one update: sigma must be non-negative."
+        );
+        assert_eq!(
+            x.nrows(),
+            self.chol.nrows(),
+            "Cholesky rank-one update: dimension mismatch."
+        );
+
+        let mut x = x.clone_owned();
+        let _ = rank_one_update_unchecked(&mut self.chol, &mut x, sigma);
+    }
+
+    /// Given the Cholesky decomposition of a matrix `M`, a non-negative scalar `sigma` and a
+    /// vector `x`, updates this decomposition in-place such that it becomes the decomposition of
+    /// `M - sigma * x * x.adjoint()`.
+    ///
+    /// Returns `false` and leaves `self` unchanged if the downdated matrix is not
+    /// definite-positive.
+    pub fn rank_one_downdate<R2: Dim, S2>(&mut self, x: &Vector<N, R2, S2>, sigma: N::RealField) -> bool
+    where
+        S2: Storage<N, R2>,
+        DefaultAllocator: Allocator<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert!(
+            sigma >= N::RealField::zero(),
+            "Cholesky rank-one downdate: sigma must be non-negative."
+        );
+        assert_eq!(
+            x.nrows(),
+            self.chol.nrows(),
+            "Cholesky rank-one downdate: dimension mismatch."
+        );
+
+        let mut chol = self.chol.clone();
+        let mut x = x.clone_owned();
+
+        if rank_one_update_unchecked(&mut chol, &mut x, -sigma) {
+            self.chol = chol;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Computes the Cholesky decomposition of the matrix obtained by inserting the row and
+    /// column `col` at the `j`-th position of the decomposed matrix.
+    ///
+    /// Since the decomposed matrix is symmetric, `col` is both the new `j`-th column and the
+    /// adjoint of the new `j`-th row. All its components are read, except the imaginary part of its
+    /// `j`-th component which is ignored.
+    /// Returns `None` if the resulting matrix is not definite-positive.
+    pub fn insert_column<R2, S2>(&self, j: usize, col: &Vector<N, R2, S2>) -> Option<Cholesky<N, DimSum<D, U1>>>
+    where
+        D: DimAdd<U1>,
+        R2: Dim,
+        S2: Storage<N, R2>,
+        DefaultAllocator: Allocator<N, DimSum<D, U1>, DimSum<D, U1>> + Allocator<N, DimSum<D, U1>>,
+        ShapeConstraint: SameNumberOfRows<R2, DimSum<D, U1>>,
+    {
+        let n = self.chol.nrows();
+        assert_eq!(
+            col.nrows(),
+            n + 1,
+            "Cholesky column insertion: the new column must have one more component than the decomposed matrix dimension."
+        );
+        assert!(j <= n, "Cholesky column insertion: index out of bounds.");
+
+        let (nrows, ncols) = self.chol.data.shape();
+        let mut chol = MatrixN::zeros_generic(nrows.add(U1), ncols.add(U1));
+        chol.slice_range_mut(..j, ..j).copy_from(&self.chol.slice_range(..j, ..j));
+        chol.slice_range_mut(j + 1.., ..j).copy_from(&self.chol.slice_range(j.., ..j));
+        chol.slice_range_mut(j + 1.., j + 1..).copy_from(&self.chol.slice_range(j.., j..));
+
+        // Workspace holding the adjoint of the new j-th row in its components `..j`, and the new
+        // sub-diagonal part of the j-th column in its components `j + 1..`.
+        let mut work = VectorN::<N, DimSum<D, U1>>::zeros_generic(nrows.add(U1), U1);
+        for i in 0..n + 1 {
+            work[i] = col[i];
+        }
+
+        // The new j-th row `r` is such that `L11 * r.adjoint() = col[..j]`.
+        let l11 = self.chol.slice_range(..j, ..j);
+        if !l11.solve_lower_triangular_mut(&mut work.rows_range_mut(..j)) {
+            return None;
+        }
+
+        for k in 0..j {
+            chol[(j, k)] = work[k].conjugate();
+        }
+
+        let new_diag2 = col[j].real() - work.rows_range(..j).norm_squared();
+        if new_diag2 <= N::RealField::zero() {
+            return None;
+        }
+
+        let new_diag = new_diag2.sqrt();
+        chol[(j, j)] = N::from_real(new_diag);
+
+        // The new sub-diagonal part of the j-th column is `(col[j + 1..] - L21 * r.adjoint()) / new_diag`.
+        for i in j + 1..n + 1 {
+            let mut val = work[i];
+
+            for k in 0..j {
+                val -= self.chol[(i - 1, k)] * work[k];
+            }
+
+            work[i] = val.unscale(new_diag);
+        }
+
+        chol.slice_range_mut(j + 1.., j).copy_from(&work.rows_range(j + 1..));
+
+        // The bottom-right block must absorb the contribution of the new column.
+        if !rank_one_update_unchecked(
+            &mut chol.slice_range_mut(j + 1.., j + 1..),
+            &mut work.rows_range_mut(j + 1..),
+            -N::RealField::one(),
+        ) {
+            return None;
+        }
+
+        Some(Cholesky { chol })
+    }
+
+    /// Computes the Cholesky decomposition of the matrix obtained by removing the `j`-th row and
+    /// column of the decomposed matrix.
+    pub fn remove_column(&self, j: usize) -> Cholesky<N, DimDiff<D, U1>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>, DimDiff<D, U1>> + Allocator<N, D>,
+    {
+        let n = self.chol.nrows();
+        assert!(n > 0, "Cholesky column removal: the decomposed matrix is empty.");
+        assert!(j < n, "Cholesky column removal: index out of bounds.");
+
+        let (nrows, ncols) = self.chol.data.shape();
+        let mut chol = MatrixN::zeros_generic(nrows.sub(U1), ncols.sub(U1));
+        chol.slice_range_mut(..j, ..j).copy_from(&self.chol.slice_range(..j, ..j));
+        chol.slice_range_mut(j.., ..j).copy_from(&self.chol.slice_range(j + 1.., ..j));
+        chol.slice_range_mut(j.., j..).copy_from(&self.chol.slice_range(j + 1.., j + 1..));
+
+        // The bottom-right block must absorb the contribution of the removed column.
+        let mut removed_col = self.chol.column(j).clone_owned();
+        let _ = rank_one_update_unchecked(
+            &mut chol.slice_range_mut(j.., j..),
+            &mut removed_col.rows_range_mut(j + 1..),
+            N::RealField::one(),
+        );
+
+        Cholesky { chol }
+    }
+}
+
+/// Updates the lower-triangular Cholesky factor `chol` in-place such that it becomes the factor of
+/// `chol * chol.adjoint() + sigma * x * x.adjoint()`. The vector `x` is overwritten.
+///
+/// Returns `false` if the updated matrix is not definite-positive, in which case `chol` contains
+/// garbage.
+fn rank_one_update_unchecked<N, D, S, R2, S2>(
+    chol: &mut Matrix<N, D, D, S>,
+    x: &mut Vector<N, R2, S2>,
+    sigma: N::RealField,
+) -> bool
+where
+    N: ComplexField,
+    D: Dim,
+    S: StorageMut<N, D, D>,
+    R2: Dim,
+    S2: StorageMut<N, R2>,
+{
+    let n = x.nrows();
+    let mut beta = N::RealField::one();
+
+    for j in 0..n {
+        let diag = chol[(j, j)].real();
+        let diag2 = diag * diag;
+        let xj = x[j];
+        let sigma_xj2 = sigma * xj.modulus_squared();
+        let new_diag2 = diag2 + sigma_xj2 / beta;
+
+        if new_diag2 <= N::RealField::zero() {
+            return false;
+        }
+
+        let new_diag = new_diag2.sqrt();
+        let gamma = diag2 * beta + sigma_xj2;
+        chol[(j, j)] = N::from_real(new_diag);
+        beta += sigma_xj2 / diag2;
+
+        // Update the remaining components of `x` and the j-th column of the factor.
+        let mut x_rest = x.rows_range_mut(j + 1..);
+        let mut col_j = chol.slice_range_mut(j + 1.., j);
+        x_rest.axpy(-xj / N::from_real(diag), &col_j, N::one());
+
+        if !gamma.is_zero() {
+            col_j.axpy(
+                N::from_real(new_diag * sigma / gamma) * xj.conjugate(),
+                &x_rest,
+                N::from_real(new_diag / diag),
+            );
+        }
+    }
+
+    true
 }
 
 impl<N: ComplexField, D: DimSub<Dynamic>, S: Storage<N, D, D>> SquareMatrix<N, D, S>
@@ -158,4 +388,12 @@ where DefaultAllocator: Allocator<N, D, D>
     pub fn cholesky(self) -> Option<Cholesky<N, D>> {
         Cholesky::new(self.into_owned())
     }
+
+    /// Attempts to compute the Cholesky decomposition of this matrix.
+    ///
+    /// Returns `LinalgError::NotPositiveDefinite` if the input matrix is not definite-positive.
+    /// The input matrix is assumed to be symmetric and only the lower-triangular part is read.
+    pub fn try_cholesky(self) -> Result<Cholesky<N, D>, LinalgError> {
+        Cholesky::try_decompose(self.into_owned())
+    }
 }
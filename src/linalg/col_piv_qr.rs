@@ -0,0 +1,408 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+use num::{One, Zero};
+use approx::AbsDiffEq;
+
+use alga::general::ComplexField;
+use crate::allocator::{Allocator, Reallocator};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, VectorN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimMin, DimMinimum, Dynamic, U1};
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::householder;
+use crate::linalg::{LinalgError, PermutationSequence, QR};
+
+/// The QR decomposition with column pivoting of a general matrix.
+///
+/// This is a rank-revealing decomposition: the magnitudes of the diagonal elements of `R` are
+/// non-increasing.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        serialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         QR<N, R, C>: Serialize,
+         PermutationSequence<DimMinimum<R, C>>: Serialize"
+    ))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        deserialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         QR<N, R, C>: Deserialize<'de>,
+         PermutationSequence<DimMinimum<R, C>>: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct ColPivQR<N: ComplexField, R: DimMin<C>, C: Dim>
+where DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+{
+    // The QR decomposition of the column-permuted matrix.
+    qr: QR<N, R, C>,
+    p: PermutationSequence<DimMinimum<R, C>>,
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> Copy for ColPivQR<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+    QR<N, R, C>: Copy,
+    PermutationSequence<DimMinimum<R, C>>: Copy,
+{}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> ColPivQR<N, R, C>
+where DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+{
+    /// Computes the QR decomposition with column pivoting using householder reflections.
+    ///
+    /// This effectively computes `Q, R, P` such that `matrix * P = QR`.
+    pub fn new(mut matrix: MatrixMN<N, R, C>) -> Self
+    where DefaultAllocator: Allocator<N::RealField, C> {
+        let (nrows, ncols) = matrix.data.shape();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        let mut p = PermutationSequence::identity_generic(min_nrows_ncols);
+        let mut diag = unsafe { MatrixMN::new_uninitialized_generic(min_nrows_ncols, U1) };
+
+        if min_nrows_ncols.value() == 0 {
+            return ColPivQR {
+                qr: QR::from_parts(matrix, diag),
+                p: p,
+            };
+        }
+
+        // The norms of the trailing part of each column are downdated after each householder
+        // reflection instead of being recomputed. `ref_norms` holds the norms at the time they
+        // were last computed exactly, which is needed to detect cancellation.
+        let mut norms = VectorN::<N::RealField, C>::from_fn_generic(ncols, U1, |j, _| {
+            matrix.column(j).norm()
+        });
+        let mut ref_norms = norms.clone();
+        let tol = N::RealField::default_epsilon().sqrt();
+
+        for i in 0..min_nrows_ncols.value() {
+            // Select the remaining column with the greatest norm.
+            let piv = i + norms.rows_range(i..).imax();
+
+            if piv != i {
+                matrix.swap_columns(i, piv);
+                norms.swap_rows(i, piv);
+                ref_norms.swap_rows(i, piv);
+            }
+
+            p.append_permutation(i, piv);
+
+            householder::clear_column_unchecked(&mut matrix, &mut diag[i], i, 0, None);
+
+            // Remove the contribution of the `i`-th row from the norms of the trailing columns.
+            for j in i + 1..ncols.value() {
+                if norms[j].is_zero() {
+                    continue;
+                }
+
+                let ratio = matrix[(i, j)].modulus() / norms[j];
+                let mut factor = N::RealField::one() - ratio * ratio;
+
+                if factor < N::RealField::zero() {
+                    factor = N::RealField::zero();
+                }
+
+                let rel = norms[j] / ref_norms[j];
+
+                if factor * rel * rel <= tol {
+                    // Too much cancellation occurred: recompute the norm.
+                    norms[j] = matrix.slice_range(i + 1.., j).norm();
+                    ref_norms[j] = norms[j];
+                } else {
+                    norms[j] *= factor.sqrt();
+                }
+            }
+        }
+
+        ColPivQR {
+            qr: QR::from_parts(matrix, diag),
+            p: p,
+        }
+    }
+
+    /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
+    #[inline]
+    pub fn r(&self) -> MatrixMN<N, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Allocator<N, DimMinimum<R, C>, C>,
+    {
+        self.qr.r()
+    }
+
+    /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
+    ///
+    /// This is usually faster than `r` but consumes `self`.
+    #[inline]
+    pub fn unpack_r(self) -> MatrixMN<N, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Reallocator<N, R, C, DimMinimum<R, C>, C>,
+    {
+        self.qr.unpack_r()
+    }
+
+    /// Computes the orthogonal matrix `Q` of this decomposition.
+    pub fn q(&self) -> MatrixMN<N, R, DimMinimum<R, C>>
+    where DefaultAllocator: Allocator<N, R, DimMinimum<R, C>> {
+        self.qr.q()
+    }
+
+    /// The column permutations of this decomposition.
+    #[inline]
+    pub fn p(&self) -> &PermutationSequence<DimMinimum<R, C>> {
+        &self.p
+    }
+
+    /// Unpacks this decomposition into its three factors `(Q, R, P)`.
+    pub fn unpack(
+        self,
+    ) -> (
+        MatrixMN<N, R, DimMinimum<R, C>>,
+        MatrixMN<N, DimMinimum<R, C>, C>,
+        PermutationSequence<DimMinimum<R, C>>,
+    )
+    where
+        DimMinimum<R, C>: DimMin<C, Output = DimMinimum<R, C>>,
+        DefaultAllocator: Allocator<N, R, DimMinimum<R, C>>
+            + Reallocator<N, R, C, DimMinimum<R, C>, C>
+            + Allocator<(usize, usize), DimMinimum<R, C>>,
+    {
+        let q = self.q();
+        let p = self.p.clone();
+
+        (q, self.unpack_r(), p)
+    }
+
+    #[doc(hidden)]
+    pub fn col_piv_qr_internal(&self) -> &MatrixMN<N, R, C> {
+        self.qr.qr_internal()
+    }
+
+    /// Multiplies the provided matrix by the transpose of the `Q` matrix of this decomposition.
+    pub fn q_tr_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where S2: StorageMut<N, R2, C2> {
+        self.qr.q_tr_mul(rhs)
+    }
+
+    /// Computes the rank of the decomposed matrix, i.e., the number of diagonal elements of `R`
+    /// with a magnitude greater than `eps`.
+    pub fn rank(&self, eps: N::RealField) -> usize {
+        assert!(
+            eps >= N::RealField::zero(),
+            "ColPivQR rank: the epsilon must be non-negative."
+        );
+
+        // Because of the pivoting, the magnitudes of the diagonal elements are non-increasing.
+        self.qr.diag_internal().iter().take_while(|e| e.modulus() > eps).count()
+    }
+
+    /// Computes an orthonormal basis of the null space (or kernel) of the decomposed matrix.
+    ///
+    /// The numerical rank of the decomposed matrix is determined using the threshold `eps` (see
+    /// `.rank(eps)`). Each column of the result is an element of the null space.
+    pub fn null_space(&self, eps: N::RealField) -> MatrixMN<N, C, Dynamic>
+    where DefaultAllocator: Allocator<N, C, Dynamic> {
+        let ncols = self.qr.qr_internal().data.shape().1;
+        let rank = self.rank(eps);
+        let nullity = ncols.value() - rank;
+        let mut res = MatrixMN::zeros_generic(ncols, Dynamic::new(nullity));
+
+        // With `A * P = Q * [R11 R12; 0 0]`, the null space of `A` is spanned by the columns of
+        // `P * [-R11⁻¹ * R12; I]`.
+        for j in 0..nullity {
+            for i in 0..rank {
+                res[(i, j)] = -self.r_elt(i, rank + j);
+            }
+
+            res[(rank + j, j)] = N::one();
+        }
+
+        {
+            let mut top = res.rows_range_mut(..rank);
+
+            for k in 0..top.ncols() {
+                let mut col = top.column_mut(k);
+
+                for i in (0..rank).rev() {
+                    let coeff = col[i] / self.r_elt(i, i);
+                    col[i] = coeff;
+
+                    for l in 0..i {
+                        col[l] -= self.r_elt(l, i) * coeff;
+                    }
+                }
+            }
+        }
+
+        self.p.inv_permute_rows(&mut res);
+
+        // Orthonormalize the basis with the modified Gram-Schmidt process.
+        for j in 0..nullity {
+            for k in 0..j {
+                let proj = res.column(k).dotc(&res.column(j));
+                let (mut col_j, col_k) = res.columns_range_pair_mut(j, k);
+                col_j.axpy(-proj, &col_k, N::one());
+            }
+
+            let _ = res.column_mut(j).normalize_mut();
+        }
+
+        res
+    }
+
+    /// The element `(i, j)` of the `R` factor of this decomposition, with `i <= j`.
+    #[inline]
+    fn r_elt(&self, i: usize, j: usize) -> N {
+        if i == j {
+            N::from_real(self.qr.diag_internal()[i].modulus())
+        } else {
+            self.qr.qr_internal()[(i, j)]
+        }
+    }
+}
+
+impl<N: ComplexField, D: DimMin<D, Output = D>> ColPivQR<N, D, D>
+where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>
+{
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
+    /// overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        if self.qr.solve_mut(b) {
+            self.p.inv_permute_rows(b);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible.
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Result<MatrixMN<N, R2, C2>, LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.try_solve_mut(&mut res)?;
+        Ok(res)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible, in which case
+    /// its input `b` is left unchanged.
+    pub fn try_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> Result<(), LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        self.qr.try_solve_mut(b)?;
+        self.p.inv_permute_rows(b);
+        Ok(())
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    pub fn try_inverse(&self) -> Option<MatrixN<N, D>> {
+        let mut res = self.qr.try_inverse()?;
+        self.p.inv_permute_rows(&mut res);
+        Some(res)
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    pub fn is_invertible(&self) -> bool {
+        self.qr.is_invertible()
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N {
+        let (qr, diags) = (self.qr.qr_internal(), self.qr.diag_internal());
+        let dim = qr.nrows();
+        assert!(
+            qr.is_square(),
+            "ColPivQR determinant: unable to compute the determinant of a non-square matrix."
+        );
+
+        // The i-th householder transformation is a reflection (with determinant -1) of the last
+        // `dim - i` rows followed by their multiplication by the conjugate of `diag[i].signum()`.
+        let mut res = self.p.determinant();
+        for i in 0..dim {
+            let diag = unsafe { *diags.vget_unchecked(i) };
+
+            if diag.is_zero() {
+                return N::zero();
+            }
+
+            let sign = diag.signum();
+            let mut sign_pow = N::one();
+            for _ in i..dim {
+                sign_pow *= sign;
+            }
+
+            res *= -N::from_real(diag.modulus()) * sign_pow;
+        }
+
+        res
+    }
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
+where DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+{
+    /// Computes the QR decomposition with column pivoting of this matrix.
+    pub fn col_piv_qr(self) -> ColPivQR<N, R, C>
+    where DefaultAllocator: Allocator<N::RealField, C> {
+        ColPivQR::new(self.into_owned())
+    }
+}
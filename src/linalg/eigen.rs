@@ -1,120 +1,256 @@
 #[cfg(feature = "serde-serialize")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
+use approx::AbsDiffEq;
 use alga::general::ComplexField;
-use num_complex::Complex;
-use std::cmp;
-use std::fmt::Display;
-use std::ops::MulAssign;
+use num::Zero;
+use num_complex::Complex as NumComplex;
 
 use crate::allocator::Allocator;
-use crate::base::dimension::{Dim, DimDiff, DimSub, Dynamic, U1, U2, U3};
+use crate::base::dimension::{Dim, DimDiff, DimSub, Dynamic, U1, U2};
 use crate::base::storage::Storage;
-use crate::base::{DefaultAllocator, Hessenberg, MatrixN, SquareMatrix, Unit, Vector2, Vector3, VectorN};
-use crate::constraint::{DimEq, ShapeConstraint};
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix, VectorN};
 
-use crate::geometry::{Reflection, UnitComplex};
-use crate::linalg::householder;
-use crate::linalg::Schur;
+use crate::linalg::schur;
+use crate::linalg::{LinalgError, Schur};
 
-/// Eigendecomposition of a real matrix with real eigenvalues (or complex eigen values for complex matrices).
+/// Eigendecomposition of a general real or complex square matrix.
+///
+/// The eigenvalues and eigenvectors are always complex. For each eigenvalue `λ_i`, the `i`-th
+/// column `v_i` of `self.eigenvectors` is a right eigenvector (`A * v_i = λ_i * v_i`) and the
+/// `i`-th column `u_i` of `self.left_eigenvectors` is a left eigenvector
+/// (`u_i.adjoint() * A = λ_i * u_i.adjoint()`). All eigenvectors have a unit norm.
+///
+/// If the decomposed matrix is defective, some eigenvectors will be (numerically) colinear.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize",
-    serde(
-        bound(
-            serialize = "DefaultAllocator: Allocator<N, D>,
-         VectorN<N, D>: Serialize,
-         MatrixN<N, D>: Serialize"
-        )
-    )
+    serde(bound(
+        serialize = "DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D> +
+                           Allocator<NumComplex<N::RealField>, D>,
+         VectorN<NumComplex<N::RealField>, D>: Serialize,
+         MatrixN<NumComplex<N::RealField>, D>: Serialize"
+    ))
 )]
 #[cfg_attr(
     feature = "serde-serialize",
-    serde(
-        bound(
-            deserialize = "DefaultAllocator: Allocator<N, D>,
-         VectorN<N, D>: Serialize,
-         MatrixN<N, D>: Deserialize<'de>"
-        )
-    )
+    serde(bound(
+        deserialize = "DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D> +
+                           Allocator<NumComplex<N::RealField>, D>,
+         VectorN<NumComplex<N::RealField>, D>: Deserialize<'de>,
+         MatrixN<NumComplex<N::RealField>, D>: Deserialize<'de>"
+    ))
 )]
 #[derive(Clone, Debug)]
 pub struct Eigen<N: ComplexField, D: Dim>
-where
-    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+where DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D> + Allocator<NumComplex<N::RealField>, D>
 {
-    pub eigenvectors: MatrixN<N, D>,
-    pub eigenvalues: VectorN<N, D>,
+    /// The unsorted eigenvalues of the decomposed matrix.
+    pub eigenvalues: VectorN<NumComplex<N::RealField>, D>,
+
+    /// The right eigenvectors of the decomposed matrix.
+    pub eigenvectors: MatrixN<NumComplex<N::RealField>, D>,
+
+    /// The left eigenvectors of the decomposed matrix.
+    pub left_eigenvectors: MatrixN<NumComplex<N::RealField>, D>,
 }
 
 impl<N: ComplexField, D: Dim> Copy for Eigen<N, D>
 where
-    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
-    MatrixN<N, D>: Copy,
-    VectorN<N, D>: Copy,
-{
-}
+    DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D> + Allocator<NumComplex<N::RealField>, D>,
+    MatrixN<NumComplex<N::RealField>, D>: Copy,
+    VectorN<NumComplex<N::RealField>, D>: Copy,
+{}
 
 impl<N: ComplexField, D: Dim> Eigen<N, D>
 where
     D: DimSub<U1>,                                   // For Hessenberg.
-    ShapeConstraint: DimEq<Dynamic, DimDiff<D, U1>>, // For Hessenberg.
     DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
         + Allocator<N, DimDiff<D, U1>>
         + Allocator<N, D, D>
-        + Allocator<N, D>,
-    // XXX: for debug
-    DefaultAllocator: Allocator<usize, D, D>,
-    MatrixN<N, D>: Display,
+        + Allocator<N, D>
+        + Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, D>,
 {
-    /// Computes the eigendecomposition of a diagonalizable matrix with Complex eigenvalues.
-    pub fn new(m: MatrixN<N, D>) -> Option<Eigen<N, D>> {
+    /// Computes the eigendecomposition of a square matrix.
+    pub fn new(m: MatrixN<N, D>) -> Self {
+        Self::try_new(m, N::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the eigendecomposition of a square matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(m: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Option<Self> {
         assert!(
             m.is_square(),
             "Unable to compute the eigendecomposition of a non-square matrix."
         );
 
-        let dim = m.nrows();
-        let (mut eigenvectors, mut eigenvalues) = Schur::new(m, 0).unwrap().unpack();
+        let (q, t) = Schur::try_new(m, eps, max_niter)?.unpack();
+        let mut q = q.map(|e| NumComplex::new(e.real(), e.imaginary()));
+        let mut t = t.map(|e| NumComplex::new(e.real(), e.imaginary()));
 
-        println!("Schur eigenvalues: {}", eigenvalues);
+        // The Schur form of a real matrix is only quasi-triangular: reduce each of its 2x2
+        // diagonal blocks (which have complex conjugate eigenvalues) to triangular form.
+        let dim = t.data.shape().0;
+        let n = dim.value();
+        let mut i = 0;
 
-        // Check that the eigenvalues are all Complex.
-        for i in 0..dim - 1 {
-            if !eigenvalues[(i + 1, i)].is_zero() {
-                return None;
+        while i + 1 < n {
+            if t[(i + 1, i)].is_zero() {
+                i += 1;
+                continue;
             }
+
+            if let Some(rot) = schur::compute_2x2_basis(&t.fixed_slice::<U2, U2>(i, i)) {
+                let inv_rot = rot.inverse();
+                inv_rot.rotate(&mut t.generic_slice_mut((i, i), (U2, Dynamic::new(n - i))));
+                rot.rotate_rows(&mut t.generic_slice_mut((0, i), (Dynamic::new(i + 2), U2)));
+                rot.rotate_rows(&mut q.generic_slice_mut((0, i), (dim, U2)));
+            }
+
+            t[(i + 1, i)] = NumComplex::zero();
+            i += 2;
+        }
+
+        let eigenvalues = t.diagonal();
+        let mut eigenvectors = &q * triangular_right_eigenvectors(&t, eps);
+        let mut left_eigenvectors = &q * triangular_left_eigenvectors(&t, eps);
+
+        for j in 0..n {
+            let _ = eigenvectors.column_mut(j).normalize_mut();
+            let _ = left_eigenvectors.column_mut(j).normalize_mut();
         }
 
-        for j in 1..dim {
-            for i in 0..j {
-                let diff = eigenvalues[(i, i)] - eigenvalues[(j, j)];
+        Some(Eigen {
+            eigenvalues,
+            eigenvectors,
+            left_eigenvectors,
+        })
+    }
+
+    /// Attempts to compute the eigendecomposition of a square matrix.
+    ///
+    /// Returns `LinalgError::NoConvergence` if the algorithm did not converge after `max_niter`
+    /// iterations. See `Eigen::try_new` for a description of the arguments.
+    pub fn try_decompose(m: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Result<Self, LinalgError> {
+        Self::try_new(m, eps, max_niter).ok_or(LinalgError::NoConvergence { niter: max_niter })
+    }
+}
+
+/// The smallest magnitude allowed for the pivots `t_ii - λ` of the triangular eigenvector solves.
+///
+/// Pivots smaller than this are perturbed so that repeated eigenvalues yield (nearly colinear)
+/// eigenvectors instead of infinite or NaN components.
+fn perturbation_threshold<N: ComplexField, D: Dim>(t: &MatrixN<N, D>, eps: N::RealField) -> N::RealField
+where DefaultAllocator: Allocator<N, D, D> {
+    let smin = eps * t.norm();
+
+    if smin.is_zero() {
+        eps
+    } else {
+        smin
+    }
+}
+
+fn perturbed_pivot<N: ComplexField>(pivot: N, smin: N::RealField) -> N {
+    if pivot.modulus() < smin {
+        N::from_real(smin)
+    } else {
+        pivot
+    }
+}
 
-                if diff.is_zero() && !eigenvalues[(i, j)].is_zero() {
-                    return None;
-                }
+/// Computes the right eigenvectors of the upper-triangular matrix `t` by back-substitution.
+fn triangular_right_eigenvectors<N: ComplexField, D: Dim>(t: &MatrixN<N, D>, eps: N::RealField) -> MatrixN<N, D>
+where DefaultAllocator: Allocator<N, D, D> {
+    let dim = t.data.shape().0;
+    let smin = perturbation_threshold(t, eps);
+    let mut x = MatrixN::zeros_generic(dim, dim);
 
-                let z = -eigenvalues[(i, j)] / diff;
+    for k in 0..dim.value() {
+        let lambda = t[(k, k)];
+        x[(k, k)] = N::one();
 
-                for k in j + 1..dim {
-                    eigenvalues[(i, k)] -= z * eigenvalues[(j, k)];
-                }
+        for i in (0..k).rev() {
+            let mut s = N::zero();
 
-                for k in 0..dim {
-                    eigenvectors[(k, j)] += z * eigenvectors[(k, i)];
-                }
+            for j in i + 1..=k {
+                s += t[(i, j)] * x[(j, k)];
             }
+
+            x[(i, k)] = -s / perturbed_pivot(t[(i, i)] - lambda, smin);
         }
 
-        // Normalize the eigenvector basis.
-        for i in 0..dim {
-            let _ = eigenvectors.column_mut(i).normalize_mut();
+        // Prevent overflows caused by the perturbed pivots.
+        let _ = x.column_mut(k).normalize_mut();
+    }
+
+    x
+}
+
+/// Computes the left eigenvectors of the upper-triangular matrix `t` by forward-substitution.
+fn triangular_left_eigenvectors<N: ComplexField, D: Dim>(t: &MatrixN<N, D>, eps: N::RealField) -> MatrixN<N, D>
+where DefaultAllocator: Allocator<N, D, D> {
+    let dim = t.data.shape().0;
+    let smin = perturbation_threshold(t, eps);
+    let mut w = MatrixN::zeros_generic(dim, dim);
+
+    // Each column `w` satisfies `w.transpose() * (t - λ) = 0`. The left eigenvector is its
+    // conjugate.
+    for k in 0..dim.value() {
+        let lambda = t[(k, k)];
+        w[(k, k)] = N::one();
+
+        for i in k + 1..dim.value() {
+            let mut s = N::zero();
+
+            for j in k..i {
+                s += w[(j, k)] * t[(j, i)];
+            }
+
+            w[(i, k)] = -s / perturbed_pivot(t[(i, i)] - lambda, smin);
         }
 
-        Some(Eigen {
-            eigenvectors,
-            eigenvalues: eigenvalues.diagonal(),
-        })
+        let _ = w.column_mut(k).normalize_mut();
+    }
+
+    w.conjugate_mut();
+    w
+}
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    D: DimSub<U1>,                                   // For Hessenberg.
+    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, D>,
+{
+    /// Computes the eigendecomposition of this square matrix.
+    ///
+    /// Both the left and right eigenvectors are computed. If only the eigenvalues are needed, it
+    /// is more efficient to call `.complex_eigenvalues()` instead.
+    pub fn eigen(self) -> Eigen<N, D> {
+        Eigen::new(self.into_owned())
+    }
+
+    /// Attempts to compute the eigendecomposition of this square matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_eigen(self, eps: N::RealField, max_niter: usize) -> Option<Eigen<N, D>> {
+        Eigen::try_new(self.into_owned(), eps, max_niter)
     }
 }
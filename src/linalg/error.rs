@@ -0,0 +1,41 @@
+use std::fmt;
+
+/// The reason why a matrix decomposition, or an operation based on a matrix decomposition,
+/// failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LinalgError {
+    /// The matrix is not positive-definite.
+    NotPositiveDefinite,
+    /// The matrix is singular: the decomposition has a zero pivot at the index `pivot`.
+    Singular {
+        /// The index of the first zero pivot of the decomposition.
+        pivot: usize,
+    },
+    /// The iterative algorithm did not converge after `niter` iterations.
+    NoConvergence {
+        /// The number of iterations performed before giving up.
+        niter: usize,
+    },
+    /// The left singular vectors `U` of the SVD have not been computed.
+    MissingU,
+    /// The right singular vectors `V^t` of the SVD have not been computed.
+    MissingVt,
+    /// The tolerance given to the operation is negative.
+    InvalidTolerance,
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LinalgError::NotPositiveDefinite => write!(f, "the matrix is not positive-definite"),
+            LinalgError::Singular { pivot } => write!(f, "the matrix is singular (zero pivot at index {})", pivot),
+            LinalgError::NoConvergence { niter } => write!(f, "the algorithm did not converge after {} iterations", niter),
+            LinalgError::MissingU => write!(f, "the singular vectors U have not been computed"),
+            LinalgError::MissingVt => write!(f, "the singular vectors V^t have not been computed"),
+            LinalgError::InvalidTolerance => write!(f, "the tolerance must be non-negative"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LinalgError {}
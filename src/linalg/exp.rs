@@ -0,0 +1,178 @@
+//! Matrix exponential.
+
+use alga::general::ComplexField;
+use num::{One, Zero};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimMin};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix};
+
+// Coefficients of the numerators of the Padé approximants of degree 3, 5, 7, 9 and 13 of the
+// exponential function. See N. J. Higham, "The scaling and squaring method for the matrix
+// exponential revisited", SIAM J. Matrix Anal. Appl., 26(4), 2005.
+const PADE3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const PADE9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+const PADE13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+// The largest 1-norms for which the Padé approximant of degree 3, 5, 7, 9 and 13 (respectively)
+// is accurate up to double-precision.
+const THETA3: f64 = 1.495585217958292e-2;
+const THETA5: f64 = 2.539398330063230e-1;
+const THETA7: f64 = 9.504178996162932e-1;
+const THETA9: f64 = 2.097847961257068e0;
+const THETA13: f64 = 5.371920351148152e0;
+
+/// The maximum absolute column sum of `m`.
+pub(crate) fn one_norm<N: ComplexField, D: Dim, S: Storage<N, D, D>>(
+    m: &SquareMatrix<N, D, S>,
+) -> N::RealField {
+    let mut res = N::RealField::zero();
+
+    for j in 0..m.ncols() {
+        let sum = m.column(j).iter().fold(N::RealField::zero(), |acc, e| acc + e.modulus());
+        if sum > res {
+            res = sum;
+        }
+    }
+
+    res
+}
+
+impl<N: ComplexField, D: DimMin<D, Output = D>, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D>
+{
+    /// Computes the exponential of this square matrix.
+    ///
+    /// This uses the scaling and squaring method with Padé approximants. No heap allocation is
+    /// performed if this matrix is statically-sized.
+    pub fn exp(&self) -> MatrixN<N, D> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the exponential of a non-square matrix."
+        );
+
+        let (dim, _) = self.data.shape();
+        let id = MatrixN::<N, D>::identity_generic(dim, dim);
+        let norm = one_norm(self);
+
+        let (u, v, nsquarings) = if norm <= crate::convert(THETA3) {
+            let (u, v) = pade_low_degree(self.clone_owned(), &id, &PADE3);
+            (u, v, 0)
+        } else if norm <= crate::convert(THETA5) {
+            let (u, v) = pade_low_degree(self.clone_owned(), &id, &PADE5);
+            (u, v, 0)
+        } else if norm <= crate::convert(THETA7) {
+            let (u, v) = pade_low_degree(self.clone_owned(), &id, &PADE7);
+            (u, v, 0)
+        } else if norm <= crate::convert(THETA9) {
+            let (u, v) = pade_low_degree(self.clone_owned(), &id, &PADE9);
+            (u, v, 0)
+        } else {
+            // Scale the matrix so that its norm becomes smaller than THETA13.
+            let theta13: N::RealField = crate::convert(THETA13);
+            let two: N::RealField = crate::convert(2.0);
+            let mut scaled_norm = norm;
+            let mut scale = N::RealField::one();
+            let mut nsquarings = 0;
+
+            while scaled_norm > theta13 {
+                scaled_norm /= two;
+                scale *= two;
+                nsquarings += 1;
+            }
+
+            let a = self.unscale(scale);
+            let (u, v) = pade13(a, &id);
+            (u, v, nsquarings)
+        };
+
+        let mut res = (&v - &u)
+            .lu()
+            .solve(&(v + u))
+            .expect("Matrix exponential: the Padé denominator is not invertible.");
+
+        for _ in 0..nsquarings {
+            res = &res * &res;
+        }
+
+        res
+    }
+}
+
+/// Computes the odd and even parts `(U, V)` of the Padé approximant of degree lower than 13.
+fn pade_low_degree<N: ComplexField, D: Dim>(
+    a: MatrixN<N, D>,
+    id: &MatrixN<N, D>,
+    coeffs: &[f64],
+) -> (MatrixN<N, D>, MatrixN<N, D>)
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let a2 = &a * &a;
+    let mut u = id * crate::convert::<f64, N>(coeffs[1]);
+    let mut v = id * crate::convert::<f64, N>(coeffs[0]);
+    let mut pow = id.clone();
+
+    for k in 1..coeffs.len() / 2 {
+        pow = &pow * &a2;
+        u += &pow * crate::convert::<f64, N>(coeffs[2 * k + 1]);
+        v += &pow * crate::convert::<f64, N>(coeffs[2 * k]);
+    }
+
+    (a * u, v)
+}
+
+/// Computes the odd and even parts `(U, V)` of the Padé approximant of degree 13.
+fn pade13<N: ComplexField, D: Dim>(
+    a: MatrixN<N, D>,
+    id: &MatrixN<N, D>,
+) -> (MatrixN<N, D>, MatrixN<N, D>)
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let b = |i: usize| crate::convert::<f64, N>(PADE13[i]);
+    let a2 = &a * &a;
+    let a4 = &a2 * &a2;
+    let a6 = &a4 * &a2;
+
+    let u1 = &a6 * b(13) + &a4 * b(11) + &a2 * b(9);
+    let u2 = &a6 * b(7) + &a4 * b(5) + &a2 * b(3) + id * b(1);
+    let u = a * (&a6 * u1 + u2);
+
+    let v1 = &a6 * b(12) + &a4 * b(10) + &a2 * b(8);
+    let v2 = &a6 * b(6) + &a4 * b(4) + &a2 * b(2) + id * b(0);
+    let v = &a6 * v1 + v2;
+
+    (u, v)
+}
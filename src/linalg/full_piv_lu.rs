@@ -9,7 +9,7 @@ use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
 
 use crate::linalg::lu;
-use crate::linalg::PermutationSequence;
+use crate::linalg::{LinalgError, PermutationSequence};
 
 /// LU decomposition with full row and column pivoting.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -210,6 +210,46 @@ where DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D>
         }
     }
 
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible.
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Result<MatrixMN<N, R2, C2>, LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.try_solve_mut(&mut res)?;
+        Ok(res)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible, in which case
+    /// its input `b` is left unchanged.
+    pub fn try_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> Result<(), LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        match self.first_zero_pivot() {
+            Some(pivot) => Err(LinalgError::Singular { pivot }),
+            None => {
+                let _ = self.solve_mut(b);
+                Ok(())
+            }
+        }
+    }
+
+    // The index of the first zero pivot of this decomposition, if any.
+    fn first_zero_pivot(&self) -> Option<usize> {
+        (0..self.lu.nrows()).find(|i| self.lu[(*i, *i)].is_zero())
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the decomposed matrix is not invertible.
@@ -0,0 +1,551 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use alga::general::{ComplexField, RealField};
+use num::Zero;
+use num_complex::Complex as NumComplex;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, Dynamic, U2};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix, Vector2, VectorN};
+
+use crate::linalg::givens::GivensRotation;
+use crate::linalg::LinalgError;
+
+/// Generalized eigendecomposition of a pair of general real or complex square matrices.
+///
+/// This solves the generalized eigenvalue problem `A * x = λ * B * x`. Each generalized
+/// eigenvalue is represented as a ratio `λ_i = α_i / β_i` where `β_i` is real and non-negative:
+/// a zero `β_i` indicates an infinite eigenvalue, which happens when `B` is singular.
+///
+/// For each eigenvalue, the `i`-th column `v_i` of `self.eigenvectors` is a right eigenvector
+/// (`β_i * A * v_i = α_i * B * v_i`) and the `i`-th column `u_i` of `self.left_eigenvectors` is a
+/// left eigenvector (`β_i * u_i.adjoint() * A = α_i * u_i.adjoint() * B`). All eigenvectors have a
+/// unit norm.
+///
+/// This is computed with the QZ algorithm of Moler and Stewart, operating on complex numbers.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        serialize = "DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D> +
+                           Allocator<NumComplex<N::RealField>, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<NumComplex<N::RealField>, D>: Serialize,
+         VectorN<N::RealField, D>: Serialize,
+         MatrixN<NumComplex<N::RealField>, D>: Serialize"
+    ))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        deserialize = "DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D> +
+                           Allocator<NumComplex<N::RealField>, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<NumComplex<N::RealField>, D>: Deserialize<'de>,
+         VectorN<N::RealField, D>: Deserialize<'de>,
+         MatrixN<NumComplex<N::RealField>, D>: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedEigen<N: ComplexField, D: Dim>
+where DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, D>
+        + Allocator<N::RealField, D>
+{
+    /// The unsorted numerators `α_i` of the generalized eigenvalues.
+    pub alphas: VectorN<NumComplex<N::RealField>, D>,
+
+    /// The denominators `β_i` of the generalized eigenvalues.
+    pub betas: VectorN<N::RealField, D>,
+
+    /// The right generalized eigenvectors.
+    pub eigenvectors: MatrixN<NumComplex<N::RealField>, D>,
+
+    /// The left generalized eigenvectors.
+    pub left_eigenvectors: MatrixN<NumComplex<N::RealField>, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for GeneralizedEigen<N, D>
+where
+    DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, D>
+        + Allocator<N::RealField, D>,
+    MatrixN<NumComplex<N::RealField>, D>: Copy,
+    VectorN<NumComplex<N::RealField>, D>: Copy,
+    VectorN<N::RealField, D>: Copy,
+{}
+
+impl<N: ComplexField, D: Dim> GeneralizedEigen<N, D>
+where DefaultAllocator: Allocator<N, D, D>
+        + Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, D>
+        + Allocator<N::RealField, D>
+{
+    /// Computes the generalized eigendecomposition of the pair of square matrices `(a, b)`.
+    pub fn new(a: MatrixN<N, D>, b: MatrixN<N, D>) -> Self {
+        Self::try_new(a, b, N::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the generalized eigendecomposition of the pair of square matrices
+    /// `(a, b)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(a: MatrixN<N, D>, b: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Option<Self> {
+        assert!(
+            a.is_square() && a.shape() == b.shape(),
+            "Unable to compute the generalized eigendecomposition of non-square or mismatched matrices."
+        );
+
+        let mut s = a.map(|e| NumComplex::new(e.real(), e.imaginary()));
+        let mut t = b.map(|e| NumComplex::new(e.real(), e.imaginary()));
+        let dim = s.data.shape().0;
+        let n = dim.value();
+        let mut q = MatrixN::identity_generic(dim, dim);
+        let mut z = MatrixN::identity_generic(dim, dim);
+
+        hessenberg_triangular(&mut s, &mut t, &mut q, &mut z);
+
+        if !qz_iterations(&mut s, &mut t, &mut q, &mut z, eps, max_niter) {
+            return None;
+        }
+
+        // Normalize each pair `(α, β)` so that `β` is real and non-negative.
+        let mut alphas = s.diagonal();
+        let mut betas = VectorN::zeros_generic(dim, crate::dimension::U1);
+
+        for k in 0..n {
+            let beta = t[(k, k)];
+            let beta_mod = beta.modulus();
+
+            if !beta_mod.is_zero() {
+                alphas[k] = alphas[k] * beta.conjugate().unscale(beta_mod);
+            }
+
+            betas[k] = beta_mod;
+        }
+
+        let mut eigenvectors = &z * triangular_pencil_right_eigenvectors(&s, &t, eps);
+        let mut left_eigenvectors = &q * triangular_pencil_left_eigenvectors(&s, &t, eps);
+
+        for j in 0..n {
+            let _ = eigenvectors.column_mut(j).normalize_mut();
+            let _ = left_eigenvectors.column_mut(j).normalize_mut();
+        }
+
+        Some(GeneralizedEigen {
+            alphas,
+            betas,
+            eigenvectors,
+            left_eigenvectors,
+        })
+    }
+
+    /// Attempts to compute the generalized eigendecomposition of the pair of square matrices `(a, b)`.
+    ///
+    /// Returns `LinalgError::NoConvergence` if the algorithm did not converge after `max_niter`
+    /// iterations. See `GeneralizedEigen::try_new` for a description of the arguments.
+    pub fn try_decompose(
+        a: MatrixN<N, D>,
+        b: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError>
+    {
+        Self::try_new(a, b, eps, max_niter).ok_or(LinalgError::NoConvergence { niter: max_niter })
+    }
+}
+
+impl<N: ComplexField, D: Dim> GeneralizedEigen<N, D>
+where DefaultAllocator: Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, D>
+        + Allocator<N::RealField, D>
+{
+    /// The generalized eigenvalues `λ_i = α_i / β_i`.
+    ///
+    /// The infinite eigenvalues (for which `β_i` is zero) have non-finite components.
+    pub fn eigenvalues(&self) -> VectorN<NumComplex<N::RealField>, D> {
+        self.alphas.zip_map(&self.betas, |alpha, beta| alpha.unscale(beta))
+    }
+}
+
+/// Computes the rotation `G` such that the first component of `[a, b] * G` is zero.
+fn cancel_left<N: RealField>(a: NumComplex<N>, b: NumComplex<N>) -> Option<GivensRotation<NumComplex<N>>> {
+    if a.is_zero() {
+        return None;
+    }
+
+    let b_mod = b.modulus();
+
+    if b_mod.is_zero() {
+        return Some(GivensRotation::new_unchecked(N::zero(), NumComplex::new(N::one(), N::zero())));
+    }
+
+    let norm = (a.modulus_squared() + b.modulus_squared()).sqrt();
+    let c = b_mod / norm;
+    let s = -(a * b.conjugate()).unscale(b_mod * norm);
+
+    Some(GivensRotation::new_unchecked(c, s))
+}
+
+/// Computes the rotation `G` such that the second component of `G * [x, y]` is zero.
+fn cancel_down<N: RealField>(x: NumComplex<N>, y: NumComplex<N>) -> Option<GivensRotation<NumComplex<N>>> {
+    GivensRotation::cancel_y(&Vector2::new(x, y)).map(|(rot, _)| rot)
+}
+
+/// Applies `rot` to the rows `i` and `i + 1` of `m`, starting at the column `start`.
+fn rotate_rows_from<N: RealField, D: Dim>(rot: &GivensRotation<NumComplex<N>>, m: &mut MatrixN<NumComplex<N>, D>, i: usize, start: usize)
+where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    let ncols = m.ncols();
+    rot.rotate(&mut m.generic_slice_mut((i, start), (U2, Dynamic::new(ncols - start))));
+}
+
+/// Applies `rot` to the columns `j` and `j + 1` of `m`, on its first `nrows` rows.
+fn rotate_columns_upto<N: RealField, D: Dim>(rot: &GivensRotation<NumComplex<N>>, m: &mut MatrixN<NumComplex<N>, D>, j: usize, nrows: usize)
+where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    rot.rotate_rows(&mut m.generic_slice_mut((0, j), (Dynamic::new(nrows), U2)));
+}
+
+/// Reduces the pair `(s, t)` to an upper-Hessenberg and upper-triangular pair, updating the
+/// unitary matrices `q` and `z` so that `q * s * z.adjoint()` and `q * t * z.adjoint()` remain
+/// constant.
+fn hessenberg_triangular<N: RealField, D: Dim>(
+    s: &mut MatrixN<NumComplex<N>, D>,
+    t: &mut MatrixN<NumComplex<N>, D>,
+    q: &mut MatrixN<NumComplex<N>, D>,
+    z: &mut MatrixN<NumComplex<N>, D>,
+) where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    let n = s.nrows();
+
+    // Triangularize `t`.
+    for j in 0..n {
+        for i in (j + 1..n).rev() {
+            if let Some(rot) = cancel_down(t[(i - 1, j)], t[(i, j)]) {
+                rotate_rows_from(&rot, t, i - 1, j);
+                rotate_rows_from(&rot, s, i - 1, 0);
+                rotate_columns_upto(&rot.inverse(), q, i - 1, n);
+                t[(i, j)] = NumComplex::zero();
+            }
+        }
+    }
+
+    // Reduce `s` to Hessenberg form while keeping `t` triangular.
+    for j in 0..n.saturating_sub(2) {
+        for i in (j + 2..n).rev() {
+            if let Some(rot) = cancel_down(s[(i - 1, j)], s[(i, j)]) {
+                rotate_rows_from(&rot, s, i - 1, j);
+                rotate_rows_from(&rot, t, i - 1, i - 1);
+                rotate_columns_upto(&rot.inverse(), q, i - 1, n);
+                s[(i, j)] = NumComplex::zero();
+            }
+
+            if let Some(rot) = cancel_left(t[(i, i - 1)], t[(i, i)]) {
+                rotate_columns_upto(&rot, t, i - 1, i + 1);
+                rotate_columns_upto(&rot, s, i - 1, n);
+                rotate_columns_upto(&rot, z, i - 1, n);
+                t[(i, i - 1)] = NumComplex::zero();
+            }
+        }
+    }
+}
+
+/// Reduces the upper-Hessenberg and upper-triangular pair `(s, t)` to the generalized Schur form
+/// (where both are upper-triangular) with single-shift QZ iterations.
+///
+/// Returns `false` if the algorithm did not converge after `max_niter` iterations.
+fn qz_iterations<N: RealField, D: Dim>(
+    s: &mut MatrixN<NumComplex<N>, D>,
+    t: &mut MatrixN<NumComplex<N>, D>,
+    q: &mut MatrixN<NumComplex<N>, D>,
+    z: &mut MatrixN<NumComplex<N>, D>,
+    eps: N,
+    max_niter: usize,
+) -> bool
+where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    let n = s.nrows();
+
+    if n == 0 {
+        return true;
+    }
+
+    let s_norm = s.norm();
+    let t_tol = eps * t.norm();
+    let mut niter = 0;
+    let mut niter_since_deflation = 0;
+    let mut exceptional_shift = NumComplex::zero();
+    let mut ihi = n - 1;
+
+    while ihi > 0 {
+        // Find the start `ilo` of the unreduced block ending at `ihi`.
+        let mut ilo = ihi;
+
+        while ilo > 0 {
+            let mut scale = s[(ilo, ilo)].modulus() + s[(ilo - 1, ilo - 1)].modulus();
+
+            if scale.is_zero() {
+                scale = s_norm;
+            }
+
+            if s[(ilo, ilo - 1)].modulus() <= eps * scale {
+                s[(ilo, ilo - 1)] = NumComplex::zero();
+                break;
+            }
+
+            ilo -= 1;
+        }
+
+        if ilo == ihi {
+            // A 1x1 block converged.
+            ihi -= 1;
+            niter_since_deflation = 0;
+            exceptional_shift = NumComplex::zero();
+            continue;
+        }
+
+        // A zero on the diagonal of `t` yields an infinite eigenvalue that can be deflated.
+        if let Some(j) = (ilo..=ihi).find(|j| t[(*j, *j)].modulus() <= t_tol) {
+            t[(j, j)] = NumComplex::zero();
+
+            if j == ilo {
+                // Split the 1x1 block at the top.
+                if let Some(rot) = cancel_down(s[(ilo, ilo)], s[(ilo + 1, ilo)]) {
+                    rotate_rows_from(&rot, s, ilo, ilo);
+                    rotate_rows_from(&rot, t, ilo, ilo);
+                    rotate_columns_upto(&rot.inverse(), q, ilo, n);
+                    s[(ilo + 1, ilo)] = NumComplex::zero();
+                }
+            } else {
+                // Chase the zero down to the bottom of the block, and split the 1x1 block there.
+                for k in j..ihi {
+                    if let Some(rot) = cancel_down(t[(k, k + 1)], t[(k + 1, k + 1)]) {
+                        rotate_rows_from(&rot, t, k, k + 1);
+                        rotate_rows_from(&rot, s, k, k - 1);
+                        rotate_columns_upto(&rot.inverse(), q, k, n);
+                        t[(k + 1, k + 1)] = NumComplex::zero();
+                    }
+
+                    if let Some(rot) = cancel_left(s[(k + 1, k - 1)], s[(k + 1, k)]) {
+                        rotate_columns_upto(&rot, s, k - 1, k + 2);
+                        rotate_columns_upto(&rot, t, k - 1, k + 1);
+                        rotate_columns_upto(&rot, z, k - 1, n);
+                        s[(k + 1, k - 1)] = NumComplex::zero();
+                    }
+                }
+
+                if let Some(rot) = cancel_left(s[(ihi, ihi - 1)], s[(ihi, ihi)]) {
+                    rotate_columns_upto(&rot, s, ihi - 1, ihi + 1);
+                    rotate_columns_upto(&rot, t, ihi - 1, ihi + 1);
+                    rotate_columns_upto(&rot, z, ihi - 1, n);
+                    s[(ihi, ihi - 1)] = NumComplex::zero();
+                }
+            }
+
+            continue;
+        }
+
+        niter += 1;
+        niter_since_deflation += 1;
+
+        if niter == max_niter {
+            return false;
+        }
+
+        let shift = if niter_since_deflation % 10 == 0 {
+            // Exceptional shift to break cycles.
+            exceptional_shift += s[(ihi, ihi - 1)] / t[(ihi - 1, ihi - 1)];
+            exceptional_shift
+        } else {
+            pencil_2x2_shift(s, t, ihi)
+        };
+
+        // Single-shift QZ sweep on the block `ilo..=ihi`.
+        for k in ilo..ihi {
+            let rot = if k == ilo {
+                cancel_down(s[(ilo, ilo)] - shift * t[(ilo, ilo)], s[(ilo + 1, ilo)])
+            } else {
+                cancel_down(s[(k, k - 1)], s[(k + 1, k - 1)])
+            };
+
+            if let Some(rot) = rot {
+                rotate_rows_from(&rot, s, k, if k == ilo { k } else { k - 1 });
+                rotate_rows_from(&rot, t, k, k);
+                rotate_columns_upto(&rot.inverse(), q, k, n);
+
+                if k > ilo {
+                    s[(k + 1, k - 1)] = NumComplex::zero();
+                }
+            }
+
+            if let Some(rot) = cancel_left(t[(k + 1, k)], t[(k + 1, k + 1)]) {
+                rotate_columns_upto(&rot, t, k, k + 2);
+                rotate_columns_upto(&rot, s, k, (k + 3).min(ihi + 1));
+                rotate_columns_upto(&rot, z, k, n);
+                t[(k + 1, k)] = NumComplex::zero();
+            }
+        }
+    }
+
+    true
+}
+
+/// The eigenvalue of the trailing 2x2 block of the pencil `(s, t)` ending at `ihi` that is the
+/// closest to `s[(ihi, ihi)] / t[(ihi, ihi)]`.
+fn pencil_2x2_shift<N: RealField, D: Dim>(s: &MatrixN<NumComplex<N>, D>, t: &MatrixN<NumComplex<N>, D>, ihi: usize) -> NumComplex<N>
+where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    let (s11, s12, s21, s22) = (s[(ihi - 1, ihi - 1)], s[(ihi - 1, ihi)], s[(ihi, ihi - 1)], s[(ihi, ihi)]);
+    let (t11, t12, t22) = (t[(ihi - 1, ihi - 1)], t[(ihi - 1, ihi)], t[(ihi, ihi)]);
+
+    // The eigenvalues are the roots of `a * λ² - 2 * b * λ + c`.
+    let half: N = crate::convert(0.5);
+    let a = t11 * t22;
+    let b = (s11 * t22 + s22 * t11 - t12 * s21).scale(half);
+    let c = s11 * s22 - s12 * s21;
+    let sqrt_disc = (b * b - a * c).sqrt();
+
+    let target = s22 / t22;
+    let lambda1 = (b + sqrt_disc) / a;
+    let lambda2 = (b - sqrt_disc) / a;
+
+    if (lambda1 - target).modulus() <= (lambda2 - target).modulus() {
+        lambda1
+    } else {
+        lambda2
+    }
+}
+
+/// The smallest magnitude allowed for the pivots of the triangular eigenvector solves of the
+/// pencil `(s, t)` with the eigenvalue `(alpha, beta)`.
+fn pencil_perturbation_threshold<N: RealField, D: Dim>(
+    s: &MatrixN<NumComplex<N>, D>,
+    t: &MatrixN<NumComplex<N>, D>,
+    alpha: NumComplex<N>,
+    beta: NumComplex<N>,
+    eps: N,
+) -> N
+where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    let smin = eps * (beta.modulus() * s.norm() + alpha.modulus() * t.norm());
+
+    if smin.is_zero() {
+        eps
+    } else {
+        smin
+    }
+}
+
+fn perturbed_pivot<N: RealField>(pivot: NumComplex<N>, smin: N) -> NumComplex<N> {
+    if pivot.modulus() < smin {
+        NumComplex::new(smin, N::zero())
+    } else {
+        pivot
+    }
+}
+
+/// Computes the right eigenvectors of the upper-triangular pencil `(s, t)` by back-substitution.
+fn triangular_pencil_right_eigenvectors<N: RealField, D: Dim>(
+    s: &MatrixN<NumComplex<N>, D>,
+    t: &MatrixN<NumComplex<N>, D>,
+    eps: N,
+) -> MatrixN<NumComplex<N>, D>
+where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    let dim = s.data.shape().0;
+    let mut x = MatrixN::zeros_generic(dim, dim);
+
+    for k in 0..dim.value() {
+        // Each column `x` satisfies `(β * s - α * t) * x = 0`.
+        let (alpha, beta) = (s[(k, k)], t[(k, k)]);
+        let smin = pencil_perturbation_threshold(s, t, alpha, beta, eps);
+        x[(k, k)] = NumComplex::new(N::one(), N::zero());
+
+        for i in (0..k).rev() {
+            let mut acc = NumComplex::<N>::zero();
+
+            for j in i + 1..=k {
+                acc += (beta * s[(i, j)] - alpha * t[(i, j)]) * x[(j, k)];
+            }
+
+            let pivot = beta * s[(i, i)] - alpha * t[(i, i)];
+            x[(i, k)] = -acc / perturbed_pivot(pivot, smin);
+        }
+
+        // Prevent overflows caused by the perturbed pivots.
+        let _ = x.column_mut(k).normalize_mut();
+    }
+
+    x
+}
+
+/// Computes the left eigenvectors of the upper-triangular pencil `(s, t)` by forward-substitution.
+fn triangular_pencil_left_eigenvectors<N: RealField, D: Dim>(
+    s: &MatrixN<NumComplex<N>, D>,
+    t: &MatrixN<NumComplex<N>, D>,
+    eps: N,
+) -> MatrixN<NumComplex<N>, D>
+where DefaultAllocator: Allocator<NumComplex<N>, D, D> {
+    let dim = s.data.shape().0;
+    let mut w = MatrixN::zeros_generic(dim, dim);
+
+    // Each column `w` satisfies `w.transpose() * (β * s - α * t) = 0`. The left eigenvector is
+    // its conjugate.
+    for k in 0..dim.value() {
+        let (alpha, beta) = (s[(k, k)], t[(k, k)]);
+        let smin = pencil_perturbation_threshold(s, t, alpha, beta, eps);
+        w[(k, k)] = NumComplex::new(N::one(), N::zero());
+
+        for i in k + 1..dim.value() {
+            let mut acc = NumComplex::<N>::zero();
+
+            for j in k..i {
+                acc += w[(j, k)] * (beta * s[(j, i)] - alpha * t[(j, i)]);
+            }
+
+            let pivot = beta * s[(i, i)] - alpha * t[(i, i)];
+            w[(i, k)] = -acc / perturbed_pivot(pivot, smin);
+        }
+
+        let _ = w.column_mut(k).normalize_mut();
+    }
+
+    w.conjugate_mut();
+    w
+}
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where DefaultAllocator: Allocator<N, D, D>
+        + Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, D>
+        + Allocator<N::RealField, D>
+{
+    /// Computes the generalized eigendecomposition of the pair `(self, b)`, i.e., the solutions
+    /// of `self * x = λ * b * x`.
+    ///
+    /// Use `.symmetric_generalized_eigen(b)` instead if `self` is symmetric (or hermitian) and
+    /// `b` is positive-definite.
+    pub fn generalized_eigen(self, b: MatrixN<N, D>) -> GeneralizedEigen<N, D> {
+        GeneralizedEigen::new(self.into_owned(), b)
+    }
+
+    /// Attempts to compute the generalized eigendecomposition of the pair `(self, b)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_generalized_eigen(
+        self,
+        b: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Option<GeneralizedEigen<N, D>>
+    {
+        GeneralizedEigen::try_new(self.into_owned(), b, eps, max_niter)
+    }
+}
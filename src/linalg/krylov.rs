@@ -0,0 +1,547 @@
+//! Iterative Krylov subspace solvers for square linear systems.
+
+use alga::general::{ClosedAdd, ClosedMul, RealField};
+use num::{One, Zero};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, Scalar, Vector, VectorN};
+use crate::dimension::Dim;
+use crate::storage::Storage;
+
+/// A square linear operator `A`, i.e., an object able to compute the product `A * x`.
+///
+/// This is implemented for dense square matrices and for sparse matrices.
+pub trait LinearOperator<N: Scalar, D: Dim>
+where DefaultAllocator: Allocator<N, D>
+{
+    /// Computes `out = self * x`.
+    fn apply(&self, x: &VectorN<N, D>, out: &mut VectorN<N, D>);
+}
+
+/// A preconditioner, i.e., an approximation `M` of a linear operator for which the systems
+/// `M * z = r` are cheap to solve.
+pub trait Preconditioner<N: Scalar, D: Dim>
+where DefaultAllocator: Allocator<N, D>
+{
+    /// Computes `z = M⁻¹ * r`.
+    fn solve(&self, r: &VectorN<N, D>, z: &mut VectorN<N, D>);
+}
+
+impl<N, D: Dim, S> LinearOperator<N, D> for Matrix<N, D, D, S>
+where
+    N: Scalar + Zero + One + ClosedAdd + ClosedMul,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D>,
+{
+    #[inline]
+    fn apply(&self, x: &VectorN<N, D>, out: &mut VectorN<N, D>) {
+        out.gemv(N::one(), self, x, N::zero());
+    }
+}
+
+/// The Jacobi (or diagonal) preconditioner.
+#[derive(Clone, Debug)]
+pub struct JacobiPreconditioner<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<N, D>
+{
+    inv_diagonal: VectorN<N, D>,
+}
+
+impl<N: RealField, D: Dim> JacobiPreconditioner<N, D>
+where DefaultAllocator: Allocator<N, D>
+{
+    /// Creates the Jacobi preconditioner of a matrix with the given diagonal.
+    ///
+    /// Zero diagonal elements are replaced by 1.
+    pub fn new<S: Storage<N, D>>(diagonal: &Vector<N, D, S>) -> Self {
+        let inv_diagonal = diagonal.map(|e| if e.is_zero() { N::one() } else { N::one() / e });
+        JacobiPreconditioner { inv_diagonal }
+    }
+
+    /// Creates the Jacobi preconditioner of the given dense square matrix.
+    pub fn from_matrix<S: Storage<N, D, D>>(m: &Matrix<N, D, D, S>) -> Self {
+        Self::new(&m.diagonal())
+    }
+}
+
+impl<N: RealField, D: Dim> Preconditioner<N, D> for JacobiPreconditioner<N, D>
+where DefaultAllocator: Allocator<N, D>
+{
+    #[inline]
+    fn solve(&self, r: &VectorN<N, D>, z: &mut VectorN<N, D>) {
+        z.zip_zip_apply(r, &self.inv_diagonal, |_, r, d| r * d);
+    }
+}
+
+/// The outcome of an iterative linear solver.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvergenceReport<N: RealField> {
+    /// Whether the convergence criterion was reached.
+    pub converged: bool,
+    /// The number of iterations performed.
+    pub niter: usize,
+    /// The norm of the residual `b - A * x` of the returned solution.
+    pub residual_norm: N,
+    /// The residual norm monitored by the solver after each iteration.
+    ///
+    /// For preconditioned MINRES, this is the norm of the preconditioned residual induced by
+    /// `M⁻¹`. For other solvers, this is the norm of the residual updated by the solver's
+    /// recurrences, which may differ from the true residual norm because of rounding errors.
+    pub residual_history: Vec<N>,
+}
+
+// Computes `b - A * x`.
+fn residual<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    b: &Vector<N, D, S>,
+    x: &VectorN<N, D>,
+) -> VectorN<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    let mut r = x.clone();
+    a.apply(x, &mut r);
+    r.axpy(N::one(), b, -N::one());
+    r
+}
+
+fn precondition<N: RealField, D: Dim>(
+    preconditioner: Option<&dyn Preconditioner<N, D>>,
+    r: &VectorN<N, D>,
+    z: &mut VectorN<N, D>,
+) where
+    DefaultAllocator: Allocator<N, D>,
+{
+    match preconditioner {
+        Some(preconditioner) => preconditioner.solve(r, z),
+        None => z.copy_from(r),
+    }
+}
+
+fn report<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    b: &Vector<N, D, S>,
+    x: &VectorN<N, D>,
+    converged: bool,
+    residual_history: Vec<N>,
+) -> ConvergenceReport<N>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    ConvergenceReport {
+        converged,
+        niter: residual_history.len(),
+        residual_norm: residual(a, b, x).norm(),
+        residual_history,
+    }
+}
+
+/// Solves `A * x = b` with the preconditioned conjugate gradient method, where `A` is symmetric
+/// positive-definite.
+///
+/// The solver stops when the norm of the residual is smaller than `tolerance * ‖b‖`.
+///
+/// # Arguments
+///
+/// * `a`              − the symmetric positive-definite linear operator.
+/// * `b`              − the right-hand-side.
+/// * `x`              − the initial guess, overwritten by the solution.
+/// * `preconditioner` − an optional symmetric positive-definite preconditioner.
+/// * `tolerance`      − the relative tolerance on the residual norm.
+/// * `max_niter`      − the maximum number of iterations.
+pub fn conjugate_gradient<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    b: &Vector<N, D, S>,
+    x: &mut VectorN<N, D>,
+    preconditioner: Option<&dyn Preconditioner<N, D>>,
+    tolerance: N,
+    max_niter: usize,
+) -> ConvergenceReport<N>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    let threshold = tolerance * b.norm();
+    let mut history = Vec::new();
+    let mut r = residual(a, b, x);
+    let mut converged = r.norm() <= threshold;
+    let mut z = r.clone();
+    precondition(preconditioner, &r, &mut z);
+    let mut p = z.clone();
+    let mut q = z.clone();
+    let mut rz = r.dot(&z);
+
+    while !converged && history.len() < max_niter {
+        a.apply(&p, &mut q);
+        let pq = p.dot(&q);
+
+        if pq <= N::zero() {
+            // The operator is not positive-definite.
+            break;
+        }
+
+        let alpha = rz / pq;
+        x.axpy(alpha, &p, N::one());
+        r.axpy(-alpha, &q, N::one());
+
+        let rnorm = r.norm();
+        history.push(rnorm);
+        converged = rnorm <= threshold;
+
+        precondition(preconditioner, &r, &mut z);
+        let rz_new = r.dot(&z);
+        let beta = rz_new / rz;
+        rz = rz_new;
+        p.axpy(N::one(), &z, beta);
+    }
+
+    report(a, b, x, converged, history)
+}
+
+/// Solves `A * x = b` with the preconditioned minimal residual method, where `A` is symmetric,
+/// possibly indefinite.
+///
+/// The solver stops when the norm of the residual is smaller than `tolerance * ‖b‖`, where both
+/// norms are induced by `M⁻¹` if a preconditioner `M` is given. The preconditioner must be
+/// symmetric positive-definite. See `conjugate_gradient` for a description of the arguments.
+pub fn minres<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    b: &Vector<N, D, S>,
+    x: &mut VectorN<N, D>,
+    preconditioner: Option<&dyn Preconditioner<N, D>>,
+    tolerance: N,
+    max_niter: usize,
+) -> ConvergenceReport<N>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    let mut history = Vec::new();
+    let b = b.clone_owned();
+    let mut y = b.clone();
+    precondition(preconditioner, &b, &mut y);
+    let bnorm_squared = b.dot(&y);
+    let threshold = if bnorm_squared > N::zero() {
+        tolerance * bnorm_squared.sqrt()
+    } else {
+        N::zero()
+    };
+
+    // Lanczos vectors.
+    let mut r1 = residual(a, &b, x);
+    precondition(preconditioner, &r1, &mut y);
+    let beta1 = r1.dot(&y);
+
+    if beta1 <= N::zero() {
+        // Either the residual is zero or the preconditioner is not positive-definite.
+        let converged = beta1.is_zero();
+        return report(a, &b, x, converged, history);
+    }
+
+    let beta1 = beta1.sqrt();
+    let mut r2 = r1.clone();
+    let mut v = r1.clone();
+    let mut beta = beta1;
+    let mut old_beta = N::zero();
+
+    // QR factorization of the Lanczos tridiagonal matrix.
+    let mut cs = -N::one();
+    let mut sn = N::zero();
+    let mut dbar = N::zero();
+    let mut epsln = N::zero();
+    let mut phibar = beta1;
+    let mut converged = phibar <= threshold;
+
+    // Search directions.
+    let mut w = x.map(|_| N::zero());
+    let mut w1 = w.clone();
+    let mut w2 = w.clone();
+
+    while !converged && history.len() < max_niter {
+        v.copy_from(&y);
+        v.unscale_mut(beta);
+        a.apply(&v, &mut y);
+
+        if !history.is_empty() {
+            y.axpy(-beta / old_beta, &r1, N::one());
+        }
+
+        let alpha = v.dot(&y);
+        y.axpy(-alpha / beta, &r2, N::one());
+        r1.copy_from(&r2);
+        r2.copy_from(&y);
+        precondition(preconditioner, &r2, &mut y);
+        old_beta = beta;
+        beta = r2.dot(&y);
+
+        if beta < N::zero() {
+            // The preconditioner is not positive-definite.
+            break;
+        }
+
+        beta = beta.sqrt();
+
+        // Apply the previous rotation, then compute and apply the next one.
+        let old_epsln = epsln;
+        let delta = cs * dbar + sn * alpha;
+        let gbar = sn * dbar - cs * alpha;
+        epsln = sn * beta;
+        dbar = -cs * beta;
+
+        let gamma = gbar.hypot(beta);
+        if gamma.is_zero() {
+            break;
+        }
+
+        cs = gbar / gamma;
+        sn = beta / gamma;
+        let phi = cs * phibar;
+        phibar = sn * phibar;
+
+        // Update the solution.
+        w1.copy_from(&w2);
+        w2.copy_from(&w);
+        w.copy_from(&v);
+        w.axpy(-old_epsln, &w1, N::one());
+        w.axpy(-delta, &w2, N::one());
+        w.unscale_mut(gamma);
+        x.axpy(phi, &w, N::one());
+
+        history.push(phibar.abs());
+        converged = phibar.abs() <= threshold;
+
+        if beta.is_zero() {
+            // The Krylov subspace is invariant: `x` is the exact solution.
+            converged = true;
+        }
+    }
+
+    report(a, &b, x, converged, history)
+}
+
+/// Solves `A * x = b` with the right-preconditioned stabilized biconjugate gradient method, where
+/// `A` is a general square operator.
+///
+/// The solver stops when the norm of the residual is smaller than `tolerance * ‖b‖`. Each
+/// iteration requires two applications of the operator and of the preconditioner. See
+/// `conjugate_gradient` for a description of the arguments.
+pub fn bicgstab<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    b: &Vector<N, D, S>,
+    x: &mut VectorN<N, D>,
+    preconditioner: Option<&dyn Preconditioner<N, D>>,
+    tolerance: N,
+    max_niter: usize,
+) -> ConvergenceReport<N>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    let threshold = tolerance * b.norm();
+    let mut history = Vec::new();
+    let mut r = residual(a, b, x);
+    let mut converged = r.norm() <= threshold;
+    let r_hat = r.clone();
+    let mut p = r.clone();
+    let mut v = r.map(|_| N::zero());
+    let mut p_hat = v.clone();
+    let mut s_hat = v.clone();
+    let mut t = v.clone();
+    let mut rho = N::one();
+    let mut alpha = N::one();
+    let mut omega = N::one();
+
+    while !converged && history.len() < max_niter {
+        let rho_new = r_hat.dot(&r);
+
+        if rho_new.is_zero() {
+            // Breakdown.
+            break;
+        }
+
+        if !history.is_empty() {
+            let beta = (rho_new / rho) * (alpha / omega);
+            p.axpy(-omega, &v, N::one());
+            p.axpy(N::one(), &r, beta);
+        }
+
+        rho = rho_new;
+        precondition(preconditioner, &p, &mut p_hat);
+        a.apply(&p_hat, &mut v);
+
+        let r_hat_v = r_hat.dot(&v);
+        if r_hat_v.is_zero() {
+            break;
+        }
+
+        alpha = rho / r_hat_v;
+        x.axpy(alpha, &p_hat, N::one());
+        // `r` now holds `s = r - alpha * v`.
+        r.axpy(-alpha, &v, N::one());
+
+        let snorm = r.norm();
+        if snorm <= threshold {
+            history.push(snorm);
+            converged = true;
+            break;
+        }
+
+        precondition(preconditioner, &r, &mut s_hat);
+        a.apply(&s_hat, &mut t);
+
+        let tt = t.dot(&t);
+        omega = if tt.is_zero() { N::zero() } else { t.dot(&r) / tt };
+        x.axpy(omega, &s_hat, N::one());
+        r.axpy(-omega, &t, N::one());
+
+        let rnorm = r.norm();
+        history.push(rnorm);
+        converged = rnorm <= threshold;
+
+        if omega.is_zero() {
+            break;
+        }
+    }
+
+    report(a, b, x, converged, history)
+}
+
+/// Solves `A * x = b` with the right-preconditioned restarted generalized minimal residual method,
+/// where `A` is a general square operator.
+///
+/// The solver stops when the norm of the residual is smaller than `tolerance * ‖b‖`. The Krylov
+/// subspace is restarted every `restart` iterations, so that at most `restart + 1` basis vectors
+/// are stored. If the Krylov subspace cannot be extended, e.g., because the operator or the
+/// preconditioner is singular, the solver stops and returns the best solution found so far without
+/// reporting convergence. See `conjugate_gradient` for a description of the other arguments.
+pub fn gmres<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    b: &Vector<N, D, S>,
+    x: &mut VectorN<N, D>,
+    preconditioner: Option<&dyn Preconditioner<N, D>>,
+    restart: usize,
+    tolerance: N,
+    max_niter: usize,
+) -> ConvergenceReport<N>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    assert!(restart > 0, "GMRES: the restart length must be non-zero.");
+
+    let threshold = tolerance * b.norm();
+    let mut history = Vec::new();
+    let mut converged = false;
+    let mut z = x.clone();
+    let mut w = x.clone();
+
+    // Hessenberg matrix (stored by columns), Givens rotations, and right-hand-side of the
+    // projected least-squares problem.
+    let mut h: Vec<Vec<N>> = Vec::with_capacity(restart);
+    let mut rotations: Vec<(N, N)> = Vec::with_capacity(restart);
+    let mut g: Vec<N> = Vec::with_capacity(restart + 1);
+    let mut basis: Vec<VectorN<N, D>> = Vec::with_capacity(restart + 1);
+    // Estimate of the norm of the preconditioned operator, used to detect breakdowns.
+    let mut operator_norm = N::zero();
+
+    loop {
+        let mut r = residual(a, b, x);
+        let beta = r.norm();
+
+        if beta <= threshold {
+            converged = true;
+            break;
+        }
+
+        if history.len() >= max_niter {
+            break;
+        }
+
+        h.clear();
+        rotations.clear();
+        g.clear();
+        basis.clear();
+        r.unscale_mut(beta);
+        basis.push(r);
+        g.push(beta);
+
+        for j in 0..restart {
+            precondition(preconditioner, &basis[j], &mut z);
+            a.apply(&z, &mut w);
+
+            // Modified Gram-Schmidt.
+            let mut column = Vec::with_capacity(j + 2);
+            for vi in &basis {
+                let hij = w.dot(vi);
+                w.axpy(-hij, vi, N::one());
+                column.push(hij);
+            }
+
+            let h_next = w.norm();
+            column.push(h_next);
+            let column_norm = column.iter().fold(N::zero(), |acc, e| acc + *e * *e).sqrt();
+            operator_norm = operator_norm.max(column_norm);
+
+            for (i, (c, s)) in rotations.iter().cloned().enumerate() {
+                let (h0, h1) = (column[i], column[i + 1]);
+                column[i] = c * h0 + s * h1;
+                column[i + 1] = -s * h0 + c * h1;
+            }
+
+            let norm = column[j].hypot(column[j + 1]);
+
+            if norm <= operator_norm * N::default_epsilon() * crate::convert((j + 2) as f64) {
+                // The preconditioned operator maps the last basis vector into the span of the
+                // previous ones (up to rounding errors), e.g., because it is singular. This
+                // column would make the triangular system singular: solve the projected system
+                // with the previous columns only.
+                break;
+            }
+
+            let (c, s) = (column[j] / norm, column[j + 1] / norm);
+            column[j] = norm;
+            column[j + 1] = N::zero();
+            rotations.push((c, s));
+            h.push(column);
+
+            let gj = g[j];
+            g[j] = c * gj;
+            g.push(-s * gj);
+
+            let rnorm = g[j + 1].abs();
+            history.push(rnorm);
+
+            if rnorm <= threshold || history.len() >= max_niter || h_next.is_zero() {
+                break;
+            }
+
+            w.unscale_mut(h_next);
+            basis.push(w.clone());
+        }
+
+        // Solve the triangular projected system and update the solution.
+        let k = h.len();
+
+        if k == 0 {
+            // The solver cannot make any progress from the current solution.
+            break;
+        }
+
+        let mut y = g[..k].to_vec();
+
+        for j in (0..k).rev() {
+            y[j] /= h[j][j];
+            let yj = y[j];
+
+            for i in 0..j {
+                y[i] -= h[j][i] * yj;
+            }
+        }
+
+        w.fill(N::zero());
+        for (vi, yi) in basis.iter().zip(y.iter()) {
+            w.axpy(*yi, vi, N::one());
+        }
+
+        precondition(preconditioner, &w, &mut z);
+        *x += &z;
+    }
+
+    report(a, b, x, converged, history)
+}
@@ -0,0 +1,485 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use alga::general::ComplexField;
+use num::{One, Zero};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix, VectorN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, U1};
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::{LinalgError, PermutationSequence};
+
+/// The LDLᵀ decomposition with symmetric pivoting of a symmetric (or hermitian) matrix.
+///
+/// This computes `P * A * Pᵀ = L * D * Lᴴ` where `P` is a permutation matrix, `L` is a
+/// unit lower-triangular matrix, and `D` is a block-diagonal matrix with blocks of size 1x1 or
+/// 2x2. The pivots are chosen using the Bunch-Kaufman strategy so the decomposed matrix does not
+/// need to be definite-positive nor invertible.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D> +
+                           Allocator<(usize, usize), D>,
+         MatrixN<N, D>: Serialize,
+         VectorN<N, D>: Serialize,
+         PermutationSequence<D>: Serialize"
+    ))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D> +
+                           Allocator<(usize, usize), D>,
+         MatrixN<N, D>: Deserialize<'de>,
+         VectorN<N, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct LDLT<N: ComplexField, D: Dim>
+where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>
+{
+    // The strictly lower-triangular part contains `L` and the diagonal contains the diagonal
+    // of `D`.
+    ldlt: MatrixN<N, D>,
+    // The `i`-th element is the element `(i + 1, i)` of `D`. It is zero for 1x1 blocks.
+    subdiag: VectorN<N, D>,
+    p: PermutationSequence<D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for LDLT<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+    MatrixN<N, D>: Copy,
+    VectorN<N, D>: Copy,
+    PermutationSequence<D>: Copy,
+{}
+
+impl<N: ComplexField, D: Dim> LDLT<N, D>
+where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>
+{
+    /// Computes the LDLᵀ decomposition with Bunch-Kaufman pivoting of `matrix`.
+    ///
+    /// The input matrix is assumed to be symmetric (or hermitian) and only its lower-triangular
+    /// part is read.
+    pub fn new(mut matrix: MatrixN<N, D>) -> Self {
+        assert!(matrix.is_square(), "The input matrix must be square.");
+
+        let nrows = matrix.data.shape().0;
+        let n = nrows.value();
+        let mut subdiag = MatrixMN::zeros_generic(nrows, U1);
+        let mut p = PermutationSequence::identity_generic(nrows);
+
+        // Rebuild the full hermitian matrix from its lower-triangular part.
+        for j in 0..n {
+            matrix[(j, j)] = N::from_real(matrix[(j, j)].real());
+
+            for i in j + 1..n {
+                matrix[(j, i)] = matrix[(i, j)].conjugate();
+            }
+        }
+
+        // Bunch-Kaufman pivoting threshold that minimizes the element growth.
+        let alpha: N::RealField = crate::convert((1.0 + 17.0f64.sqrt()) / 8.0);
+        let mut k = 0;
+
+        while k < n {
+            // The diagonal of a hermitian matrix is real, so ignore the rounding errors that
+            // could make its imaginary part non-zero.
+            let absakk = matrix[(k, k)].real().abs();
+            let (imax, colmax) = max_modulus(matrix.slice_range(k + 1.., k).iter(), k + 1);
+
+            let mut kstep = 1;
+            let mut kp = k;
+
+            if absakk.is_zero() && colmax.is_zero() {
+                // The column is already zero: nothing to eliminate.
+                k += 1;
+                continue;
+            }
+
+            if absakk < alpha * colmax {
+                // The largest off-diagonal element of the `imax`-th row (or column).
+                let (_, rowmax_left) = max_modulus(matrix.slice_range(imax, k..imax).iter(), k);
+                let (_, rowmax_right) = max_modulus(matrix.slice_range(imax + 1.., imax).iter(), imax + 1);
+                let rowmax = if rowmax_left > rowmax_right { rowmax_left } else { rowmax_right };
+
+                if absakk * rowmax >= alpha * colmax * colmax {
+                    // Use a 1x1 pivot without interchange.
+                } else if matrix[(imax, imax)].real().abs() >= alpha * rowmax {
+                    // Use a 1x1 pivot after interchanging the rows (and columns) `k` and `imax`.
+                    kp = imax;
+                } else {
+                    // Use a 2x2 pivot after interchanging the rows (and columns) `k + 1` and `imax`.
+                    kp = imax;
+                    kstep = 2;
+                }
+            }
+
+            let kk = k + kstep - 1;
+            if kp != kk {
+                matrix.swap_rows(kk, kp);
+                matrix.swap_columns(kk, kp);
+                p.append_permutation(kk, kp);
+            }
+
+            if kstep == 1 {
+                let d = matrix[(k, k)].real();
+
+                // Compute the k-th column of `L` and update the trailing submatrix.
+                for j in k + 1..n {
+                    let wj = matrix[(j, k)].conjugate();
+
+                    for i in k + 1..n {
+                        let wi = matrix[(i, k)];
+                        matrix[(i, j)] -= (wi * wj).unscale(d);
+                    }
+                }
+
+                matrix.slice_range_mut(k + 1.., k).unscale_mut(d);
+            } else {
+                // The 2x2 pivot is `[a, conj(b); b, c]`.
+                let a = matrix[(k, k)].real();
+                let b = matrix[(k + 1, k)];
+                let c = matrix[(k + 1, k + 1)].real();
+                let det = a * c - b.modulus_squared();
+
+                // Update the trailing submatrix with `W * D⁻¹ * Wᴴ` where `W` contains the two
+                // pivot columns.
+                for j in k + 2..n {
+                    let wj0 = matrix[(j, k)].conjugate();
+                    let wj1 = matrix[(j, k + 1)].conjugate();
+
+                    for i in k + 2..n {
+                        let (li0, li1) = inv_2x2_mul(a, b, c, det, matrix[(i, k)], matrix[(i, k + 1)]);
+                        matrix[(i, j)] -= li0 * wj0 + li1 * wj1;
+                    }
+                }
+
+                // Compute the two columns of `L`.
+                for i in k + 2..n {
+                    let (li0, li1) = inv_2x2_mul(a, b, c, det, matrix[(i, k)], matrix[(i, k + 1)]);
+                    matrix[(i, k)] = li0;
+                    matrix[(i, k + 1)] = li1;
+                }
+
+                subdiag[k] = b;
+                matrix[(k + 1, k)] = N::zero();
+            }
+
+            k += kstep;
+        }
+
+        LDLT {
+            ldlt: matrix,
+            subdiag,
+            p,
+        }
+    }
+
+    /// The unit lower-triangular matrix `L` of this decomposition.
+    pub fn l(&self) -> MatrixN<N, D> {
+        let mut res = self.ldlt.lower_triangle();
+        res.fill_diagonal(N::one());
+        res
+    }
+
+    /// The block-diagonal matrix `D` of this decomposition.
+    pub fn d(&self) -> MatrixN<N, D> {
+        let (nrows, ncols) = self.ldlt.data.shape();
+        let mut res = MatrixN::zeros_generic(nrows, ncols);
+        for i in 0..nrows.value() {
+            res[(i, i)] = self.ldlt[(i, i)];
+        }
+
+        for i in 0..nrows.value().saturating_sub(1) {
+            res[(i + 1, i)] = self.subdiag[i];
+            res[(i, i + 1)] = self.subdiag[i].conjugate();
+        }
+
+        res
+    }
+
+    /// The symmetric permutation `P` of this decomposition.
+    #[inline]
+    pub fn p(&self) -> &PermutationSequence<D> {
+        &self.p
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the system `self * x = b` where `self` is the decomposed matrix and `x`
+    /// the unknown.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.ldlt.nrows(),
+            b.nrows(),
+            "LDLT solve matrix dimension mismatch."
+        );
+
+        let n = self.ldlt.nrows();
+        if n == 0 {
+            return true;
+        }
+
+        self.p.permute_rows(b);
+        let _ = self.ldlt.solve_lower_triangular_with_diag_mut(b, N::one());
+
+        // Solve the block-diagonal system.
+        let mut k = 0;
+        while k < n {
+            if k + 1 < n && !self.subdiag[k].is_zero() {
+                let a = self.ldlt[(k, k)].real();
+                let sub = self.subdiag[k];
+                let c = self.ldlt[(k + 1, k + 1)].real();
+                let det = a * c - sub.modulus_squared();
+
+                if det.is_zero() {
+                    return false;
+                }
+
+                for j in 0..b.ncols() {
+                    let b0 = b[(k, j)];
+                    let b1 = b[(k + 1, j)];
+                    b[(k, j)] = (b0.scale(c) - b1 * sub.conjugate()).unscale(det);
+                    b[(k + 1, j)] = (b1.scale(a) - b0 * sub).unscale(det);
+                }
+
+                k += 2;
+            } else {
+                let d = self.ldlt[(k, k)].real();
+
+                if d.is_zero() {
+                    return false;
+                }
+
+                b.row_mut(k).unscale_mut(d);
+                k += 1;
+            }
+        }
+
+        // Solve the system with the adjoint of the unit lower-triangular matrix `L`.
+        for j in 0..b.ncols() {
+            let mut col = b.column_mut(j);
+
+            for i in (0..n - 1).rev() {
+                let dot = self.ldlt.slice_range(i + 1.., i).dotc(&col.rows_range(i + 1..));
+                col[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible.
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Result<MatrixMN<N, R2, C2>, LinalgError>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.try_solve_mut(&mut res)?;
+        Ok(res)
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible, in which case
+    /// its input `b` is left unchanged.
+    pub fn try_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> Result<(), LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        match self.first_zero_pivot() {
+            Some(pivot) => Err(LinalgError::Singular { pivot }),
+            None => {
+                let _ = self.solve_mut(b);
+                Ok(())
+            }
+        }
+    }
+
+    // The index of the first zero pivot of this decomposition, if any.
+    fn first_zero_pivot(&self) -> Option<usize> {
+        let n = self.ldlt.nrows();
+        let mut k = 0;
+
+        while k < n {
+            if k + 1 < n && !self.subdiag[k].is_zero() {
+                let a = self.ldlt[(k, k)].real();
+                let c = self.ldlt[(k + 1, k + 1)].real();
+
+                if (a * c - self.subdiag[k].modulus_squared()).is_zero() {
+                    return Some(k);
+                }
+
+                k += 2;
+            } else {
+                if self.ldlt[(k, k)].real().is_zero() {
+                    return Some(k);
+                }
+
+                k += 1;
+            }
+        }
+
+        None
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    pub fn try_inverse(&self) -> Option<MatrixN<N, D>> {
+        let (nrows, ncols) = self.ldlt.data.shape();
+        let mut res = MatrixN::identity_generic(nrows, ncols);
+
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N::RealField {
+        let n = self.ldlt.nrows();
+        let mut res = N::RealField::one();
+        let mut k = 0;
+
+        while k < n {
+            if k + 1 < n && !self.subdiag[k].is_zero() {
+                let a = self.ldlt[(k, k)].real();
+                let c = self.ldlt[(k + 1, k + 1)].real();
+                res *= a * c - self.subdiag[k].modulus_squared();
+                k += 2;
+            } else {
+                res *= self.ldlt[(k, k)].real();
+                k += 1;
+            }
+        }
+
+        res
+    }
+
+    /// Computes the inertia of the decomposed matrix, i.e., its number of positive, negative,
+    /// and zero eigenvalues (in this order).
+    ///
+    /// An eigenvalue of the block-diagonal matrix `D` is considered to be zero if its magnitude
+    /// is smaller than or equal to `eps`. By Sylvester's law of inertia, those are the same
+    /// counts as for the decomposed matrix.
+    pub fn inertia(&self, eps: N::RealField) -> (usize, usize, usize) {
+        assert!(
+            eps >= N::RealField::zero(),
+            "LDLT inertia: the epsilon must be non-negative."
+        );
+
+        let n = self.ldlt.nrows();
+        let mut res = (0, 0, 0);
+        let mut classify = |val: N::RealField| {
+            if val > eps {
+                res.0 += 1;
+            } else if val < -eps {
+                res.1 += 1;
+            } else {
+                res.2 += 1;
+            }
+        };
+
+        let mut k = 0;
+        while k < n {
+            if k + 1 < n && !self.subdiag[k].is_zero() {
+                // Eigenvalues of the hermitian 2x2 block.
+                let half: N::RealField = crate::convert(0.5);
+                let a = self.ldlt[(k, k)].real();
+                let c = self.ldlt[(k + 1, k + 1)].real();
+                let mean = (a + c) * half;
+                let half_diff = (a - c) * half;
+                let radius = (half_diff * half_diff + self.subdiag[k].modulus_squared()).sqrt();
+
+                classify(mean + radius);
+                classify(mean - radius);
+                k += 2;
+            } else {
+                classify(self.ldlt[(k, k)].real());
+                k += 1;
+            }
+        }
+
+        res
+    }
+}
+
+/// Computes the row vector `[w0, w1] * D⁻¹` where `D` is the hermitian 2x2 matrix
+/// `[a, conj(b); b, c]` with the determinant `det`.
+fn inv_2x2_mul<N: ComplexField>(a: N::RealField, b: N, c: N::RealField, det: N::RealField, w0: N, w1: N) -> (N, N) {
+    // The inverse of `D` is `[c, -conj(b); -b, a] / det`.
+    let l0 = (w0.scale(c) - w1 * b).unscale(det);
+    let l1 = (w1.scale(a) - w0 * b.conjugate()).unscale(det);
+    (l0, l1)
+}
+
+/// The index and magnitude of the element with the greatest magnitude, where the first element
+/// of `elts` has the index `start`.
+fn max_modulus<'a, N: ComplexField, I: Iterator<Item = &'a N>>(elts: I, start: usize) -> (usize, N::RealField) {
+    let mut res = (start, N::RealField::zero());
+
+    for (i, e) in elts.enumerate() {
+        let val = e.modulus();
+        if val > res.1 {
+            res = (start + i, val);
+        }
+    }
+
+    res
+}
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>
+{
+    /// Computes the LDLᵀ decomposition with Bunch-Kaufman pivoting of this symmetric (or
+    /// hermitian) matrix.
+    ///
+    /// Only the lower-triangular part of this matrix is read.
+    pub fn ldlt(self) -> LDLT<N, D> {
+        LDLT::new(self.into_owned())
+    }
+}
@@ -0,0 +1,296 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use alga::general::ComplexField;
+use num::Zero;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, Vector, VectorN};
+use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::{ColPivQR, SVD};
+
+/// The decomposition used to solve a linear least-squares problem.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LeastSquaresMethod {
+    /// The QR decomposition with column pivoting.
+    ///
+    /// This computes the basic solution, i.e., the solution with at most `rank` non-zero
+    /// components. It coincides with the minimum-norm solution only if the system matrix has
+    /// full column rank.
+    QR,
+    /// The complete orthogonal decomposition, computed from the QR decomposition with column
+    /// pivoting.
+    ///
+    /// This computes the minimum-norm solution.
+    COD,
+    /// The singular value decomposition.
+    ///
+    /// This computes the minimum-norm solution. This is the slowest but most robust method.
+    SVD,
+}
+
+/// The solution of a linear least-squares problem.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        serialize = "DefaultAllocator: Allocator<N, C, C2>,
+         MatrixMN<N, C, C2>: Serialize,
+         N::RealField: Serialize"
+    ))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        deserialize = "DefaultAllocator: Allocator<N, C, C2>,
+         MatrixMN<N, C, C2>: Deserialize<'de>,
+         N::RealField: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct LeastSquaresSolution<N: ComplexField, C: Dim, C2: Dim>
+where DefaultAllocator: Allocator<N, C, C2>
+{
+    /// The solution `x` minimizing `‖self * x - b‖`.
+    pub solution: MatrixMN<N, C, C2>,
+    /// The norm of the residual `self * x - b`.
+    pub residual_norm: N::RealField,
+    /// The effective rank of the system matrix.
+    pub rank: usize,
+}
+
+impl<N: ComplexField, C: Dim, C2: Dim> Copy for LeastSquaresSolution<N, C, C2>
+where
+    DefaultAllocator: Allocator<N, C, C2>,
+    MatrixMN<N, C, C2>: Copy,
+{}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
+where
+    DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, C>
+        + Allocator<N, R>
+        + Allocator<N, DimDiff<DimMinimum<R, C>, U1>>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, R, DimMinimum<R, C>>
+        + Allocator<N, C, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N::RealField, C>
+        + Allocator<N::RealField, DimMinimum<R, C>>
+        + Allocator<N::RealField, DimDiff<DimMinimum<R, C>, U1>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    /// Computes the minimum-norm solution `x` minimizing `‖self * x - b‖`.
+    ///
+    /// This uses the complete orthogonal decomposition of `self`. Its rank is determined with
+    /// the threshold `max(nrows, ncols) * ε * ‖self‖` where `ε` is the machine epsilon.
+    /// Use `.solve_least_squares_with(...)` to select another method or threshold.
+    pub fn solve_least_squares<C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R, C2, S2>,
+    ) -> LeastSquaresSolution<N, C, C2>
+    where
+        S2: Storage<N, R, C2>,
+        DefaultAllocator: Allocator<N, R, C2> + Allocator<N, C, C2> + Allocator<N, DimMinimum<R, C>, C2>,
+    {
+        let max_dim: N::RealField = crate::convert(self.nrows().max(self.ncols()) as f64);
+        let eps = max_dim * N::RealField::default_epsilon() * self.norm();
+
+        self.solve_least_squares_with(b, LeastSquaresMethod::COD, eps)
+    }
+
+    /// Computes a solution `x` minimizing `‖self * x - b‖` using the given method.
+    ///
+    /// # Arguments
+    ///
+    /// * `b`      − the right-hand-side of the system. Each of its columns is solved independently.
+    /// * `method` − the decomposition used to solve the system.
+    /// * `eps`    − the threshold used to determine the rank of `self`: any singular value (or
+    /// diagonal element of the `R` factor of the pivoted QR decomposition) with a magnitude
+    /// smaller than `eps` is assumed to be zero.
+    pub fn solve_least_squares_with<C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R, C2, S2>,
+        method: LeastSquaresMethod,
+        eps: N::RealField,
+    ) -> LeastSquaresSolution<N, C, C2>
+    where
+        S2: Storage<N, R, C2>,
+        DefaultAllocator: Allocator<N, R, C2> + Allocator<N, C, C2> + Allocator<N, DimMinimum<R, C>, C2>,
+    {
+        assert_eq!(
+            self.nrows(),
+            b.nrows(),
+            "Least squares: matrix dimension mismatch."
+        );
+        assert!(
+            eps >= N::RealField::zero(),
+            "Least squares: the epsilon must be non-negative."
+        );
+
+        let (solution, rank) = match method {
+            LeastSquaresMethod::QR => {
+                let col_piv_qr = ColPivQR::new(self.clone_owned());
+                solve_basic(&col_piv_qr, b, eps)
+            }
+            LeastSquaresMethod::COD => {
+                let col_piv_qr = ColPivQR::new(self.clone_owned());
+                solve_complete_orthogonal(&col_piv_qr, b, eps)
+            }
+            LeastSquaresMethod::SVD => {
+                let svd = SVD::new(self.clone_owned(), true, true);
+                let solution = svd
+                    .solve(b, eps)
+                    .expect("Least squares: the singular vectors have not been computed.");
+                (solution, svd.rank(eps))
+            }
+        };
+
+        let mut residual = b.clone_owned();
+        residual.gemm(-N::one(), self, &solution, N::one());
+
+        LeastSquaresSolution {
+            solution,
+            residual_norm: residual.norm(),
+            rank,
+        }
+    }
+}
+
+/// Computes `Q^H * b` where `Q` is the orthogonal factor of the given decomposition.
+fn q_ad_mul<N: ComplexField, R: DimMin<C>, C: Dim, C2: Dim, S2>(
+    col_piv_qr: &ColPivQR<N, R, C>,
+    b: &Matrix<N, R, C2, S2>,
+) -> MatrixMN<N, R, C2>
+where
+    S2: Storage<N, R, C2>,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, R, C2>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    let mut res = b.clone_owned();
+    col_piv_qr.q_tr_mul(&mut res);
+    res
+}
+
+/// Computes the basic solution of a least-squares problem from the pivoted QR decomposition of
+/// the system matrix. Returns the solution and the rank of the system matrix.
+fn solve_basic<N: ComplexField, R: DimMin<C>, C: Dim, C2: Dim, S2>(
+    col_piv_qr: &ColPivQR<N, R, C>,
+    b: &Matrix<N, R, C2, S2>,
+    eps: N::RealField,
+) -> (MatrixMN<N, C, C2>, usize)
+where
+    S2: Storage<N, R, C2>,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, R, C2>
+        + Allocator<N, C, C2>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    let rank = col_piv_qr.rank(eps);
+    let r = col_piv_qr.r();
+    let qb = q_ad_mul(col_piv_qr, b);
+
+    // With `A * P = Q * [R11 R12; 0 0]`, the basic solution is `P * [R11⁻¹ * (Q^H * b)₁; 0]`.
+    let mut res = MatrixMN::zeros_generic(r.data.shape().1, b.data.shape().1);
+    res.rows_range_mut(..rank).copy_from(&qb.rows_range(..rank));
+
+    let solved = r
+        .slice_range(..rank, ..rank)
+        .solve_upper_triangular_mut(&mut res.rows_range_mut(..rank));
+    assert!(solved, "Least squares: the triangular factor is not invertible.");
+
+    col_piv_qr.p().inv_permute_rows(&mut res);
+    (res, rank)
+}
+
+/// Computes the minimum-norm solution of a least-squares problem from the complete orthogonal
+/// decomposition of the system matrix. Returns the solution and the rank of the system matrix.
+fn solve_complete_orthogonal<N: ComplexField, R: DimMin<C>, C: Dim, C2: Dim, S2>(
+    col_piv_qr: &ColPivQR<N, R, C>,
+    b: &Matrix<N, R, C2, S2>,
+    eps: N::RealField,
+) -> (MatrixMN<N, C, C2>, usize)
+where
+    S2: Storage<N, R, C2>,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, C>
+        + Allocator<N, R, C2>
+        + Allocator<N, C, C2>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, C, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    let rank = col_piv_qr.rank(eps);
+    let mut r_ad = col_piv_qr.r().adjoint();
+    let (ncols, min_nrows_ncols) = r_ad.data.shape();
+
+    // With `A * P = Q * [R11 R12; 0 0]`, we compute the householder reflections `Hᵢ` such that
+    // `H_0 * ... * H_{rank - 1} * [R11 R12]^H = [T^H; 0]` where `T` is upper-triangular. Each
+    // `Hᵢ` only acts on the components `i` and `rank..`. The corresponding householder vectors
+    // are stored on the columns of `axes`.
+    let mut axes = MatrixMN::<N, C, DimMinimum<R, C>>::zeros_generic(ncols, min_nrows_ncols);
+
+    for i in (0..rank).rev() {
+        let mut axis = VectorN::<N, C>::zeros_generic(ncols, U1);
+        axis[i] = r_ad[(i, i)];
+        axis.rows_range_mut(rank..).copy_from(&r_ad.slice_range(rank.., i));
+
+        let norm = axis.norm();
+        let signum = if axis[i].is_zero() { N::one() } else { axis[i].signum() };
+        axis[i] += signum * N::from_real(norm);
+
+        if axis.norm_squared().is_zero() {
+            continue;
+        }
+
+        let _ = axis.normalize_mut();
+
+        for j in 0..=i {
+            reflect(&axis, &mut r_ad.column_mut(j));
+        }
+
+        axes.column_mut(i).copy_from(&axis);
+    }
+
+    // The minimum-norm solution is `P * H_{rank - 1} * ... * H_0 * [T⁻¹ * (Q^H * b)₁; 0]`.
+    let qb = q_ad_mul(col_piv_qr, b);
+    let mut res = MatrixMN::zeros_generic(ncols, b.data.shape().1);
+    res.rows_range_mut(..rank).copy_from(&qb.rows_range(..rank));
+
+    let solved = r_ad
+        .slice_range(..rank, ..rank)
+        .ad_solve_lower_triangular_mut(&mut res.rows_range_mut(..rank));
+    assert!(solved, "Least squares: the triangular factor is not invertible.");
+
+    for i in 0..rank {
+        for k in 0..res.ncols() {
+            reflect(&axes.column(i), &mut res.column_mut(k));
+        }
+    }
+
+    col_piv_qr.p().inv_permute_rows(&mut res);
+    (res, rank)
+}
+
+/// Applies to `v` the householder reflection with the unit axis `axis`.
+fn reflect<N: ComplexField, D: Dim, S1, S2>(axis: &Vector<N, D, S1>, v: &mut Vector<N, D, S2>)
+where
+    S1: Storage<N, D>,
+    S2: StorageMut<N, D>,
+{
+    let factor = axis.dotc(v) * crate::convert(2.0);
+    v.axpy(-factor, axis, N::one());
+}
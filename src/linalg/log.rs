@@ -0,0 +1,129 @@
+//! Matrix logarithm and real powers.
+
+use alga::general::ComplexField;
+use num::{One, Zero};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimDiff, DimMin, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix};
+
+use crate::linalg::exp::one_norm;
+use crate::linalg::sqrt::{block_size, sqrt_quasi_triangular};
+use crate::linalg::Schur;
+
+// Nodes and weights of the 8-points Gauss-Legendre quadrature on [-1, 1]. They are used to
+// evaluate the diagonal Padé approximant of `log(I + X)` in its partial fraction form.
+const GAUSS_LEGENDRE_NODES: [f64; 4] = [
+    0.1834346424956498,
+    0.5255324099163290,
+    0.7966664774136267,
+    0.9602898564975363,
+];
+const GAUSS_LEGENDRE_WEIGHTS: [f64; 4] = [
+    0.3626837833783620,
+    0.3137066458778873,
+    0.2223810344533745,
+    0.1012285362903763,
+];
+
+// The Padé approximant of degree 8 is accurate up to double-precision for `‖X‖₁ <= 0.25`.
+const LOG_PADE_THRESHOLD: f64 = 0.25;
+const MAX_SQRT_ITERATIONS: usize = 100;
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    D: DimSub<U1> + DimMin<D, Output = D>,                  // For Hessenberg and LU.
+    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<(usize, usize), D>,
+{
+    /// Computes the principal logarithm of this square matrix.
+    ///
+    /// This uses the inverse scaling and squaring method on the Schur decomposition of this
+    /// matrix. Returns `None` if this matrix is singular, or if it is a real matrix with a
+    /// negative real eigenvalue (in which case its principal logarithm is not real). No heap
+    /// allocation is performed if this matrix is statically-sized.
+    pub fn ln(&self) -> Option<MatrixN<N, D>> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the logarithm of a non-square matrix."
+        );
+
+        let (q, mut t) = Schur::new(self.clone_owned()).unpack();
+        let (dim, _) = t.data.shape();
+
+        // The matrix is singular iff one of the diagonal blocks of `t` is singular. Note that a
+        // 2x2 block with complex eigenvalues may have zero diagonal elements.
+        let mut i = 0;
+        while i < dim.value() {
+            let size = block_size(&t, i);
+            let det = if size == 1 {
+                t[(i, i)]
+            } else {
+                t[(i, i)] * t[(i + 1, i + 1)] - t[(i, i + 1)] * t[(i + 1, i)]
+            };
+
+            if det.is_zero() {
+                return None;
+            }
+
+            i += size;
+        }
+
+        // Take square roots until the matrix is close enough to the identity.
+        let id = MatrixN::<N, D>::identity_generic(dim, dim);
+        let threshold: N::RealField = crate::convert(LOG_PADE_THRESHOLD);
+        let mut nsqrts = 0;
+
+        while one_norm(&(&t - &id)) > threshold {
+            if nsqrts == MAX_SQRT_ITERATIONS {
+                return None;
+            }
+
+            t = sqrt_quasi_triangular(&t)?;
+            nsqrts += 1;
+        }
+
+        // Padé approximant of `log(I + X)` as the Gauss-Legendre quadrature of
+        // `∫₀¹ X (I + sX)⁻¹ ds`.
+        let x = t - &id;
+        let half: N = crate::convert(0.5);
+        let mut log = MatrixN::zeros_generic(dim, dim);
+
+        for k in 0..GAUSS_LEGENDRE_NODES.len() {
+            let node: N = crate::convert(GAUSS_LEGENDRE_NODES[k]);
+            let weight: N = crate::convert(GAUSS_LEGENDRE_WEIGHTS[k]);
+
+            for s in [half - node * half, half + node * half].iter() {
+                let term = (&id + &x * *s).lu().solve(&x)?;
+                log += term * (weight * half);
+            }
+        }
+
+        let two: N::RealField = crate::convert(2.0);
+        let mut scale = N::RealField::one();
+        for _ in 0..nsqrts {
+            scale *= two;
+        }
+        log.scale_mut(scale);
+
+        Some(&q * log * q.adjoint())
+    }
+
+    /// Computes this matrix raised to the real power `exponent`.
+    ///
+    /// This is computed as `exp(exponent * ln(self))` so this returns `None` whenever the
+    /// principal logarithm of this matrix does not exist (see `.ln()`). If `exponent` is zero,
+    /// the identity matrix is returned.
+    pub fn powf(&self, exponent: N::RealField) -> Option<MatrixN<N, D>> {
+        if exponent.is_zero() {
+            let (dim, _) = self.data.shape();
+            return Some(MatrixN::identity_generic(dim, dim));
+        }
+
+        self.ln().map(|log| log.scale(exponent).exp())
+    }
+}
@@ -1,15 +1,16 @@
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
+use num::{One, Zero};
 use alga::general::{Field, ComplexField};
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Scalar};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Scalar, SquareMatrix, VectorN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Dim, DimMin, DimMinimum};
+use crate::dimension::{Dim, DimMin, DimMinimum, U1};
 use std::mem;
 use crate::storage::{Storage, StorageMut};
 
-use crate::linalg::PermutationSequence;
+use crate::linalg::{LinalgError, PermutationSequence};
 
 /// LU decomposition with partial (row) pivoting.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -244,6 +245,46 @@ where DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D>
         self.lu.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible.
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Result<MatrixMN<N, R2, C2>, LinalgError>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.try_solve_mut(&mut res)?;
+        Ok(res)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible, in which case
+    /// its input `b` is left unchanged.
+    pub fn try_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> Result<(), LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        match self.first_zero_pivot() {
+            Some(pivot) => Err(LinalgError::Singular { pivot }),
+            None => {
+                let _ = self.solve_mut(b);
+                Ok(())
+            }
+        }
+    }
+
+    // The index of the first zero pivot of this decomposition, if any.
+    fn first_zero_pivot(&self) -> Option<usize> {
+        (0..self.lu.nrows()).find(|i| self.lu[(*i, *i)].is_zero())
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the matrix is not invertible.
@@ -311,6 +352,120 @@ where DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D>
 
         true
     }
+
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// The argument `anorm` must be the 1-norm of the decomposed matrix, as computed by
+    /// `.induced_norm_1()` before the decomposition. The norm of the inverse matrix is estimated
+    /// with a few triangular solves using the method of Hager and Higham (LAPACK's `gecon`), so this
+    /// is much cheaper than computing the actual condition number. The estimate is usually
+    /// accurate up to a small factor.
+    ///
+    /// Returns zero if the decomposed matrix is not invertible. A result close to the machine
+    /// epsilon indicates that solving a linear system with this decomposition may yield a
+    /// meaningless result.
+    pub fn rcond_estimate(&self, anorm: N::RealField) -> N::RealField
+    where DefaultAllocator: Allocator<N, D> {
+        assert!(
+            self.lu.is_square(),
+            "LU rcond: unable to estimate the condition number of a non-square matrix."
+        );
+
+        if self.lu.nrows() == 0 {
+            return N::RealField::one();
+        }
+
+        if anorm.is_zero() || !self.is_invertible() {
+            return N::RealField::zero();
+        }
+
+        N::RealField::one() / (anorm * self.inverse_norm_1_estimate())
+    }
+
+    // Estimates the 1-norm of the inverse of the decomposed matrix, using Higham's variant of
+    // Hager's method: "FORTRAN codes for estimating the one-norm of a real or complex matrix,
+    // with applications to condition estimation", ACM Trans. Math. Softw., 14(4), 1988.
+    fn inverse_norm_1_estimate(&self) -> N::RealField
+    where DefaultAllocator: Allocator<N, D> {
+        let nrows = self.lu.data.shape().0;
+        let n = nrows.value();
+        let norm_1 = |v: &VectorN<N, D>| v.fold(N::RealField::zero(), |acc, e| acc + e.modulus());
+
+        let n_real: N::RealField = crate::convert(n as f64);
+        let mut x = VectorN::repeat_generic(nrows, U1, N::from_real(N::RealField::one() / n_real));
+        let mut est = N::RealField::zero();
+
+        for _ in 0..5 {
+            let mut y = x.clone();
+            let _ = self.solve_mut(&mut y);
+            let y_norm = norm_1(&y);
+
+            if y_norm <= est {
+                break;
+            }
+
+            est = y_norm;
+
+            // The subgradient of the 1-norm at `y`.
+            let mut z = y.map(|e| {
+                let modulus = e.modulus();
+                if modulus.is_zero() {
+                    N::one()
+                } else {
+                    e.unscale(modulus)
+                }
+            });
+            self.ad_solve_mut(&mut z);
+
+            let (imax, zmax) = z.iter().enumerate().fold((0, N::RealField::zero()), |acc, (i, e)| {
+                let modulus = e.modulus();
+                if modulus > acc.1 { (i, modulus) } else { acc }
+            });
+
+            if zmax <= z.dotc(&x).real() {
+                break;
+            }
+
+            x.fill(N::zero());
+            x[imax] = N::one();
+        }
+
+        // Alternative estimate that guards against the cases where the iteration above is
+        // known to perform poorly.
+        if n > 1 {
+            let denom: N::RealField = crate::convert((n - 1) as f64);
+            let mut alt = VectorN::from_fn_generic(nrows, U1, |i, _| {
+                let sign = if i % 2 == 0 { N::RealField::one() } else { -N::RealField::one() };
+                let val: N::RealField = crate::convert(i as f64);
+                N::from_real(sign * (N::RealField::one() + val / denom))
+            });
+            let _ = self.solve_mut(&mut alt);
+
+            let two_thirds: N::RealField = crate::convert(2.0 / 3.0);
+            let alt_est = norm_1(&alt) * two_thirds / n_real;
+
+            if alt_est > est {
+                est = alt_est;
+            }
+        }
+
+        est
+    }
+
+    // Solves `self.adjoint() * x = b` where `self` is the decomposed matrix, assumed to be
+    // invertible.
+    fn ad_solve_mut(&self, b: &mut VectorN<N, D>)
+    where DefaultAllocator: Allocator<N, D> {
+        // Since `P * A = L * U`, we have `Aᴴ = Uᴴ * Lᴴ * P`.
+        let _ = self.lu.ad_solve_upper_triangular_mut(b);
+
+        for i in (0..self.lu.nrows()).rev() {
+            let dot = self.lu.slice_range(i + 1.., i).dotc(&b.slice_range(i + 1.., 0));
+            b[i] -= dot;
+        }
+
+        self.p.inv_permute_rows(b);
+    }
 }
 
 #[doc(hidden)]
@@ -376,3 +531,17 @@ where DefaultAllocator: Allocator<N, R, C> + Allocator<(usize, usize), DimMinimu
         LU::new(self.into_owned())
     }
 }
+
+impl<N: ComplexField, D: DimMin<D, Output = D>, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>
+{
+    /// Estimates the reciprocal of the condition number of this matrix in the 1-norm from its LU
+    /// decomposition.
+    ///
+    /// See `LU::rcond_estimate` for details. Use `.condition_number()` to compute the exact
+    /// condition number in the spectral norm from the SVD instead.
+    pub fn rcond_estimate(&self) -> N::RealField {
+        let anorm = self.induced_norm_1();
+        LU::new(self.clone_owned()).rcond_estimate(anorm)
+    }
+}
@@ -3,35 +3,58 @@
 pub mod balancing;
 mod bidiagonal;
 mod cholesky;
+mod col_piv_qr;
 mod determinant;
+mod eigen;
+mod error;
+mod exp;
 mod full_piv_lu;
+mod generalized_eigen;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
 mod inverse;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod krylov;
+mod ldlt;
+mod least_squares;
+mod log;
 mod lu;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod partial_eigen;
 mod permutation_sequence;
+mod polar;
 mod qr;
 mod schur;
 mod solve;
+mod sqrt;
 mod svd;
 mod symmetric_eigen;
+mod symmetric_generalized_eigen;
 mod symmetric_tridiagonal;
 mod convolution;
 
-//// FIXME: Not complete enough for publishing.
-//// This handles only cases where each eigenvalue has multiplicity one.
-// mod eigen;
-
 pub use self::bidiagonal::*;
 pub use self::cholesky::*;
+pub use self::col_piv_qr::*;
+pub use self::eigen::*;
+pub use self::error::*;
 pub use self::full_piv_lu::*;
+pub use self::generalized_eigen::*;
 pub use self::hessenberg::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::krylov::*;
+pub use self::ldlt::*;
+pub use self::least_squares::*;
 pub use self::lu::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::partial_eigen::*;
 pub use self::permutation_sequence::*;
+pub use self::polar::*;
 pub use self::qr::*;
 pub use self::schur::*;
 pub use self::svd::*;
 pub use self::symmetric_eigen::*;
+pub use self::symmetric_generalized_eigen::*;
 pub use self::symmetric_tridiagonal::*;
 pub use self::convolution::*;
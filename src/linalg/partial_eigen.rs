@@ -0,0 +1,578 @@
+//! Iterative eigensolvers computing a few eigenpairs of large linear operators.
+
+use alga::general::RealField;
+use num_complex::Complex as NumComplex;
+use std::cmp::Ordering;
+
+use crate::allocator::Allocator;
+use crate::base::{DMatrix, DVector, DefaultAllocator, MatrixMN, Vector, VectorN};
+use crate::dimension::{Dim, Dynamic, U1};
+use crate::linalg::{Eigen, LinearOperator, SymmetricEigen};
+use crate::storage::Storage;
+
+/// The part of the spectrum computed by the `lanczos` and `arnoldi` eigensolvers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EigenTarget {
+    /// The eigenvalues with the largest magnitude.
+    LargestMagnitude,
+    /// The eigenvalues with the smallest magnitude.
+    ///
+    /// The convergence toward those eigenvalues is usually slow. Computing the eigenvalues with
+    /// the largest magnitude of the inverse operator, e.g., with the shift-invert methods of
+    /// `CsMatrix`, is often much faster.
+    SmallestMagnitude,
+    /// The eigenvalues with the largest real part.
+    LargestReal,
+    /// The eigenvalues with the smallest real part.
+    SmallestReal,
+}
+
+impl EigenTarget {
+    // Orders eigenvalues from the most wanted to the least wanted.
+    fn cmp<N: RealField>(self, a: &NumComplex<N>, b: &NumComplex<N>) -> Ordering {
+        let key = |z: &NumComplex<N>| match self {
+            EigenTarget::LargestMagnitude => -modulus(z),
+            EigenTarget::SmallestMagnitude => modulus(z),
+            EigenTarget::LargestReal => -z.re,
+            EigenTarget::SmallestReal => z.re,
+        };
+
+        key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// A few eigenvalues and eigenvectors of a symmetric linear operator, computed by `lanczos`.
+#[derive(Clone, Debug)]
+pub struct PartialSymmetricEigen<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<N, D, Dynamic>
+{
+    /// The eigenvectors, with unit norms. The `i`-th column corresponds to the `i`-th eigenvalue.
+    pub eigenvectors: MatrixMN<N, D, Dynamic>,
+    /// The eigenvalues, sorted from the most wanted to the least wanted.
+    pub eigenvalues: DVector<N>,
+    /// The number of restarts performed.
+    pub niter: usize,
+}
+
+/// A few eigenvalues and eigenvectors of a general linear operator, computed by `arnoldi`.
+#[derive(Clone, Debug)]
+pub struct PartialEigen<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<NumComplex<N>, D, Dynamic>
+{
+    /// The right eigenvectors, with unit norms. The `i`-th column corresponds to the `i`-th
+    /// eigenvalue.
+    pub eigenvectors: MatrixMN<NumComplex<N>, D, Dynamic>,
+    /// The eigenvalues, sorted from the most wanted to the least wanted.
+    pub eigenvalues: DVector<NumComplex<N>>,
+    /// The number of restarts performed.
+    pub niter: usize,
+}
+
+/// Computes a few eigenpairs of a symmetric linear operator with the implicitly restarted
+/// Lanczos method.
+///
+/// Returns `None` if the eigenpairs did not converge after `max_niter` restarts. An eigenpair
+/// `(λ, x)` is considered converged if `‖A * x - λ * x‖ <= tolerance * |λ|`.
+///
+/// # Arguments
+///
+/// * `a`            − the symmetric linear operator.
+/// * `v0`           − the non-zero starting vector of the Krylov subspace. A vector with random
+/// components is usually a good choice.
+/// * `nev`          − the number of eigenpairs to compute.
+/// * `target`       − the part of the spectrum to compute.
+/// * `subspace_dim` − the dimension of the Krylov subspace, at least `nev + 1` and at most the
+/// dimension of `a`. Something like `2 * nev + 1` is usually a good choice.
+/// * `tolerance`    − the relative tolerance on the residual of each eigenpair.
+/// * `max_niter`    − the maximum number of restarts.
+pub fn lanczos<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    v0: &Vector<N, D, S>,
+    nev: usize,
+    target: EigenTarget,
+    subspace_dim: usize,
+    tolerance: N,
+    max_niter: usize,
+) -> Option<PartialSymmetricEigen<N, D>>
+where
+    DefaultAllocator: Allocator<N, D> + Allocator<N, D, Dynamic>,
+{
+    let mut factorization = ArnoldiFactorization::new(v0, nev, subspace_dim);
+    let eps23 = N::default_epsilon().powf(crate::convert(2.0 / 3.0));
+    let eps_sqrt = N::default_epsilon().sqrt();
+
+    for niter in 0..max_niter {
+        factorization.extend(a);
+
+        // The projected matrix is tridiagonal up to rounding errors.
+        let h = &factorization.h;
+        let eigen = SymmetricEigen::<N, Dynamic>::new((h + h.transpose()) * crate::convert::<_, N>(0.5));
+        let ritz_values: Vec<_> = eigen
+            .eigenvalues
+            .iter()
+            .map(|e| NumComplex::new(*e, N::zero()))
+            .collect();
+        let order = sorted_indices(&ritz_values, target);
+
+        let beta = factorization.residual.norm();
+        let last = subspace_dim - 1;
+        let nconv = order[..nev]
+            .iter()
+            .take_while(|i| {
+                let theta = eigen.eigenvalues[**i];
+                beta * eigen.eigenvectors[(last, **i)].abs() <= tolerance * theta.abs().max(eps23)
+            })
+            .count();
+
+        if nconv == nev {
+            let mut eigenvectors =
+                MatrixMN::zeros_generic(factorization.residual.data.shape().0, Dynamic::new(nev));
+
+            for (k, i) in order[..nev].iter().enumerate() {
+                let mut x = eigenvectors.column_mut(k);
+
+                for (j, v) in factorization.basis.iter().enumerate() {
+                    x.axpy(eigen.eigenvectors[(j, *i)], v, N::one());
+                }
+
+                let _ = x.normalize_mut();
+            }
+
+            let eigenvalues = DVector::from_iterator(nev, order[..nev].iter().map(|i| eigen.eigenvalues[*i]));
+
+            return Some(PartialSymmetricEigen {
+                eigenvectors,
+                eigenvalues,
+                niter,
+            });
+        }
+
+        let d = factorization.deflated_dim();
+        let sorted: Vec<_> = order.iter().map(|i| ritz_values[*i]).collect();
+        let deflated: Vec<_> = order
+            .iter()
+            .map(|i| (d..subspace_dim).all(|j| eigen.eigenvectors[(j, *i)].abs() <= eps_sqrt))
+            .collect();
+        let (shifts, k) = restart_shifts(&sorted, &deflated, d, nev, nconv);
+        factorization.restart(&shifts, k);
+    }
+
+    None
+}
+
+/// Computes a few eigenpairs of a general linear operator with the implicitly restarted Arnoldi
+/// method.
+///
+/// Returns `None` if the eigenpairs did not converge after `max_niter` restarts. An eigenpair
+/// `(λ, x)` is considered converged if `‖A * x - λ * x‖ <= tolerance * |λ|`. See `lanczos` for a
+/// description of the arguments.
+pub fn arnoldi<N: RealField, D: Dim, S: Storage<N, D>>(
+    a: &dyn LinearOperator<N, D>,
+    v0: &Vector<N, D, S>,
+    nev: usize,
+    target: EigenTarget,
+    subspace_dim: usize,
+    tolerance: N,
+    max_niter: usize,
+) -> Option<PartialEigen<N, D>>
+where
+    DefaultAllocator: Allocator<N, D> + Allocator<NumComplex<N>, D, Dynamic>,
+{
+    let mut factorization = ArnoldiFactorization::new(v0, nev, subspace_dim);
+    let eps23 = N::default_epsilon().powf(crate::convert(2.0 / 3.0));
+    let eps_sqrt = N::default_epsilon().sqrt();
+
+    for niter in 0..max_niter {
+        factorization.extend(a);
+
+        let eigen = Eigen::<N, Dynamic>::new(factorization.h.clone());
+        let ritz_values: Vec<_> = eigen.eigenvalues.iter().cloned().collect();
+        let order = sorted_indices(&ritz_values, target);
+
+        let beta = factorization.residual.norm();
+        let last = subspace_dim - 1;
+        let nconv = order[..nev]
+            .iter()
+            .take_while(|i| {
+                let theta = ritz_values[**i];
+                let residual = beta * modulus(&eigen.eigenvectors[(last, **i)]);
+                residual <= tolerance * modulus(&theta).max(eps23)
+            })
+            .count();
+
+        if nconv == nev {
+            let nrows = factorization.residual.data.shape().0;
+            let mut eigenvectors = MatrixMN::zeros_generic(nrows, Dynamic::new(nev));
+
+            for (k, i) in order[..nev].iter().enumerate() {
+                let mut re = VectorN::zeros_generic(nrows, U1);
+                let mut im = VectorN::zeros_generic(nrows, U1);
+
+                for (j, v) in factorization.basis.iter().enumerate() {
+                    let y = eigen.eigenvectors[(j, *i)];
+                    re.axpy(y.re, v, N::one());
+                    im.axpy(y.im, v, N::one());
+                }
+
+                let norm = (re.norm_squared() + im.norm_squared()).sqrt();
+
+                for (x, (re, im)) in eigenvectors.column_mut(k).iter_mut().zip(re.iter().zip(im.iter())) {
+                    *x = NumComplex::new(*re / norm, *im / norm);
+                }
+            }
+
+            let eigenvalues = DVector::from_iterator(nev, order[..nev].iter().map(|i| ritz_values[*i]));
+
+            return Some(PartialEigen {
+                eigenvectors,
+                eigenvalues,
+                niter,
+            });
+        }
+
+        let d = factorization.deflated_dim();
+        let sorted: Vec<_> = order.iter().map(|i| ritz_values[*i]).collect();
+        let deflated: Vec<_> = order
+            .iter()
+            .map(|i| (d..subspace_dim).all(|j| modulus(&eigen.eigenvectors[(j, *i)]) <= eps_sqrt))
+            .collect();
+        let (shifts, k) = restart_shifts(&sorted, &deflated, d, nev, nconv);
+        factorization.restart(&shifts, k);
+    }
+
+    None
+}
+
+fn modulus<N: RealField>(z: &NumComplex<N>) -> N {
+    (z.re * z.re + z.im * z.im).sqrt()
+}
+
+// Whether `a` and `b` are complex conjugate up to rounding errors.
+fn is_conjugate<N: RealField>(a: &NumComplex<N>, b: &NumComplex<N>) -> bool {
+    let eps = N::default_epsilon().sqrt() * modulus(a);
+    !a.im.is_zero() && (a.re - b.re).abs() <= eps && (a.im + b.im).abs() <= eps
+}
+
+// The indices of the given eigenvalues, sorted from the most wanted to the least wanted.
+fn sorted_indices<N: RealField>(values: &[NumComplex<N>], target: EigenTarget) -> Vec<usize> {
+    let mut order: Vec<_> = (0..values.len()).collect();
+    order.sort_by(|i, j| target.cmp(&values[*i], &values[*j]));
+    order
+}
+
+// The dimension of the Krylov subspace kept after a restart, given the sorted Ritz values.
+fn restart_dim<N: RealField>(sorted: &[NumComplex<N>], nev: usize, nconv: usize) -> usize {
+    // Keeping a few more vectors than wanted speeds up convergence.
+    let mut k = nev + nconv.min((sorted.len() - nev) / 2);
+
+    // Complex conjugate pairs must not be split between the wanted and unwanted Ritz values.
+    if k < sorted.len() && is_conjugate(&sorted[k], &sorted[k - 1]) {
+        k = if k + 1 < sorted.len() { k + 1 } else { k - 1 };
+    }
+
+    k
+}
+
+// The shifts of a restart and the number of vectors kept, given the sorted Ritz values, and
+// whether each of them belongs to the invariant subspace spanned by the first `d` basis vectors.
+// Those Ritz values cannot be purged by the shifts so they are kept along `nev` other ones.
+fn restart_shifts<N: RealField>(
+    sorted: &[NumComplex<N>],
+    deflated: &[bool],
+    d: usize,
+    nev: usize,
+    nconv: usize,
+) -> (Vec<NumComplex<N>>, usize)
+{
+    let m = sorted.len();
+
+    if d > 0 && d + nev < m {
+        let active: Vec<_> = sorted.iter().zip(deflated.iter()).filter(|e| !*e.1).map(|e| *e.0).collect();
+
+        if active.len() + d == m {
+            let k = restart_dim(&active, nev, nconv);
+            return (active[k..].to_vec(), d + k);
+        }
+    }
+
+    let k = restart_dim(sorted, nev, nconv);
+    (sorted[k..].to_vec(), k)
+}
+
+// An Arnoldi factorization `A * V = V * H + f * eₘᵀ` where `V` has `m` orthonormal columns `basis`
+// orthogonal to the residual `f`, and `H` is a `m x m` upper-Hessenberg matrix.
+struct ArnoldiFactorization<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<N, D>
+{
+    basis: Vec<VectorN<N, D>>,
+    h: DMatrix<N>,
+    residual: VectorN<N, D>,
+    // The largest norm of `A * v` encountered so far, used to detect invariant subspaces.
+    scale: N,
+    // The state of the pseudo-random generator of the vectors added to the basis when an
+    // invariant subspace is found.
+    seed: u64,
+}
+
+impl<N: RealField, D: Dim> ArnoldiFactorization<N, D>
+where DefaultAllocator: Allocator<N, D>
+{
+    fn new<S: Storage<N, D>>(v0: &Vector<N, D, S>, nev: usize, subspace_dim: usize) -> Self {
+        assert!(nev > 0, "At least one eigenpair must be computed.");
+        assert!(
+            subspace_dim > nev && subspace_dim <= v0.len(),
+            "The subspace dimension must be larger than `nev` and at most the operator dimension."
+        );
+
+        let v0_norm = v0.norm();
+        assert!(!v0_norm.is_zero(), "The starting vector must not be zero.");
+
+        ArnoldiFactorization {
+            basis: Vec::with_capacity(subspace_dim),
+            h: DMatrix::zeros(subspace_dim, subspace_dim),
+            residual: v0.unscale(v0_norm),
+            scale: N::zero(),
+            seed: 0x853c_49e6_748f_ea9b,
+        }
+    }
+
+    // The dimension of the invariant subspace spanned by the first basis vectors, if any.
+    fn deflated_dim(&self) -> usize {
+        let m = self.h.nrows();
+        (1..m).rev().find(|j| self.h[(*j, *j - 1)].is_zero()).unwrap_or(0)
+    }
+
+    // Extends the factorization until the basis reaches the dimension of `self.h`.
+    fn extend(&mut self, a: &dyn LinearOperator<N, D>) {
+        let m = self.h.nrows();
+        let eps = N::default_epsilon();
+
+        while self.basis.len() < m {
+            let j = self.basis.len();
+            let beta = self.residual.norm();
+
+            let v = if j == 0 {
+                // The residual is the normalized starting vector.
+                self.residual.clone()
+            } else if beta > eps * self.scale {
+                self.h[(j, j - 1)] = beta;
+                self.residual.unscale(beta)
+            } else {
+                // An invariant subspace has been found, continue with a random vector.
+                self.h[(j, j - 1)] = N::zero();
+                let mut v = self.random_vector();
+                let _ = self.orthogonalize(&mut v);
+                let _ = v.normalize_mut();
+                v
+            };
+
+            let mut w = v.clone();
+            a.apply(&v, &mut w);
+            self.scale = self.scale.max(w.norm());
+            self.basis.push(v);
+
+            let coeffs = self.orthogonalize(&mut w);
+
+            for (i, c) in coeffs.into_iter().enumerate() {
+                self.h[(i, j)] = c;
+            }
+
+            self.residual = w;
+        }
+    }
+
+    // Orthogonalizes `w` against the basis and returns the components removed.
+    fn orthogonalize(&self, w: &mut VectorN<N, D>) -> Vec<N> {
+        let mut coeffs: Vec<N> = self.basis.iter().map(|_| N::zero()).collect();
+
+        // NOTE: a second pass is needed to maintain orthogonality in finite precision.
+        for _ in 0..2 {
+            for (v, c) in self.basis.iter().zip(coeffs.iter_mut()) {
+                let dot = v.dot(w);
+                w.axpy(-dot, v, N::one());
+                *c += dot;
+            }
+        }
+
+        coeffs
+    }
+
+    fn random_vector(&mut self) -> VectorN<N, D> {
+        let mut res = self.residual.clone();
+
+        for e in res.iter_mut() {
+            // Linear congruential generator with the constants of PCG.
+            self.seed = self
+                .seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            *e = crate::convert((self.seed >> 33) as f64 / (1u64 << 31) as f64 - 0.5);
+        }
+
+        res
+    }
+
+    // Applies the given shifts with implicit QR steps, and truncates the factorization to `k`
+    // vectors.
+    fn restart(&mut self, shifts: &[NumComplex<N>], k: usize) {
+        let m = self.h.nrows();
+        let mut q = DMatrix::identity(m, m);
+        let mut shifts = shifts.to_vec();
+
+        // Deflate the negligible subdiagonal entries so that the shifts are applied to every
+        // unreduced block of `H`. Otherwise, the bulge would never cross the blocks found after
+        // an invariant subspace.
+        let eps = N::default_epsilon();
+        let mut blocks = Vec::new();
+        let mut first = 0;
+
+        for j in 1..=m {
+            if j == m || self.h[(j, j - 1)].abs() <= eps * (self.h[(j, j)].abs() + self.h[(j - 1, j - 1)].abs()) {
+                if j < m {
+                    self.h[(j, j - 1)] = N::zero();
+                }
+
+                if j - first > 1 {
+                    blocks.push((first, j));
+                }
+
+                first = j;
+            }
+        }
+
+        while let Some(mu) = shifts.pop() {
+            let conjugate = shifts.iter().position(|s| is_conjugate(&mu, s));
+
+            if let Some(i) = conjugate {
+                let _ = shifts.remove(i);
+            }
+
+            for &(first, end) in &blocks {
+                match conjugate {
+                    Some(_) => {
+                        // Apply the complex conjugate shifts together to keep real arithmetic.
+                        let two: N = crate::convert(2.0);
+                        self.double_shift_qr_step(&mut q, first, end, mu.re * two, mu.re * mu.re + mu.im * mu.im)
+                    }
+                    None => self.single_shift_qr_step(&mut q, first, end, mu.re),
+                }
+            }
+        }
+
+        // The new residual is `V * Q[:, k] * H[k, k - 1] + f * Q[m - 1, k - 1]`.
+        let mut basis: Vec<_> = (0..=k)
+            .map(|i| {
+                let mut v = self.residual.clone();
+                v.fill(N::zero());
+
+                for (j, vj) in self.basis.iter().enumerate() {
+                    v.axpy(q[(j, i)], vj, N::one());
+                }
+
+                v
+            })
+            .collect();
+
+        let vk = basis.pop().unwrap();
+        self.residual.axpy(self.h[(k, k - 1)], &vk, q[(m - 1, k - 1)]);
+        self.basis = basis;
+
+        for j in 0..m {
+            for i in 0..m {
+                if i >= k || j >= k {
+                    self.h[(i, j)] = N::zero();
+                }
+            }
+        }
+    }
+
+    // Performs an implicit QR step on the block `first..end` of `self.h` with the real shift `mu`,
+    // by bulge chasing.
+    fn single_shift_qr_step(&mut self, q: &mut DMatrix<N>, first: usize, end: usize, mu: N) {
+        let mut x = [self.h[(first, first)] - mu, self.h[(first + 1, first)], N::zero()];
+
+        for j in first..end - 1 {
+            if j > first {
+                x = [self.h[(j, j - 1)], self.h[(j + 1, j - 1)], N::zero()];
+            }
+
+            self.reflect(q, j, &x[..2], j > first);
+        }
+    }
+
+    // Performs an implicit QR step on the block `first..end` of `self.h` with the complex
+    // conjugate shifts that are the roots of `X² - s * X + t`, by bulge chasing.
+    fn double_shift_qr_step(&mut self, q: &mut DMatrix<N>, first: usize, end: usize, s: N, t: N) {
+        let h = &self.h;
+        let f = first;
+
+        // The first column of `H² - s * H + t * I`.
+        let mut x = [
+            h[(f, f)] * h[(f, f)] + h[(f, f + 1)] * h[(f + 1, f)] - s * h[(f, f)] + t,
+            h[(f + 1, f)] * (h[(f, f)] + h[(f + 1, f + 1)] - s),
+            if end - f > 2 { h[(f + 1, f)] * h[(f + 2, f + 1)] } else { N::zero() },
+        ];
+
+        for j in first..end - 1 {
+            let len = (end - j).min(3);
+
+            if j > first {
+                for (i, e) in x[..len].iter_mut().enumerate() {
+                    *e = self.h[(j + i, j - 1)];
+                }
+            }
+
+            self.reflect(q, j, &x[..len], j > first);
+        }
+    }
+
+    // Applies to `self.h` the similarity by the householder reflection of the rows and columns
+    // `start..start + x.len()` that maps `x` to a multiple of the first canonical basis vector.
+    // The transformation is accumulated into `q`. If `chase` is `true`, `x` is the bulge below
+    // the subdiagonal of the column `start - 1`.
+    fn reflect(&mut self, q: &mut DMatrix<N>, start: usize, x: &[N], chase: bool) {
+        let m = self.h.nrows();
+        let norm = x.iter().fold(N::zero(), |acc, e| acc + *e * *e).sqrt();
+
+        if norm.is_zero() {
+            return;
+        }
+
+        let alpha = if x[0] > N::zero() { -norm } else { norm };
+        let mut v = [N::zero(); 3];
+        v[..x.len()].copy_from_slice(x);
+        v[0] -= alpha;
+        let v = &v[..x.len()];
+        let two: N = crate::convert(2.0);
+        let factor = two / v.iter().fold(N::zero(), |acc, e| acc + *e * *e);
+
+        // H = P * H
+        for j in 0..m {
+            let dot = v.iter().enumerate().fold(N::zero(), |acc, (i, e)| acc + *e * self.h[(start + i, j)]);
+
+            for (i, e) in v.iter().enumerate() {
+                self.h[(start + i, j)] -= factor * dot * *e;
+            }
+        }
+
+        // H = H * P and Q = Q * P
+        for mat in [&mut self.h, q].iter_mut() {
+            for i in 0..m {
+                let dot = v.iter().enumerate().fold(N::zero(), |acc, (j, e)| acc + *e * mat[(i, start + j)]);
+
+                for (j, e) in v.iter().enumerate() {
+                    mat[(i, start + j)] -= factor * dot * *e;
+                }
+            }
+        }
+
+        // The bulge has been moved down by one column.
+        if chase {
+            self.h[(start, start - 1)] = alpha;
+
+            for i in 1..x.len() {
+                self.h[(start + i, start - 1)] = N::zero();
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use alga::general::ComplexField;
+use num::Zero;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix};
+use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::storage::Storage;
+
+use crate::linalg::SVD;
+
+/// The polar decomposition of a general matrix.
+///
+/// This computes `A = U * P` where `P` is a hermitian positive-semidefinite matrix and `U` has
+/// orthonormal columns (or orthonormal rows if `A` has less rows than columns). If `A` is square,
+/// `U` is the unitary matrix closest to `A` in the Frobenius norm.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        serialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, C, C>,
+         MatrixMN<N, R, C>: Serialize,
+         MatrixN<N, C>: Serialize"
+    ))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        deserialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, C, C>,
+         MatrixMN<N, R, C>: Deserialize<'de>,
+         MatrixN<N, C>: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct Polar<N: ComplexField, R: Dim, C: Dim>
+where DefaultAllocator: Allocator<N, R, C> + Allocator<N, C, C>
+{
+    /// The unitary factor of the decomposition.
+    pub u: MatrixMN<N, R, C>,
+    /// The hermitian positive-semidefinite factor of the decomposition.
+    pub p: MatrixN<N, C>,
+}
+
+impl<N: ComplexField, R: Dim, C: Dim> Copy for Polar<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, C, C>,
+    MatrixMN<N, R, C>: Copy,
+    MatrixN<N, C>: Copy,
+{}
+
+impl<N: ComplexField, R: Dim, C: Dim> Polar<N, R, C>
+where DefaultAllocator: Allocator<N, R, C> + Allocator<N, C, C>
+{
+    /// Retrieves the unitary factor `U` and the hermitian positive-semidefinite factor `P` of
+    /// this decomposition.
+    pub fn unpack(self) -> (MatrixMN<N, R, C>, MatrixN<N, C>) {
+        (self.u, self.p)
+    }
+
+    /// Rebuilds the decomposed matrix `U * P`.
+    pub fn recompose(&self) -> MatrixMN<N, R, C> {
+        &self.u * &self.p
+    }
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> Polar<N, R, C>
+where
+    DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, C, C>
+        + Allocator<N, C>
+        + Allocator<N, R>
+        + Allocator<N, DimDiff<DimMinimum<R, C>, U1>>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, R, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N::RealField, DimMinimum<R, C>>
+        + Allocator<N::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+{
+    /// Computes the polar decomposition of `matrix` from its singular value decomposition.
+    ///
+    /// If `matrix = W * Σ * Vᴴ`, then `U = W * Vᴴ` and `P = V * Σ * Vᴴ`.
+    pub fn new(matrix: MatrixMN<N, R, C>) -> Self {
+        let svd = SVD::new(matrix, true, true);
+        let w = svd.u.unwrap();
+        let v_t = svd.v_t.unwrap();
+
+        let mut sigma_v_t = v_t.clone();
+        for i in 0..svd.singular_values.len() {
+            sigma_v_t.row_mut(i).scale_mut(svd.singular_values[i]);
+        }
+
+        Polar {
+            u: &w * &v_t,
+            p: v_t.ad_mul(&sigma_v_t),
+        }
+    }
+}
+
+impl<N: ComplexField, D: Dim> Polar<N, D, D>
+where DefaultAllocator: Allocator<N, D, D>
+{
+    /// Attempts to compute the polar decomposition of the square matrix `matrix` using the
+    /// scaled Newton iteration `X ← (γX + X⁻ᴴ/γ) / 2`.
+    ///
+    /// This converges quadratically and is usually faster than the SVD-based decomposition.
+    /// Returns `None` if `matrix` is not invertible or if the iteration did not converge.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine convergence: the iteration stops when the
+    /// relative change of the unitary factor in the Frobenius norm is smaller than `eps`.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new_newton(matrix: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Option<Self> {
+        assert!(matrix.is_square(), "Unable to compute the polar decomposition of a non-square matrix.");
+        assert!(
+            eps >= N::RealField::zero(),
+            "Polar decomposition: the epsilon must be non-negative."
+        );
+
+        let half: N::RealField = crate::convert(0.5);
+        let mut u = matrix.clone();
+        let mut niter = 0;
+
+        loop {
+            let u_inv = u.clone().try_inverse()?;
+            let gamma = (u_inv.norm() / u.norm()).sqrt();
+
+            let mut new_u = u.scale(gamma) + u_inv.adjoint().unscale(gamma);
+            new_u.scale_mut(half);
+
+            let change = (&new_u - &u).norm();
+            u = new_u;
+
+            if change <= eps * u.norm() {
+                break;
+            }
+
+            niter += 1;
+            if niter == max_niter {
+                return None;
+            }
+        }
+
+        // `Uᴴ * A` is hermitian in exact arithmetic, so remove the effect of rounding errors.
+        let p = u.ad_mul(&matrix);
+        let p = (&p + p.adjoint()).scale(half);
+
+        Some(Polar { u, p })
+    }
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
+where
+    DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, C, C>
+        + Allocator<N, C>
+        + Allocator<N, R>
+        + Allocator<N, DimDiff<DimMinimum<R, C>, U1>>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, R, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N::RealField, DimMinimum<R, C>>
+        + Allocator<N::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+{
+    /// Computes the polar decomposition of this matrix using its singular value decomposition.
+    pub fn polar(self) -> Polar<N, R, C> {
+        Polar::new(self.into_owned())
+    }
+}
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where DefaultAllocator: Allocator<N, D, D>
+{
+    /// Attempts to compute the polar decomposition of this square matrix using a scaled Newton
+    /// iteration.
+    ///
+    /// See `Polar::try_new_newton` for a description of the arguments.
+    pub fn try_polar_newton(self, eps: N::RealField, max_niter: usize) -> Option<Polar<N, D, D>> {
+        Polar::try_new_newton(self.into_owned(), eps, max_niter)
+    }
+}
@@ -11,6 +11,7 @@ use crate::storage::{Storage, StorageMut};
 
 use crate::geometry::Reflection;
 use crate::linalg::householder;
+use crate::linalg::LinalgError;
 
 /// The QR decomposition of a general matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -74,6 +75,12 @@ where DefaultAllocator: Allocator<N, R, C> + Allocator<N, R> + Allocator<N, DimM
         }
     }
 
+    /// Builds a decomposition from householder reflectors already stored in the lower
+    /// triangle of `qr`, as computed by `householder::clear_column_unchecked`.
+    pub(crate) fn from_parts(qr: MatrixMN<N, R, C>, diag: VectorN<N, DimMinimum<R, C>>) -> Self {
+        QR { qr, diag }
+    }
+
     /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
     #[inline]
     pub fn r(&self) -> MatrixMN<N, DimMinimum<R, C>, C>
@@ -143,6 +150,11 @@ where DefaultAllocator: Allocator<N, R, C> + Allocator<N, R> + Allocator<N, DimM
         &self.qr
     }
 
+    // The diagonal elements of `R`, with the signs of the householder reflections.
+    pub(crate) fn diag_internal(&self) -> &VectorN<N, DimMinimum<R, C>> {
+        &self.diag
+    }
+
     /// Multiplies the provided matrix by the transpose of the `Q` matrix of this decomposition.
     pub fn q_tr_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
     // FIXME: do we need a static constraint on the number of rows of rhs?
@@ -241,6 +253,46 @@ where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>
         true
     }
 
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible.
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Result<MatrixMN<N, R2, C2>, LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.try_solve_mut(&mut res)?;
+        Ok(res)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `LinalgError::Singular` if the decomposed matrix is not invertible, in which case
+    /// its input `b` is left unchanged.
+    pub fn try_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> Result<(), LinalgError>
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        match self.first_zero_pivot() {
+            Some(pivot) => Err(LinalgError::Singular { pivot }),
+            None => {
+                let _ = self.solve_mut(b);
+                Ok(())
+            }
+        }
+    }
+
+    // The index of the first zero pivot of this decomposition, if any.
+    fn first_zero_pivot(&self) -> Option<usize> {
+        (0..self.qr.nrows()).find(|i| self.diag[*i].is_zero())
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the decomposed matrix is not invertible.
@@ -15,6 +15,7 @@ use crate::geometry::Reflection;
 use crate::linalg::householder;
 use crate::linalg::Hessenberg;
 use crate::linalg::givens::GivensRotation;
+use crate::linalg::LinalgError;
 
 /// Schur decomposition of a square matrix.
 ///
@@ -81,6 +82,14 @@ where
         })
     }
 
+    /// Attempts to compute the Schur decomposition of a square matrix.
+    ///
+    /// Returns `LinalgError::NoConvergence` if the algorithm did not converge after `max_niter`
+    /// iterations. See `Schur::try_new` for a description of the arguments.
+    pub fn try_decompose(m: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Result<Self, LinalgError> {
+        Self::try_new(m, eps, max_niter).ok_or(LinalgError::NoConvergence { niter: max_niter })
+    }
+
     fn do_decompose(
         mut m: MatrixN<N, D>,
         work: &mut VectorN<N, D>,
@@ -461,7 +470,7 @@ fn compute_2x2_eigvals<N: ComplexField, S: Storage<N, U2, U2>>(
 ///
 /// Returns `None` if the matrix has complex eigenvalues, or is upper-triangular. In both case,
 /// the basis is the identity.
-fn compute_2x2_basis<N: ComplexField, S: Storage<N, U2, U2>>(
+pub(crate) fn compute_2x2_basis<N: ComplexField, S: Storage<N, U2, U2>>(
     m: &SquareMatrix<N, U2, S>,
 ) -> Option<GivensRotation<N>> {
     let h10 = m[(1, 0)];
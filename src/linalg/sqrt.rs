@@ -0,0 +1,187 @@
+//! Matrix square root.
+
+use alga::general::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1, U2};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix2, Matrix4, MatrixN, SquareMatrix, Vector4};
+
+use crate::linalg::Schur;
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    D: DimSub<U1>,                                   // For Hessenberg.
+    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N, D, D>
+        + Allocator<N, D>,
+{
+    /// Computes the principal square root of this square matrix.
+    ///
+    /// This is the unique square root with eigenvalues with positive real parts. It is computed
+    /// from the Schur decomposition of this matrix. Returns `None` if this matrix is singular
+    /// with a repeated zero eigenvalue, or if it is a real matrix with a negative real
+    /// eigenvalue (in which case its principal square root is not real). No heap allocation is
+    /// performed if this matrix is statically-sized.
+    pub fn sqrtm(&self) -> Option<MatrixN<N, D>> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the square root of a non-square matrix."
+        );
+
+        let (q, t) = Schur::new(self.clone_owned()).unpack();
+        let sqrt_t = sqrt_quasi_triangular(&t)?;
+
+        Some(&q * sqrt_t * q.adjoint())
+    }
+}
+
+/// The size of the diagonal block starting at the `i`-th row of the quasi-triangular matrix `t`.
+pub(crate) fn block_size<N: ComplexField, D: Dim>(t: &MatrixN<N, D>, i: usize) -> usize
+where DefaultAllocator: Allocator<N, D, D> {
+    if i + 1 < t.nrows() && !t[(i + 1, i)].is_zero() {
+        2
+    } else {
+        1
+    }
+}
+
+/// Computes the principal square root of a quasi-upper-triangular matrix, e.g., the `T` factor
+/// of a real Schur decomposition.
+///
+/// This follows the block recurrence of N. J. Higham, "Computing real square roots of a real
+/// matrix", Linear Algebra Appl., 88/89, 1987.
+pub(crate) fn sqrt_quasi_triangular<N: ComplexField, D: Dim>(
+    t: &MatrixN<N, D>,
+) -> Option<MatrixN<N, D>>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let (dim, _) = t.data.shape();
+    let n = dim.value();
+    let mut u = MatrixN::zeros_generic(dim, dim);
+
+    let mut j = 0;
+    while j < n {
+        let q = block_size(t, j);
+
+        // Square root of the diagonal block.
+        if q == 1 {
+            u[(j, j)] = t[(j, j)].try_sqrt()?;
+        } else {
+            let block = sqrt_2x2_complex_eigenvalues(&t.fixed_slice::<U2, U2>(j, j).into_owned())?;
+            u.fixed_slice_mut::<U2, U2>(j, j).copy_from(&block);
+        }
+
+        // Off-diagonal blocks of the j-th block column, from the bottom to the top.
+        let mut i = j;
+        while i > 0 {
+            let p = if i >= 2 && !t[(i - 1, i - 2)].is_zero() { 2 } else { 1 };
+            i -= p;
+
+            let mut rhs = Matrix2::new(N::zero(), N::zero(), N::zero(), N::zero());
+            for a in 0..p {
+                for b in 0..q {
+                    let mut val = t[(i + a, j + b)];
+
+                    for k in i + p..j {
+                        val -= u[(i + a, k)] * u[(k, j + b)];
+                    }
+
+                    rhs[(a, b)] = val;
+                }
+            }
+
+            let uii = diagonal_block(&u, i, p);
+            let ujj = diagonal_block(&u, j, q);
+            let x = solve_small_sylvester(&uii, p, &ujj, q, &rhs)?;
+
+            for a in 0..p {
+                for b in 0..q {
+                    u[(i + a, j + b)] = x[(a, b)];
+                }
+            }
+        }
+
+        j += q;
+    }
+
+    Some(u)
+}
+
+/// Square root of a 2x2 matrix with a pair of complex conjugate eigenvalues `θ ± iμ`.
+fn sqrt_2x2_complex_eigenvalues<N: ComplexField>(b: &Matrix2<N>) -> Option<Matrix2<N>> {
+    let half: N = crate::convert(0.5);
+    let theta = (b.m11 + b.m22) * half;
+    let det = b.m11 * b.m22 - b.m12 * b.m21;
+    // If θ + iμ = (α + iβ)², then α² = (θ + |θ + iμ|) / 2.
+    let alpha = ((theta + det.try_sqrt()?) * half).try_sqrt()?;
+
+    if alpha.is_zero() {
+        return None;
+    }
+
+    let mut res = (b - Matrix2::identity() * theta) / (alpha + alpha);
+    res.m11 += alpha;
+    res.m22 += alpha;
+    Some(res)
+}
+
+/// The `size × size` diagonal block of `u` starting at the `i`-th row, padded with zeros.
+fn diagonal_block<N: ComplexField, D: Dim>(u: &MatrixN<N, D>, i: usize, size: usize) -> Matrix2<N>
+where DefaultAllocator: Allocator<N, D, D> {
+    if size == 1 {
+        Matrix2::new(u[(i, i)], N::zero(), N::zero(), N::zero())
+    } else {
+        Matrix2::new(u[(i, i)], u[(i, i + 1)], u[(i + 1, i)], u[(i + 1, i + 1)])
+    }
+}
+
+/// Solves the Sylvester equation `a * X + X * b = rhs` where `a` (resp. `b`) is a `p × p`
+/// (resp. `q × q`) matrix stored on the top-left corner of a 2x2 matrix.
+fn solve_small_sylvester<N: ComplexField>(
+    a: &Matrix2<N>,
+    p: usize,
+    b: &Matrix2<N>,
+    q: usize,
+    rhs: &Matrix2<N>,
+) -> Option<Matrix2<N>> {
+    // Kronecker formulation `(I ⊗ a + b^T ⊗ I) vec(X) = vec(rhs)`. The unused rows and
+    // columns are set to the identity so the system can always be solved as a 4x4 one.
+    let mut k = Matrix4::identity();
+    let mut v = Vector4::zeros();
+    let idx = |r: usize, c: usize| r + c * p;
+
+    for col in 0..q {
+        for r in 0..p {
+            let row = idx(r, col);
+            k[(row, row)] = N::zero();
+            v[row] = rhs[(r, col)];
+
+            for c in 0..p {
+                k[(row, idx(c, col))] += a[(r, c)];
+            }
+
+            for d in 0..q {
+                k[(row, idx(r, d))] += b[(d, col)];
+            }
+        }
+    }
+
+    let x = match k.lu().solve(&v) {
+        Some(x) => x,
+        // The system is singular but consistent if the right-hand-side is zero.
+        None if v.iter().all(|e| e.is_zero()) => Vector4::zeros(),
+        None => return None,
+    };
+
+    let mut res = Matrix2::zeros();
+    for col in 0..q {
+        for r in 0..p {
+            res[(r, col)] = x[idx(r, col)];
+        }
+    }
+
+    Some(res)
+}
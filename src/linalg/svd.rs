@@ -6,14 +6,16 @@ use approx::AbsDiffEq;
 
 use alga::general::{RealField, ComplexField};
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, Matrix2x3, MatrixMN, Vector2, VectorN};
+use crate::base::{DefaultAllocator, Matrix, Matrix2x3, MatrixMN, MatrixN, Vector2, VectorN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1, U2};
+use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, Dynamic, U1, U2};
 use crate::storage::Storage;
 
 use crate::linalg::symmetric_eigen;
 use crate::linalg::Bidiagonal;
+use crate::linalg::ColPivQR;
 use crate::linalg::givens::GivensRotation;
+use crate::linalg::LinalgError;
 
 /// Singular Value Decomposition of a general matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -285,6 +287,21 @@ where
         })
     }
 
+    /// Attempts to compute the Singular Value Decomposition of `matrix` using implicit shift.
+    ///
+    /// Returns `LinalgError::NoConvergence` if the algorithm did not converge after `max_niter`
+    /// iterations. See `SVD::try_new` for a description of the arguments.
+    pub fn try_decompose(
+        matrix: MatrixMN<N, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError>
+    {
+        Self::try_new(matrix, compute_u, compute_v, eps, max_niter).ok_or(LinalgError::NoConvergence { niter: max_niter })
+    }
+
     /*
     fn display_bidiag(b: &Bidiagonal<N, R, C>, begin: usize, end: usize) {
         for i in begin .. end {
@@ -453,6 +470,131 @@ where
         self.singular_values.iter().filter(|e| **e > eps).count()
     }
 
+    /// Computes the condition number of the decomposed matrix in the spectral norm, i.e., the
+    /// ratio between its greatest and smallest singular values.
+    ///
+    /// This is infinite if the decomposed matrix is singular, and zero if it has no row or no
+    /// column.
+    pub fn condition_number(&self) -> N::RealField {
+        if self.singular_values.len() == 0 {
+            return N::RealField::zero();
+        }
+
+        let mut min = self.singular_values[0];
+        let mut max = self.singular_values[0];
+
+        for val in self.singular_values.iter() {
+            if *val < min {
+                min = *val;
+            }
+
+            if *val > max {
+                max = *val;
+            }
+        }
+
+        max / min
+    }
+
+    /// Computes an orthonormal basis of the column space (aka. range) of the decomposed matrix.
+    ///
+    /// The basis is made of the left-singular vectors associated to the singular values greater
+    /// than `eps`, sorted by decreasing singular value.
+    /// Returns `LinalgError::InvalidTolerance` if `eps` is negative, and `LinalgError::MissingU`
+    /// if the left-singular vectors have not been computed at construction-time.
+    pub fn column_space(&self, eps: N::RealField) -> Result<MatrixMN<N, R, Dynamic>, LinalgError>
+    where DefaultAllocator: Allocator<N, R, Dynamic> {
+        if eps < N::RealField::zero() {
+            return Err(LinalgError::InvalidTolerance);
+        }
+
+        match &self.u {
+            Some(u) => {
+                let sv = &self.singular_values;
+                let rank = sv.iter().filter(|e| **e > eps).count();
+                let nrows = u.data.shape().0;
+                let mut res = MatrixMN::zeros_generic(nrows, Dynamic::new(rank));
+                let mut prev: Option<usize> = None;
+
+                // Selection sort of the singular values by decreasing value, ties being broken by
+                // increasing index. The singular values selected so far are exactly those that
+                // come before `prev` in this order.
+                for j in 0..rank {
+                    let mut best: Option<usize> = None;
+
+                    for i in 0..sv.len() {
+                        if !(sv[i] > eps) {
+                            continue;
+                        }
+
+                        if let Some(p) = prev {
+                            if sv[i] > sv[p] || (sv[i] == sv[p] && i <= p) {
+                                continue;
+                            }
+                        }
+
+                        match best {
+                            Some(b) if sv[b] >= sv[i] => {}
+                            _ => best = Some(i),
+                        }
+                    }
+
+                    if let Some(i) = best {
+                        res.column_mut(j).copy_from(&u.column(i));
+                        prev = best;
+                    }
+                }
+
+                Ok(res)
+            }
+            None => Err(LinalgError::MissingU),
+        }
+    }
+
+    /// Computes an orthonormal basis of the null space (aka. kernel) of the decomposed matrix.
+    ///
+    /// Any singular value smaller than or equal to `eps` is assumed to be zero. If the decomposed
+    /// matrix has more columns than rows, the basis also spans the directions that are not
+    /// covered by the (thin) right-singular vectors.
+    /// Returns `LinalgError::InvalidTolerance` if `eps` is negative, and `LinalgError::MissingVt`
+    /// if the right-singular vectors have not been computed at construction-time.
+    pub fn null_space(&self, eps: N::RealField) -> Result<MatrixMN<N, C, Dynamic>, LinalgError>
+    where
+        C: DimMin<C, Output = C>,
+        DefaultAllocator: Allocator<N, C, C>
+            + Allocator<N::RealField, C>
+            + Allocator<(usize, usize), C>
+            + Allocator<N, C, Dynamic>,
+    {
+        if eps < N::RealField::zero() {
+            return Err(LinalgError::InvalidTolerance);
+        }
+
+        match &self.v_t {
+            Some(v_t) => {
+                // The null space is the range of the orthogonal projector `I - Vᵣ * Vᵣᴴ` where
+                // `Vᵣ` contains the right-singular vectors associated to non-zero singular values.
+                let ncols = v_t.data.shape().1;
+                let mut proj = MatrixN::identity_generic(ncols, ncols);
+                let mut rank = 0;
+
+                for i in 0..self.singular_values.len() {
+                    if self.singular_values[i] > eps {
+                        let v = v_t.row(i).adjoint();
+                        proj.gerc(-N::one(), &v, &v, N::one());
+                        rank += 1;
+                    }
+                }
+
+                // The projector has only zero or unit singular values so the pivoted QR
+                // decomposition reveals its range reliably.
+                let q = ColPivQR::new(proj).q();
+                Ok(q.columns(0, ncols.value() - rank).into_owned())
+            }
+            None => Err(LinalgError::MissingVt),
+        }
+    }
+
     /// Rebuild the original matrix.
     ///
     /// This is useful if some of the singular values have been manually modified.
@@ -478,26 +620,18 @@ where
     /// Any singular value smaller than `eps` is assumed to be zero.
     /// Returns `Err` if the right- and left- singular vectors have not
     /// been computed at construction-time.
-    pub fn pseudo_inverse(mut self, eps: N::RealField) -> Result<MatrixMN<N, C, R>, &'static str>
+    pub fn pseudo_inverse(self, eps: N::RealField) -> Result<MatrixMN<N, C, R>, &'static str>
     where
         DefaultAllocator: Allocator<N, C, R>,
     {
-        if eps < N::RealField::zero() {
-            Err("SVD pseudo inverse: the epsilon must be non-negative.")
-        }
-        else {
-            for i in 0..self.singular_values.len() {
-                let val = self.singular_values[i];
+        let (has_u, has_v_t) = (self.u.is_some(), self.v_t.is_some());
 
-                if val > eps {
-                    self.singular_values[i] = N::RealField::one() / val;
-                } else {
-                    self.singular_values[i] = N::RealField::zero();
-                }
-            }
-
-            self.recompose().map(|m| m.adjoint())
-        }
+        self.try_pseudo_inverse(eps).map_err(|e| match e {
+            LinalgError::InvalidTolerance => "SVD pseudo inverse: the epsilon must be non-negative.",
+            _ if !has_u && !has_v_t => "SVD recomposition: U and V^t have not been computed.",
+            LinalgError::MissingU => "SVD recomposition: U has not been computed.",
+            _ => "SVD recomposition: V^t has not been computed.",
+        })
     }
 
     /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
@@ -510,38 +644,107 @@ where
         b: &Matrix<N, R2, C2, S2>,
         eps: N::RealField,
     ) -> Result<MatrixMN<N, C, C2>, &'static str>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, C, C2> + Allocator<N, DimMinimum<R, C>, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        self.try_solve(b, eps).map_err(|e| match e {
+            LinalgError::InvalidTolerance => "SVD solve: the epsilon must be non-negative.",
+            _ if self.u.is_none() && self.v_t.is_none() => "SVD solve: U and V^t have not been computed.",
+            LinalgError::MissingU => "SVD solve: U has not been computed.",
+            _ => "SVD solve: V^t has not been computed.",
+        })
+    }
+
+    // Checks that both the left- and right- singular vectors have been computed.
+    fn check_singular_vectors(&self) -> Result<(), LinalgError> {
+        if self.u.is_none() {
+            Err(LinalgError::MissingU)
+        } else if self.v_t.is_none() {
+            Err(LinalgError::MissingVt)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Rebuild the original matrix.
+    ///
+    /// Returns `LinalgError::MissingU` (resp. `LinalgError::MissingVt`) if the left- (resp.
+    /// right-) singular vectors have not been computed at construction-time.
+    pub fn try_recompose(self) -> Result<MatrixMN<N, R, C>, LinalgError> {
+        self.check_singular_vectors()?;
+        Ok(self.recompose().unwrap())
+    }
+
+    /// Computes the pseudo-inverse of the decomposed matrix.
+    ///
+    /// Any singular value smaller than `eps` is assumed to be zero.
+    /// Returns `LinalgError::InvalidTolerance` if `eps` is negative, and `LinalgError::MissingU`
+    /// (resp. `LinalgError::MissingVt`) if the left- (resp. right-) singular vectors have not been
+    /// computed at construction-time.
+    pub fn try_pseudo_inverse(mut self, eps: N::RealField) -> Result<MatrixMN<N, C, R>, LinalgError>
+    where
+        DefaultAllocator: Allocator<N, C, R>,
+    {
+        if eps < N::RealField::zero() {
+            return Err(LinalgError::InvalidTolerance);
+        }
+
+        for i in 0..self.singular_values.len() {
+            let val = self.singular_values[i];
+
+            if val > eps {
+                self.singular_values[i] = N::RealField::one() / val;
+            } else {
+                self.singular_values[i] = N::RealField::zero();
+            }
+        }
+
+        self.try_recompose().map(|m| m.adjoint())
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// Any singular value smaller than `eps` is assumed to be zero.
+    /// Returns `LinalgError::InvalidTolerance` if `eps` is negative, and `LinalgError::MissingU`
+    /// (resp. `LinalgError::MissingVt`) if the left- (resp. right-) singular vectors have not been
+    /// computed at construction-time.
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        eps: N::RealField,
+    ) -> Result<MatrixMN<N, C, C2>, LinalgError>
     where
         S2: Storage<N, R2, C2>,
         DefaultAllocator: Allocator<N, C, C2> + Allocator<N, DimMinimum<R, C>, C2>,
         ShapeConstraint: SameNumberOfRows<R, R2>,
     {
         if eps < N::RealField::zero() {
-            Err("SVD solve: the epsilon must be non-negative.")
+            return Err(LinalgError::InvalidTolerance);
         }
-        else {
-            match (&self.u, &self.v_t) {
-                (Some(u), Some(v_t)) => {
-                    let mut ut_b = u.ad_mul(b);
-
-                    for j in 0..ut_b.ncols() {
-                        let mut col = ut_b.column_mut(j);
-
-                        for i in 0..self.singular_values.len() {
-                            let val = self.singular_values[i];
-                            if val > eps {
-                                col[i] = col[i].unscale(val);
-                            } else {
-                                col[i] = N::zero();
-                            }
+
+        match (&self.u, &self.v_t) {
+            (Some(u), Some(v_t)) => {
+                let mut ut_b = u.ad_mul(b);
+
+                for j in 0..ut_b.ncols() {
+                    let mut col = ut_b.column_mut(j);
+
+                    for i in 0..self.singular_values.len() {
+                        let val = self.singular_values[i];
+                        if val > eps {
+                            col[i] = col[i].unscale(val);
+                        } else {
+                            col[i] = N::zero();
                         }
                     }
-
-                    Ok(v_t.ad_mul(&ut_b))
                 }
-                (None, None) => Err("SVD solve: U and V^t have not been computed."),
-                (None, _) => Err("SVD solve: U has not been computed."),
-                (_, None) => Err("SVD solve: V^t has not been computed.")
+
+                Ok(v_t.ad_mul(&ut_b))
             }
+            (None, _) => Err(LinalgError::MissingU),
+            (_, None) => Err(LinalgError::MissingVt),
         }
     }
 }
@@ -607,12 +810,60 @@ where
     {
         SVD::new(self.clone_owned(), true, true).pseudo_inverse(eps)
     }
+
+    /// The spectral norm of this matrix, i.e., the operator norm induced by the euclidean vector
+    /// norm. This is equal to the greatest singular value of this matrix.
+    pub fn spectral_norm(&self) -> N::RealField {
+        self.singular_values().iter().fold(N::RealField::zero(), |acc, e| if *e > acc { *e } else { acc })
+    }
+
+    /// Computes the condition number of this matrix in the spectral norm, i.e., the ratio
+    /// between its greatest and smallest singular values.
+    ///
+    /// This is infinite if this matrix is singular, and zero if it has no row or no column. See
+    /// `.rcond_estimate()` for a cheaper estimate based on the LU decomposition.
+    pub fn condition_number(&self) -> N::RealField {
+        if self.is_empty() {
+            return N::RealField::zero();
+        }
+
+        SVD::new(self.clone_owned(), false, false).condition_number()
+    }
+
+    /// Computes an orthonormal basis of the column space of this matrix.
+    ///
+    /// All singular values below `eps` are considered equal to 0.
+    /// Returns `LinalgError::InvalidTolerance` if `eps` is negative.
+    pub fn column_space(&self, eps: N::RealField) -> Result<MatrixMN<N, R, Dynamic>, LinalgError>
+    where DefaultAllocator: Allocator<N, R, Dynamic> {
+        SVD::new(self.clone_owned(), true, false).column_space(eps)
+    }
+
+    /// Computes an orthonormal basis of the null space of this matrix.
+    ///
+    /// All singular values below `eps` are considered equal to 0.
+    /// Returns `LinalgError::InvalidTolerance` if `eps` is negative.
+    pub fn null_space(&self, eps: N::RealField) -> Result<MatrixMN<N, C, Dynamic>, LinalgError>
+    where
+        C: DimMin<C, Output = C>,
+        DefaultAllocator: Allocator<N, C, C>
+            + Allocator<N::RealField, C>
+            + Allocator<(usize, usize), C>
+            + Allocator<N, C, Dynamic>,
+    {
+        SVD::new(self.clone_owned(), false, true).null_space(eps)
+    }
 }
 
 
-// Explicit formulae inspired from the paper "Computing the Singular Values of 2-by-2 Complex
-// Matrices", Sanzheng Qiao and Xiaohong Wang.
-// http://www.cas.mcmaster.ca/sqrl/papers/sqrl5.pdf
+// Computes the SVD `U * diag(s1, s2) * V^t` of the upper-triangular matrix `[m11, m12; 0, m22]`,
+// where `s1` is the singular value with the greatest magnitude. The singular values may be
+// negative.
+//
+// This follows the algorithm of the LAPACK routine `dlasv2`, from J. Demmel and W. Kahan,
+// "Accurate singular values of bidiagonal matrices", SIAM J. Sci. Stat. Comput., 11(5), 1990.
+// Contrary to explicit formulae, it remains accurate when the singular values are close to each
+// other, or when one of them is close to zero.
 fn compute_2x2_uptrig_svd<N: RealField>(
     m11: N,
     m12: N,
@@ -621,40 +872,113 @@ fn compute_2x2_uptrig_svd<N: RealField>(
     compute_v: bool,
 ) -> (Option<GivensRotation<N>>, Vector2<N>, Option<GivensRotation<N>>)
 {
-    let two: N::RealField = crate::convert(2.0f64);
-    let half: N::RealField = crate::convert(0.5f64);
-
-    let denom = (m11 + m22).hypot(m12) + (m11 - m22).hypot(m12);
-
-    // NOTE: v1 is the singular value that is the closest to m22.
-    // This prevents cancellation issues when constructing the vector `csv` below. If we chose
-    // otherwise, we would have v1 ~= m11 when m12 is small. This would cause catastrophic
-    // cancellation on `v1 * v1 - m11 * m11` below.
-    let mut v1 = m11 * m22 * two / denom;
-    let mut v2 = half * denom;
-
-    let mut u = None;
-    let mut v_t = None;
-
-    if compute_u || compute_v {
-        let (csv, sgn_v) = GivensRotation::new(m11 * m12, v1 * v1 - m11 * m11);
-        v1 *= sgn_v;
-        v2 *= sgn_v;
+    let two: N = crate::convert(2.0f64);
+    let four: N = crate::convert(4.0f64);
+    let half: N = crate::convert(0.5f64);
+    let sign = |a: N, b: N| if b >= N::zero() { a.abs() } else { -a.abs() };
+
+    let mut ft = m11;
+    let mut fa = m11.abs();
+    let mut ht = m22;
+    let mut ha = m22.abs();
+
+    // `pmax` points to the element of the matrix with the greatest magnitude.
+    let mut pmax = 1;
+    let swap = ha > fa;
+
+    if swap {
+        pmax = 3;
+        std::mem::swap(&mut ft, &mut ht);
+        std::mem::swap(&mut fa, &mut ha);
+    }
 
-        if compute_v {
-            v_t = Some(csv);
+    let gt = m12;
+    let ga = gt.abs();
+
+    let mut clt = N::one();
+    let mut crt = N::one();
+    let mut slt = N::zero();
+    let mut srt = N::zero();
+    let mut ssmin = ha;
+    let mut ssmax = fa;
+
+    if !ga.is_zero() {
+        let mut ga_small = true;
+
+        if ga > fa {
+            pmax = 2;
+
+            if fa / ga < N::default_epsilon() {
+                // The off-diagonal element is very large.
+                ga_small = false;
+                ssmax = ga;
+                ssmin = if ha > N::one() { fa / (ga / ha) } else { (fa / ga) * ha };
+                clt = N::one();
+                slt = ht / gt;
+                srt = N::one();
+                crt = ft / gt;
+            }
         }
 
-        if compute_u {
-            let cu = (m11.scale(csv.c()) + m12 * csv.s()) / v1;
-            let su = (m22 * csv.s()) / v1;
-            let (csu, sgn_u) = GivensRotation::new(cu, su);
+        if ga_small {
+            let d = fa - ha;
+            let l = if d == fa { N::one() } else { d / fa };
+            let m = gt / ft;
+            let mut t = two - l;
+            let mm = m * m;
+            let tt = t * t;
+            let s = (tt + mm).sqrt();
+            let r = if l.is_zero() { m.abs() } else { (l * l + mm).sqrt() };
+            let a = half * (s + r);
+
+            ssmin = ha / a;
+            ssmax = fa * a;
+
+            if mm.is_zero() {
+                if l.is_zero() {
+                    t = sign(two, ft) * sign(N::one(), gt);
+                } else {
+                    t = gt / sign(d, ft) + m / t;
+                }
+            } else {
+                t = (m / (s + t) + m / (r + l)) * (N::one() + a);
+            }
 
-            v1 *= sgn_u;
-            v2 *= sgn_u;
-            u = Some(csu);
+            let l = (t * t + four).sqrt();
+            crt = two / l;
+            srt = t / l;
+            clt = (crt + srt * m) / a;
+            slt = (ht / ft) * srt / a;
         }
     }
 
-    (u, Vector2::new(v1, v2), v_t)
+    let (csl, snl, csr, snr) = if swap {
+        (srt, crt, slt, clt)
+    } else {
+        (clt, slt, crt, srt)
+    };
+
+    // Correct the signs of the singular values.
+    let tsign = match pmax {
+        1 => sign(N::one(), csr) * sign(N::one(), csl) * sign(N::one(), m11),
+        2 => sign(N::one(), snr) * sign(N::one(), csl) * sign(N::one(), m12),
+        _ => sign(N::one(), snr) * sign(N::one(), snl) * sign(N::one(), m22),
+    };
+
+    let s1 = sign(ssmax, tsign);
+    let s2 = sign(ssmin, tsign * sign(N::one(), m11) * sign(N::one(), m22));
+
+    let u = if compute_u {
+        Some(GivensRotation::new_unchecked(csl, snl))
+    } else {
+        None
+    };
+
+    let v_t = if compute_v {
+        Some(GivensRotation::new_unchecked(csr, snr))
+    } else {
+        None
+    };
+
+    (u, Vector2::new(s1, s2), v_t)
 }
\ No newline at end of file
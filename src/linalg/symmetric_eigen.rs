@@ -11,6 +11,7 @@ use crate::dimension::{Dim, DimDiff, DimSub, U1, U2};
 use crate::storage::Storage;
 
 use crate::linalg::givens::GivensRotation;
+use crate::linalg::LinalgError;
 use crate::linalg::SymmetricTridiagonal;
 
 /// Eigendecomposition of a symmetric matrix.
@@ -89,6 +90,19 @@ where DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>
         })
     }
 
+    /// Attempts to compute the eigendecomposition of the given symmetric matrix.
+    ///
+    /// Returns `LinalgError::NoConvergence` if the algorithm did not converge after `max_niter`
+    /// iterations. See `SymmetricEigen::try_new` for a description of the arguments.
+    pub fn try_decompose(m: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Result<Self, LinalgError>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + // For tridiagonalization
+                          Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        Self::try_new(m, eps, max_niter).ok_or(LinalgError::NoConvergence { niter: max_niter })
+    }
+
     fn do_decompose(
         mut m: MatrixN<N, D>,
         eigenvectors: bool,
@@ -193,7 +207,12 @@ where DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>
                     off_diag[start], diag[start + 1],
                 );
                 let eigvals = m.eigenvalues().unwrap();
-                let basis = Vector2::new(eigvals.x - diag[start + 1], off_diag[start]);
+                // Choose the basis least likely to experience cancellation.
+                let basis = if (eigvals.x - diag[start + 1]).abs() > (eigvals.x - diag[start]).abs() {
+                    Vector2::new(eigvals.x - diag[start + 1], off_diag[start])
+                } else {
+                    Vector2::new(off_diag[start], eigvals.x - diag[start])
+                };
 
                 diag[start + 0] = eigvals[0];
                 diag[start + 1] = eigvals[1];
@@ -0,0 +1,146 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use alga::general::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix, VectorN};
+use crate::dimension::{Dim, DimDiff, DimSub, Dynamic, U1};
+use crate::storage::Storage;
+
+use crate::linalg::{Cholesky, LinalgError, SymmetricEigen};
+
+/// Generalized eigendecomposition of a symmetric (or hermitian) matrix `A` with respect to a
+/// symmetric (or hermitian) positive-definite matrix `B`.
+///
+/// This solves `A * x = λ * B * x`. All the eigenvalues are real and the eigenvectors are
+/// `B`-orthonormal, i.e., `X.adjoint() * B * X = I` where `X` is the matrix of eigenvectors.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<N::RealField, D>: Serialize,
+         MatrixN<N, D>: Serialize"
+    ))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(
+        deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<N::RealField, D>: Deserialize<'de>,
+         MatrixN<N, D>: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct SymmetricGeneralizedEigen<N: ComplexField, D: Dim>
+where DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>
+{
+    /// The `B`-orthonormal generalized eigenvectors.
+    pub eigenvectors: MatrixN<N, D>,
+
+    /// The unsorted generalized eigenvalues.
+    pub eigenvalues: VectorN<N::RealField, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for SymmetricGeneralizedEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>,
+    MatrixN<N, D>: Copy,
+    VectorN<N::RealField, D>: Copy,
+{}
+
+impl<N: ComplexField, D: DimSub<U1> + DimSub<Dynamic>> SymmetricGeneralizedEigen<N, D>
+where DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>
+{
+    /// Computes the generalized eigendecomposition of the hermitian matrix `a` with respect to
+    /// the hermitian positive-definite matrix `b`.
+    ///
+    /// Returns `None` if `b` is not positive-definite. Only the lower-triangular part (including
+    /// the diagonal) of `b` is read.
+    pub fn new(a: MatrixN<N, D>, b: MatrixN<N, D>) -> Option<Self> {
+        Self::try_new(a, b, N::RealField::default_epsilon(), 0)
+    }
+
+    /// Computes the generalized eigendecomposition of the hermitian matrix `a` with respect to
+    /// the hermitian positive-definite matrix `b`, with user-specified convergence parameters.
+    ///
+    /// Returns `None` if `b` is not positive-definite or if the eigendecomposition did not
+    /// converge.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(a: MatrixN<N, D>, b: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Option<Self> {
+        Self::try_decompose(a, b, eps, max_niter).ok()
+    }
+
+    /// Computes the generalized eigendecomposition of the hermitian matrix `a` with respect to
+    /// the hermitian positive-definite matrix `b`, with user-specified convergence parameters.
+    ///
+    /// Returns `LinalgError::NotPositiveDefinite` if `b` is not positive-definite, or
+    /// `LinalgError::NoConvergence` if the eigendecomposition did not converge after `max_niter`
+    /// iterations. See `SymmetricGeneralizedEigen::try_new` for a description of the arguments.
+    pub fn try_decompose(
+        a: MatrixN<N, D>,
+        b: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError>
+    {
+        assert!(
+            a.is_square() && a.shape() == b.shape(),
+            "Unable to compute the generalized eigendecomposition of non-square or mismatched matrices."
+        );
+
+        // With `B = L * Lᴴ`, the problem is equivalent to the standard eigenvalue problem of the
+        // hermitian matrix `C = L⁻¹ * A * L⁻ᴴ` with eigenvectors `y = Lᴴ * x`.
+        let l = Cholesky::try_decompose(b)?.unpack();
+
+        let mut c = a;
+        let _ = l.solve_lower_triangular_mut(&mut c);
+        c.adjoint_mut();
+        let _ = l.solve_lower_triangular_mut(&mut c);
+
+        let eigen = SymmetricEigen::try_decompose(c, eps, max_niter)?;
+        let mut eigenvectors = eigen.eigenvectors;
+        let _ = l.ad_solve_lower_triangular_mut(&mut eigenvectors);
+
+        Ok(SymmetricGeneralizedEigen {
+            eigenvectors,
+            eigenvalues: eigen.eigenvalues,
+        })
+    }
+}
+
+impl<N: ComplexField, D: DimSub<U1> + DimSub<Dynamic>, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>
+{
+    /// Computes the generalized eigendecomposition of this hermitian matrix with respect to the
+    /// hermitian positive-definite matrix `b`.
+    ///
+    /// Returns `None` if `b` is not positive-definite.
+    pub fn symmetric_generalized_eigen(self, b: MatrixN<N, D>) -> Option<SymmetricGeneralizedEigen<N, D>> {
+        SymmetricGeneralizedEigen::new(self.into_owned(), b)
+    }
+
+    /// Computes the generalized eigenvalues of this hermitian matrix with respect to the
+    /// hermitian positive-definite matrix `b`.
+    ///
+    /// Returns `None` if `b` is not positive-definite.
+    pub fn symmetric_generalized_eigenvalues(self, b: MatrixN<N, D>) -> Option<VectorN<N::RealField, D>> {
+        SymmetricGeneralizedEigen::new(self.into_owned(), b).map(|eig| eig.eigenvalues)
+    }
+}
@@ -0,0 +1,204 @@
+use alga::general::ClosedAdd;
+use num::Zero;
+use std::iter;
+
+use crate::allocator::Allocator;
+use crate::sparse::{CsMatrix, CsStorage, CsrMatrix};
+use crate::{DefaultAllocator, Dim, Dynamic, Scalar};
+
+/// A sparse matrix in coordinate (triplet) format, used to assemble sparse matrices.
+///
+/// Entries can be pushed incrementally and in any order. Duplicate entries are summed when this
+/// matrix is converted into a compressed `CsMatrix` or `CsrMatrix`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMatrix<N: Scalar, R: Dim = Dynamic, C: Dim = Dynamic> {
+    shape: (R, C),
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    vals: Vec<N>,
+}
+
+impl<N: Scalar> CooMatrix<N> {
+    /// Creates an empty `nrows x ncols` sparse matrix in coordinate format.
+    pub fn new(nrows: usize, ncols: usize) -> Self {
+        Self::new_generic(Dynamic::new(nrows), Dynamic::new(ncols))
+    }
+
+    /// Creates an empty `nrows x ncols` sparse matrix in coordinate format, with enough space
+    /// allocated for `capacity` entries.
+    pub fn with_capacity(nrows: usize, ncols: usize, capacity: usize) -> Self {
+        Self::with_capacity_generic(Dynamic::new(nrows), Dynamic::new(ncols), capacity)
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> CooMatrix<N, R, C> {
+    /// Creates an empty sparse matrix in coordinate format with the given dimensions.
+    pub fn new_generic(nrows: R, ncols: C) -> Self {
+        Self::with_capacity_generic(nrows, ncols, 0)
+    }
+
+    /// Creates an empty sparse matrix in coordinate format with the given dimensions, with enough
+    /// space allocated for `capacity` entries.
+    pub fn with_capacity_generic(nrows: R, ncols: C, capacity: usize) -> Self {
+        CooMatrix {
+            shape: (nrows, ncols),
+            rows: Vec::with_capacity(capacity),
+            cols: Vec::with_capacity(capacity),
+            vals: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds the entry `val` at the `i`-th row and `j`-th column of this matrix.
+    ///
+    /// If an entry already exists at this position, both will be summed on conversion.
+    #[inline]
+    pub fn push(&mut self, i: usize, j: usize, val: N) {
+        assert!(
+            i < self.nrows() && j < self.ncols(),
+            "Triplet index out of bounds."
+        );
+
+        self.rows.push(i);
+        self.cols.push(j);
+        self.vals.push(val);
+    }
+
+    /// Reserves space for at least `additional` more entries.
+    pub fn reserve(&mut self, additional: usize) {
+        self.rows.reserve(additional);
+        self.cols.reserve(additional);
+        self.vals.reserve(additional);
+    }
+
+    /// Removes all the entries of this matrix.
+    pub fn clear(&mut self) {
+        self.rows.clear();
+        self.cols.clear();
+        self.vals.clear();
+    }
+
+    /// The number of entries pushed into this matrix, including duplicates.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vals.len()
+    }
+
+    /// Whether no entry has been pushed into this matrix.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vals.is_empty()
+    }
+
+    /// The number of rows of this matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.shape.0.value()
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.shape.1.value()
+    }
+
+    /// The shape of this matrix.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    /// The row indices of the entries of this matrix.
+    #[inline]
+    pub fn row_indices(&self) -> &[usize] {
+        &self.rows
+    }
+
+    /// The column indices of the entries of this matrix.
+    #[inline]
+    pub fn col_indices(&self) -> &[usize] {
+        &self.cols
+    }
+
+    /// The values of the entries of this matrix.
+    #[inline]
+    pub fn values(&self) -> &[N] {
+        &self.vals
+    }
+
+    /// Iterates through the entries of this matrix, given as `(row_index, col_index, value)`.
+    pub fn triplet_iter<'a>(&'a self) -> impl Iterator<Item = (usize, usize, N)> + 'a {
+        self.rows
+            .iter()
+            .zip(self.cols.iter())
+            .zip(self.vals.iter())
+            .map(|((i, j), val)| (*i, *j, *val))
+    }
+
+    /// Converts this matrix into a compressed sparse column matrix, summing duplicate entries.
+    pub fn to_cs_matrix(&self) -> CsMatrix<N, R, C>
+    where
+        N: Zero + ClosedAdd,
+        DefaultAllocator: Allocator<usize, C> + Allocator<N, R>,
+    {
+        CsMatrix::from_triplet_generic(self.shape.0, self.shape.1, &self.rows, &self.cols, &self.vals)
+    }
+
+    /// Converts this matrix into a compressed sparse row matrix, summing duplicate entries.
+    pub fn to_csr_matrix(&self) -> CsrMatrix<N, R, C>
+    where
+        N: Zero + ClosedAdd,
+        DefaultAllocator: Allocator<usize, R> + Allocator<N, C>,
+    {
+        // The CSR representation of `self` is the CSC representation of its transpose.
+        let transposed = CsMatrix::from_triplet_generic(
+            self.shape.1,
+            self.shape.0,
+            &self.cols,
+            &self.rows,
+            &self.vals,
+        );
+        CsrMatrix::from_transposed_csc(transposed)
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> Extend<(usize, usize, N)> for CooMatrix<N, R, C> {
+    fn extend<I: IntoIterator<Item = (usize, usize, N)>>(&mut self, iter: I) {
+        for (i, j, val) in iter {
+            self.push(i, j, val)
+        }
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim> From<&'a CsMatrix<N, R, C>> for CooMatrix<N, R, C>
+where DefaultAllocator: Allocator<usize, C>
+{
+    fn from(m: &'a CsMatrix<N, R, C>) -> Self {
+        let (nrows, ncols) = m.data.shape;
+        let mut res = CooMatrix::with_capacity_generic(nrows, ncols, m.len());
+
+        for j in 0..m.ncols() {
+            let range = m.data.column_range(j);
+            res.cols.extend(iter::repeat(j).take(range.len()));
+            res.rows.extend_from_slice(&m.data.i[range.clone()]);
+            res.vals.extend_from_slice(&m.data.vals[range]);
+        }
+
+        res
+    }
+}
+
+impl<N: Scalar + Zero + ClosedAdd, R: Dim, C: Dim> From<CooMatrix<N, R, C>> for CsMatrix<N, R, C>
+where DefaultAllocator: Allocator<usize, C> + Allocator<N, R>
+{
+    fn from(m: CooMatrix<N, R, C>) -> Self {
+        m.to_cs_matrix()
+    }
+}
+
+impl<N: Scalar + Zero + ClosedAdd, R: Dim, C: Dim> From<CooMatrix<N, R, C>> for CsrMatrix<N, R, C>
+where DefaultAllocator: Allocator<usize, R> + Allocator<N, C>
+{
+    fn from(m: CooMatrix<N, R, C>) -> Self {
+        m.to_csr_matrix()
+    }
+}
@@ -314,9 +314,9 @@ where DefaultAllocator: Allocator<usize, C>
             _phantoms: PhantomData,
         };
 
-        // Sort and remove duplicates.
-        res.sort();
+        // Remove duplicates and sort.
         res.dedup();
+        res.sort();
 
         res
     }*/
@@ -444,6 +444,15 @@ impl<N: Scalar, R: Dim, C: Dim, S: CsStorageMut<N, R, C>> CsMatrix<N, R, C, S> {
 impl<N: Scalar, R: Dim, C: Dim> CsMatrix<N, R, C>
 where DefaultAllocator: Allocator<usize, C>
 {
+    /// The value buffer of this sparse matrix.
+    ///
+    /// This can be given to the numerical decompositions of `CsCholesky` to refactorize a matrix
+    /// with the same non-zero pattern.
+    #[inline]
+    pub fn values(&self) -> &[N] {
+        self.data.values()
+    }
+
     pub(crate) fn sort(&mut self)
     where DefaultAllocator: Allocator<N, R> {
         // Size = R
@@ -475,37 +484,39 @@ where DefaultAllocator: Allocator<usize, C>
         }
     }
 
-    // Remove dupliate entries on a sorted CsMatrix.
+    // Remove duplicate entries, summing their values. The columns do not have to be sorted: the
+    // first occurrence of each row index is kept in place.
     pub(crate) fn dedup(&mut self)
-    where N: Zero + ClosedAdd {
+    where
+        N: Zero + ClosedAdd,
+        DefaultAllocator: Allocator<N, R>,
+    {
+        let nrows = self.data.shape().0;
+        let mut workspace = VectorN::<N, R>::zeros_generic(nrows, U1);
+        let mut timestamps: Vec<usize> = iter::repeat(0).take(nrows.value()).collect();
         let mut curr_i = 0;
 
         for j in 0..self.ncols() {
             let range = self.data.column_range(j);
+            let start = curr_i;
             self.data.p[j] = curr_i;
 
-            if range.start != range.end {
-                let mut value = N::zero();
-                let mut irow = self.data.i[range.start];
-
-                for idx in range {
-                    let curr_irow = self.data.i[idx];
-
-                    if curr_irow == irow {
-                        value += self.data.vals[idx];
-                    } else {
-                        self.data.i[curr_i] = irow;
-                        self.data.vals[curr_i] = value;
-                        value = self.data.vals[idx];
-                        irow = curr_irow;
-                        curr_i += 1;
-                    }
+            for idx in range {
+                let irow = self.data.i[idx];
+                let val = self.data.vals[idx];
+
+                if timestamps[irow] != j + 1 {
+                    timestamps[irow] = j + 1;
+                    workspace[irow] = val;
+                    self.data.i[curr_i] = irow;
+                    curr_i += 1;
+                } else {
+                    workspace[irow] += val;
                 }
+            }
 
-                // Handle the last entry.
-                self.data.i[curr_i] = irow;
-                self.data.vals[curr_i] = value;
-                curr_i += 1;
+            for idx in start..curr_i {
+                self.data.vals[idx] = workspace[self.data.i[idx]];
             }
         }
 
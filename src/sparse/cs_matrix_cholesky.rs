@@ -2,8 +2,11 @@ use std::iter;
 use std::mem;
 
 use crate::allocator::Allocator;
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::sparse::{CsMatrix, CsStorage, CsStorageIter, CsStorageIterMut, CsVecStorage};
-use crate::{DefaultAllocator, Dim, RealField, VectorN, U1};
+use crate::storage::{Storage, StorageMut};
+use crate::linalg::Cholesky;
+use crate::{DMatrix, DefaultAllocator, Dim, Matrix, MatrixMN, RealField, VectorN, U1};
 
 /// The cholesky decomposition of a column compressed sparse matrix.
 pub struct CsCholesky<N: RealField, D: Dim>
@@ -19,6 +22,14 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
     // Used only for the pattern.
     // FIXME: store only the nonzero pattern instead.
     u: CsMatrix<N, D, D>,
+    // Fill-reducing permutation: the k-th pivot is the `perm[k]`-th row/column of the original
+    // matrix.
+    perm: Option<Vec<usize>>,
+    // For each entry of the permuted matrix, the index of its value on the value buffer of the
+    // original matrix.
+    value_map: Option<Vec<usize>>,
+    // The k-th supernode is made of the columns `supernodes[k]..supernodes[k + 1]` of `l`.
+    supernodes: Vec<usize>,
     ok: bool,
     // Workspaces.
     work_x: VectorN<N, D>,
@@ -44,6 +55,7 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
         );
 
         let (l, u) = Self::nonzero_pattern(m);
+        let supernodes = Self::supernodes(&l);
 
         // Workspaces.
         let work_x = unsafe { VectorN::new_uninitialized_generic(m.data.shape().0, U1) };
@@ -56,13 +68,89 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
             original_i: m.data.i.clone(),
             l,
             u,
+            perm: None,
+            value_map: None,
+            supernodes,
             ok: false,
             work_x,
             work_c,
         }
     }
 
+    /// Computes the cholesky decomposition of the sparse matrix `m` symmetrically permuted by the
+    /// fill-reducing ordering `perm`.
+    ///
+    /// The `k`-th row and column of the factorized matrix are the `perm[k]`-th row and column of
+    /// `m`, i.e., this decomposes `P * m * P^T` where `P` is the permutation matrix with ones at
+    /// the positions `(k, perm[k])`. See `CsMatrix::approximate_minimum_degree_ordering` and
+    /// `CsMatrix::nested_dissection_ordering` for ways of computing `perm`.
+    pub fn new_with_permutation(m: &CsMatrix<N, D, D>, perm: &[usize]) -> Self {
+        let mut me = Self::new_symbolic_with_permutation(m, perm);
+        let _ = me.decompose_left_looking(&m.data.vals);
+        me
+    }
+
+    /// Perform symbolic analysis for the given matrix symmetrically permuted by the fill-reducing
+    /// ordering `perm`.
+    ///
+    /// This does not access the numerical values of `m`. The values given to the numerical
+    /// decompositions are those of a matrix with the same structure as `m`, before permutation.
+    pub fn new_symbolic_with_permutation(m: &CsMatrix<N, D, D>, perm: &[usize]) -> Self {
+        assert!(
+            m.is_square(),
+            "The matrix `m` must be square to compute its elimination tree."
+        );
+        assert_eq!(perm.len(), m.nrows(), "Invalid permutation size.");
+
+        let n = m.nrows();
+        let mut inv_perm: Vec<_> = iter::repeat(usize::max_value()).take(n).collect();
+
+        for (k, i) in perm.iter().enumerate() {
+            assert!(
+                *i < n && inv_perm[*i] == usize::max_value(),
+                "The ordering `perm` is not a permutation."
+            );
+            inv_perm[*i] = k;
+        }
+
+        // Compute `P * m * P^T`, keeping track of where each value comes from.
+        let (nrows, ncols) = m.data.shape();
+        let mut permuted = CsMatrix::new_uninitialized_generic(nrows, ncols, m.len());
+        let mut value_map = Vec::with_capacity(m.len());
+        let mut column = Vec::new();
+
+        for (j, orig_j) in perm.iter().enumerate() {
+            permuted.data.p[j] = value_map.len();
+
+            column.clear();
+            column.extend(
+                m.data
+                    .column_range(*orig_j)
+                    .map(|p| (inv_perm[m.data.i[p]], p)),
+            );
+            column.sort_unstable();
+
+            for (i, p) in column.iter().cloned() {
+                permuted.data.i[value_map.len()] = i;
+                permuted.data.vals[value_map.len()] = m.data.vals[p];
+                value_map.push(p);
+            }
+        }
+
+        let mut me = Self::new_symbolic(&permuted);
+        me.perm = Some(perm.to_vec());
+        me.value_map = Some(value_map);
+        me
+    }
+
+    /// The fill-reducing permutation applied to the matrix before its decomposition, if any.
+    pub fn permutation(&self) -> Option<&[usize]> {
+        self.perm.as_ref().map(|perm| &perm[..])
+    }
+
     /// The lower-triangular matrix of the cholesky decomposition.
+    ///
+    /// If a fill-reducing permutation `P` was given, this is the factor of `P * m * P^T`.
     pub fn l(&self) -> Option<&CsMatrix<N, D, D>> {
         if self.ok {
             Some(&self.l)
@@ -80,6 +168,75 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
         }
     }
 
+    /// Solves the system `m * x = b` where `m` is the decomposed matrix.
+    ///
+    /// The fill-reducing permutation, if any, is applied transparently. Returns `None` if the
+    /// numerical decomposition failed or has not been performed.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<D, R2>,
+    {
+        let mut b = b.clone_owned();
+        if self.solve_mut(&mut b) {
+            Some(b)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the system `m * x = b` where `m` is the decomposed matrix.
+    ///
+    /// The fill-reducing permutation, if any, is applied transparently. Returns `false` and leaves
+    /// `b` unchanged if the numerical decomposition failed or has not been performed.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<D, R2>,
+    {
+        if !self.ok {
+            return false;
+        }
+
+        assert_eq!(self.l.nrows(), b.nrows(), "Mismatched matrix dimensions.");
+
+        let perm = match self.perm {
+            Some(ref perm) => perm,
+            None => {
+                return self.l.solve_lower_triangular_mut(b)
+                    && self.l.tr_solve_lower_triangular_mut(b);
+            }
+        };
+
+        let mut column = Vec::with_capacity(perm.len());
+
+        for j in 0..b.ncols() {
+            column.clear();
+            column.extend(perm.iter().map(|i| b[(*i, j)]));
+
+            for (k, val) in column.iter().enumerate() {
+                b[(k, j)] = *val;
+            }
+        }
+
+        let ok = self.l.solve_lower_triangular_mut(b) && self.l.tr_solve_lower_triangular_mut(b);
+
+        for j in 0..b.ncols() {
+            column.clear();
+            column.extend((0..perm.len()).map(|k| b[(k, j)]));
+
+            for (val, i) in column.iter().zip(perm.iter()) {
+                b[(*i, j)] = *val;
+            }
+        }
+
+        ok
+    }
+
     /// Perform a numerical left-looking cholesky decomposition of a matrix with the same structure as the
     /// one used to initialize `self`, but with different non-zero values provided by `values`.
     pub fn decompose_left_looking(&mut self, values: &[N]) -> bool {
@@ -104,7 +261,8 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
                     let irow = *self.original_i.get_unchecked(p);
 
                     if irow >= k {
-                        *self.work_x.vget_unchecked_mut(irow) = *values.get_unchecked(p);
+                        let val = *values.get_unchecked(self.value_index(p));
+                        *self.work_x.vget_unchecked_mut(irow) = val;
                     }
                 }
 
@@ -173,7 +331,8 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
                     let irow = *self.original_i.get_unchecked(p);
 
                     if irow <= k {
-                        *self.work_x.vget_unchecked_mut(irow) = *values.get_unchecked(p);
+                        let val = *values.get_unchecked(self.value_index(p));
+                        *self.work_x.vget_unchecked_mut(irow) = val;
                     }
                 }
 
@@ -227,6 +386,196 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
         true
     }
 
+    /// The number of supernodes of the symbolic decomposition.
+    ///
+    /// A supernode is a set of contiguous columns of the factor `L` that share the same non-zero
+    /// pattern below their diagonal block.
+    pub fn supernode_count(&self) -> usize {
+        self.supernodes.len() - 1
+    }
+
+    /// Perform a numerical supernodal cholesky decomposition of a matrix with the same structure as
+    /// the one used to initialize `self`, but with different non-zero values provided by `values`.
+    ///
+    /// This is a left-looking decomposition where the columns of each supernode are computed
+    /// together as a dense block, using dense matrix multiplications for the updates from previous
+    /// supernodes and a dense cholesky decomposition for the diagonal block. This is usually much
+    /// faster than the scalar decompositions on matrices with large supernodes, e.g., resulting
+    /// from the discretization of 3D problems.
+    pub fn decompose_supernodal(&mut self, values: &[N]) -> bool {
+        assert!(
+            values.len() >= self.original_i.len(),
+            "The set of values is too small."
+        );
+
+        let n = self.l.nrows();
+        let nsuper = self.supernode_count();
+        let none = usize::max_value();
+
+        let mut col_to_super: Vec<usize> = iter::repeat(0).take(n).collect();
+        for s in 0..nsuper {
+            for j in self.supernodes[s]..self.supernodes[s + 1] {
+                col_to_super[j] = s;
+            }
+        }
+
+        // `relpos[i]` is the position of the row `i` on the dense block of the current supernode.
+        let mut relpos: Vec<usize> = iter::repeat(0).take(n).collect();
+        // Linked lists of the supernodes that have yet to update the k-th supernode. `next_row[d]`
+        // is the position, on the rows of the supernode `d`, of the first row not used by the
+        // updates performed so far.
+        let mut head: Vec<usize> = iter::repeat(none).take(nsuper).collect();
+        let mut next: Vec<usize> = iter::repeat(none).take(nsuper).collect();
+        let mut next_row: Vec<usize> = iter::repeat(0).take(nsuper).collect();
+        // The dense blocks of the supernodes that have yet to update some other supernode.
+        let mut blocks: Vec<Option<DMatrix<N>>> = Vec::with_capacity(nsuper);
+
+        for s in 0..nsuper {
+            let first = self.supernodes[s];
+            let end = self.supernodes[s + 1];
+            let width = end - first;
+            let rows = &self.l.data.i[self.l.data.column_range(first)];
+            let nrows = rows.len();
+
+            for (pos, i) in rows.iter().enumerate() {
+                relpos[*i] = pos;
+            }
+
+            // Scatter the columns of the original matrix with the values provided.
+            let mut block = DMatrix::zeros(nrows, width);
+
+            for j in first..end {
+                for p in self.original_p[j]..self.original_p[j + 1] {
+                    let irow = self.original_i[p];
+
+                    if irow >= j {
+                        block[(relpos[irow], j - first)] = values[self.value_index(p)];
+                    }
+                }
+            }
+
+            // Apply the updates from the descendant supernodes.
+            let mut d = head[s];
+
+            while d != none {
+                let next_d = next[d];
+                let rows_d = &self.l.data.i[self.l.data.column_range(self.supernodes[d])];
+                let start = next_row[d];
+                let mut stop = start;
+
+                while stop < rows_d.len() && rows_d[stop] < end {
+                    stop += 1;
+                }
+
+                let update = match blocks[d] {
+                    Some(ref block_d) => {
+                        block_d.rows_range(start..) * block_d.rows_range(start..stop).transpose()
+                    }
+                    None => unreachable!(),
+                };
+
+                for c in 0..stop - start {
+                    let j = rows_d[start + c] - first;
+                    let mut block_col = block.column_mut(j);
+                    let update_col = update.column(c);
+
+                    for r in c..rows_d.len() - start {
+                        unsafe {
+                            let i = *relpos.get_unchecked(*rows_d.get_unchecked(start + r));
+                            *block_col.vget_unchecked_mut(i) -= *update_col.vget_unchecked(r);
+                        }
+                    }
+                }
+
+                if stop < rows_d.len() {
+                    let target = col_to_super[rows_d[stop]];
+                    next_row[d] = stop;
+                    next[d] = head[target];
+                    head[target] = d;
+                } else {
+                    // This was the last update from `d`.
+                    blocks[d] = None;
+                }
+
+                d = next_d;
+            }
+
+            // Decompose the dense block.
+            if !dense_block_cholesky(&mut block) {
+                self.ok = false;
+                return false;
+            }
+
+            // Store the result on `l`.
+            for j in first..end {
+                let c = j - first;
+                let range = self.l.data.column_range(j);
+
+                for (k, p) in range.enumerate() {
+                    self.l.data.vals[p] = block[(c + k, c)];
+                }
+            }
+
+            if width < nrows {
+                let target = col_to_super[rows[width]];
+                next_row[s] = width;
+                next[s] = head[target];
+                head[target] = s;
+                blocks.push(Some(block));
+            } else {
+                blocks.push(None);
+            }
+        }
+
+        self.ok = true;
+        true
+    }
+
+    // The index, on the values buffer given to the numerical decompositions, of the `p`-th
+    // value of the (permuted) matrix.
+    #[inline]
+    fn value_index(&self, p: usize) -> usize {
+        match self.value_map {
+            Some(ref map) => unsafe { *map.get_unchecked(p) },
+            None => p,
+        }
+    }
+
+    // Detects the fundamental supernodes from the non-zero pattern of `l`: the column `j + 1`
+    // belongs to the same supernode as `j` if it is the parent of `j` on the elimination tree,
+    // has no other child, and its pattern is the pattern of `j` without `j`.
+    fn supernodes(l: &CsMatrix<N, D, D>) -> Vec<usize> {
+        let n = l.nrows();
+        let mut nchildren: Vec<usize> = iter::repeat(0).take(n).collect();
+
+        for j in 0..n {
+            let range = l.data.column_range(j);
+
+            // The parent of `j` is the first row below the diagonal.
+            if range.len() > 1 {
+                nchildren[l.data.i[range.start + 1]] += 1;
+            }
+        }
+
+        let mut supernodes = Vec::new();
+
+        for j in 0..n {
+            let is_continuation = j > 0 && {
+                let prev = l.data.column_range(j - 1);
+                prev.len() == l.data.column_range(j).len() + 1
+                    && l.data.i[prev.start + 1] == j
+                    && nchildren[j] == 1
+            };
+
+            if !is_continuation {
+                supernodes.push(j);
+            }
+        }
+
+        supernodes.push(n);
+        supernodes
+    }
+
     fn elimination_tree<S: CsStorage<N, D, D>>(m: &CsMatrix<N, D, D, S>) -> Vec<usize> {
         let nrows = m.nrows();
         let mut forest: Vec<_> = iter::repeat(usize::max_value()).take(nrows).collect();
@@ -406,3 +755,22 @@ where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
     }
     */
 }
+
+// Computes in-place the cholesky factor of the dense `nrows x width` matrix `[A11; A21]`, i.e.,
+// `[L11; L21]` such that `A11 = L11 * L11^T` and `A21 = L21 * L11^T`. Only the lower-triangular
+// part of `A11` is read, and its strictly upper-triangular part is set to zero.
+fn dense_block_cholesky<N: RealField>(block: &mut DMatrix<N>) -> bool {
+    let width = block.ncols();
+    let l11 = match Cholesky::new(block.rows(0, width).into_owned()) {
+        Some(chol) => chol.unpack(),
+        None => return false,
+    };
+
+    // `L21^T = L11^-1 * A21^T`, where `L11` has a positive diagonal.
+    let mut l21_tr = block.rows_range(width..).transpose();
+    let _ = l11.solve_lower_triangular_mut(&mut l21_tr);
+
+    block.rows_mut(0, width).copy_from(&l11);
+    block.rows_range_mut(width..).tr_copy_from(&l21_tr);
+    true
+}
@@ -59,9 +59,9 @@ where DefaultAllocator: Allocator<usize, C> + Allocator<N, R>
             workspace[j] = offset + 1;
         }
 
-        // Sort the result.
-        res.sort();
+        // Sum the duplicate entries and sort the result.
         res.dedup();
+        res.sort();
         res
     }
 }
@@ -0,0 +1,292 @@
+use num::Zero;
+use std::iter;
+use std::ops::Range;
+
+use crate::allocator::Allocator;
+use crate::sparse::{CsMatrix, CsStorage, CsStorageIter, CsVecStorage};
+use crate::{DefaultAllocator, Dim, Dynamic, Scalar, VectorN, U1};
+
+impl<N: Scalar, R: Dim, C: Dim, S: CsStorage<N, R, C>> CsMatrix<N, R, C, S> {
+    /// Creates a new matrix by extracting the columns of `self` with indices in `range`.
+    pub fn columns_range(&self, range: Range<usize>) -> CsMatrix<N, R, Dynamic> {
+        assert!(
+            range.start <= range.end && range.end <= self.ncols(),
+            "Column range out of bounds."
+        );
+
+        let nrows = self.data.shape().0;
+        let mut p = Vec::with_capacity(range.len());
+        let mut i = Vec::new();
+        let mut vals = Vec::new();
+
+        for j in range.clone() {
+            p.push(i.len());
+
+            for (irow, val) in self.data.column_entries(j) {
+                i.push(irow);
+                vals.push(val);
+            }
+        }
+
+        let ncols = Dynamic::new(range.len());
+        CsMatrix::from_data(CsVecStorage {
+            shape: (nrows, ncols),
+            p: VectorN::from_iterator_generic(ncols, U1, p.into_iter()),
+            i,
+            vals,
+        })
+    }
+
+    /// Creates a new matrix by extracting the given set of columns from `self`.
+    pub fn select_columns<'a, I>(&self, icols: I) -> CsMatrix<N, R, Dynamic>
+    where
+        I: IntoIterator<Item = &'a usize>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let icols = icols.into_iter();
+        let nrows = self.data.shape().0;
+        let ncols = Dynamic::new(icols.len());
+        let mut p = Vec::with_capacity(icols.len());
+        let mut i = Vec::new();
+        let mut vals = Vec::new();
+
+        for j in icols {
+            assert!(*j < self.ncols(), "Column index out of bounds.");
+            p.push(i.len());
+
+            for (irow, val) in self.data.column_entries(*j) {
+                i.push(irow);
+                vals.push(val);
+            }
+        }
+
+        CsMatrix::from_data(CsVecStorage {
+            shape: (nrows, ncols),
+            p: VectorN::from_iterator_generic(ncols, U1, p.into_iter()),
+            i,
+            vals,
+        })
+    }
+
+    /// Creates a new matrix by extracting the given set of rows from `self`.
+    ///
+    /// The same row may be extracted several times.
+    pub fn select_rows<'a, I>(&self, irows: I) -> CsMatrix<N, Dynamic, C>
+    where
+        I: IntoIterator<Item = &'a usize>,
+        I::IntoIter: ExactSizeIterator + Clone,
+        DefaultAllocator: Allocator<usize, C>,
+    {
+        let irows = irows.into_iter();
+        let nrows = self.nrows();
+
+        // For each row `r` of `self`, `destinations[offsets[r]..offsets[r + 1]]` are the
+        // indices of the rows of the result that are copies of `r`.
+        let mut offsets: Vec<usize> = iter::repeat(0).take(nrows + 1).collect();
+
+        for i in irows.clone() {
+            assert!(*i < nrows, "Row index out of bounds.");
+            offsets[*i + 1] += 1;
+        }
+
+        for r in 0..nrows {
+            offsets[r + 1] += offsets[r];
+        }
+
+        let mut next = offsets.clone();
+        let mut destinations: Vec<usize> = iter::repeat(0).take(irows.len()).collect();
+
+        for (destination, source) in irows.clone().enumerate() {
+            destinations[next[*source]] = destination;
+            next[*source] += 1;
+        }
+
+        let ncols = self.data.shape().1;
+        let mut p = Vec::with_capacity(ncols.value());
+        let mut i = Vec::new();
+        let mut vals = Vec::new();
+
+        for j in 0..ncols.value() {
+            let start = i.len();
+            p.push(start);
+
+            for (irow, val) in self.data.column_entries(j) {
+                for destination in &destinations[offsets[irow]..offsets[irow + 1]] {
+                    i.push(*destination);
+                    vals.push(val);
+                }
+            }
+
+            // Keep the output sorted.
+            let mut entries: Vec<_> = i[start..]
+                .iter()
+                .cloned()
+                .zip(vals[start..].iter().cloned())
+                .collect();
+            entries.sort_by_key(|e| e.0);
+
+            for (k, (irow, val)) in entries.into_iter().enumerate() {
+                i[start + k] = irow;
+                vals[start + k] = val;
+            }
+        }
+
+        CsMatrix::from_data(CsVecStorage {
+            shape: (Dynamic::new(irows.len()), ncols),
+            p: VectorN::from_iterator_generic(ncols, U1, p.into_iter()),
+            i,
+            vals,
+        })
+    }
+
+    /// Creates a new matrix by extracting the entries of `self` at the intersection of the given
+    /// sets of rows and columns.
+    pub fn select<'a, 'b, I, J>(&self, irows: I, icols: J) -> CsMatrix<N>
+    where
+        I: IntoIterator<Item = &'a usize>,
+        I::IntoIter: ExactSizeIterator + Clone,
+        J: IntoIterator<Item = &'b usize>,
+        J::IntoIter: ExactSizeIterator,
+    {
+        self.select_columns(icols).select_rows(irows)
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> CsMatrix<N, R, C>
+where DefaultAllocator: Allocator<usize, C>
+{
+    /// Removes all the entries of this matrix for which `f(i, j, value)` returns `false`.
+    pub fn prune(&mut self, mut f: impl FnMut(usize, usize, &N) -> bool) {
+        let mut nz = 0;
+
+        for j in 0..self.ncols() {
+            let range = self.data.column_range(j);
+            self.data.p[j] = nz;
+
+            for idx in range {
+                let irow = self.data.i[idx];
+
+                if f(irow, j, &self.data.vals[idx]) {
+                    self.data.i[nz] = irow;
+                    self.data.vals[nz] = self.data.vals[idx];
+                    nz += 1;
+                }
+            }
+        }
+
+        self.data.i.truncate(nz);
+        self.data.i.shrink_to_fit();
+        self.data.vals.truncate(nz);
+        self.data.vals.shrink_to_fit();
+    }
+
+    /// Removes all the entries of this matrix explicitly set to zero.
+    pub fn drop_zeros(&mut self)
+    where N: Zero {
+        self.prune(|_, _, val| !val.is_zero())
+    }
+}
+
+impl<N: Scalar> CsMatrix<N> {
+    /// Creates a `nrows x ncols` sparse matrix without any entry.
+    pub fn zeros(nrows: usize, ncols: usize) -> Self {
+        CsMatrix::new_uninitialized_generic(Dynamic::new(nrows), Dynamic::new(ncols), 0)
+    }
+
+    /// Creates a sparse matrix by stacking the given matrices vertically.
+    ///
+    /// All the blocks must have the same number of columns.
+    pub fn vstack(blocks: &[&CsMatrix<N>]) -> Self {
+        assert!(!blocks.is_empty(), "At least one block must be given.");
+        let ncols = blocks[0].ncols();
+        let nrows = blocks.iter().map(|b| b.nrows()).sum();
+        let nvals = blocks.iter().map(|b| b.len()).sum();
+
+        for block in blocks {
+            assert_eq!(block.ncols(), ncols, "Mismatched number of columns for vertical stacking.");
+        }
+
+        let mut p = Vec::with_capacity(ncols);
+        let mut i = Vec::with_capacity(nvals);
+        let mut vals = Vec::with_capacity(nvals);
+
+        for j in 0..ncols {
+            p.push(i.len());
+            let mut shift = 0;
+
+            for block in blocks {
+                for (irow, val) in block.data.column_entries(j) {
+                    i.push(irow + shift);
+                    vals.push(val);
+                }
+
+                shift += block.nrows();
+            }
+        }
+
+        CsMatrix::from_data(CsVecStorage {
+            shape: (Dynamic::new(nrows), Dynamic::new(ncols)),
+            p: VectorN::from_iterator_generic(Dynamic::new(ncols), U1, p.into_iter()),
+            i,
+            vals,
+        })
+    }
+
+    /// Creates a sparse matrix by stacking the given matrices horizontally.
+    ///
+    /// All the blocks must have the same number of rows.
+    pub fn hstack(blocks: &[&CsMatrix<N>]) -> Self {
+        assert!(!blocks.is_empty(), "At least one block must be given.");
+        let nrows = blocks[0].nrows();
+
+        for block in blocks {
+            assert_eq!(block.nrows(), nrows, "Mismatched number of rows for horizontal stacking.");
+        }
+
+        Self::block_diagonal_with_shift(blocks, false)
+    }
+
+    /// Creates a block-diagonal sparse matrix from the given diagonal blocks.
+    pub fn block_diagonal(blocks: &[&CsMatrix<N>]) -> Self {
+        Self::block_diagonal_with_shift(blocks, true)
+    }
+
+    // Concatenates the columns of all the blocks, shifting the row indices of each block by the
+    // number of rows of the previous ones if `shift_rows` is `true`.
+    fn block_diagonal_with_shift(blocks: &[&CsMatrix<N>], shift_rows: bool) -> Self {
+        let ncols = blocks.iter().map(|b| b.ncols()).sum();
+        let nvals = blocks.iter().map(|b| b.len()).sum();
+        let nrows = if shift_rows {
+            blocks.iter().map(|b| b.nrows()).sum()
+        } else {
+            blocks.first().map(|b| b.nrows()).unwrap_or(0)
+        };
+
+        let mut p = Vec::with_capacity(ncols);
+        let mut i = Vec::with_capacity(nvals);
+        let mut vals = Vec::with_capacity(nvals);
+        let mut shift = 0;
+
+        for block in blocks {
+            for j in 0..block.ncols() {
+                p.push(i.len());
+
+                for (irow, val) in block.data.column_entries(j) {
+                    i.push(irow + shift);
+                    vals.push(val);
+                }
+            }
+
+            if shift_rows {
+                shift += block.nrows();
+            }
+        }
+
+        CsMatrix::from_data(CsVecStorage {
+            shape: (Dynamic::new(nrows), Dynamic::new(ncols)),
+            p: VectorN::from_iterator_generic(Dynamic::new(ncols), U1, p.into_iter()),
+            i,
+            vals,
+        })
+    }
+}
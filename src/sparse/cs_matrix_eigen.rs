@@ -0,0 +1,195 @@
+use num_complex::Complex as NumComplex;
+
+use crate::allocator::Allocator;
+use crate::linalg::{
+    arnoldi, lanczos, EigenTarget, LinearOperator, PartialEigen, PartialSymmetricEigen,
+};
+use crate::sparse::{CsLU, CsMatrix, CsStorageIter};
+use crate::{DefaultAllocator, Dim, Dynamic, RealField, VectorN, U1};
+
+// The operator `(A - σ * I)⁻¹`.
+struct ShiftInvert<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    lu: CsLU<N, D>,
+}
+
+impl<N: RealField, D: Dim> LinearOperator<N, D> for ShiftInvert<N, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    #[inline]
+    fn apply(&self, x: &VectorN<N, D>, out: &mut VectorN<N, D>) {
+        out.copy_from(x);
+        self.lu.solve_mut(out);
+    }
+}
+
+impl<N: RealField, D: Dim> CsMatrix<N, D, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    /// Computes `nev` eigenpairs of this symmetric matrix with the implicitly restarted Lanczos
+    /// method.
+    ///
+    /// Returns `None` if `nev` is not smaller than the dimension of this matrix, or if the
+    /// eigenpairs did not converge after `max_niter` restarts. See `linalg::lanczos` for more
+    /// details.
+    pub fn symmetric_eigen_partial(
+        &self,
+        nev: usize,
+        target: EigenTarget,
+        tolerance: N,
+        max_niter: usize,
+    ) -> Option<PartialSymmetricEigen<N, D>>
+    where
+        DefaultAllocator: Allocator<N, D, Dynamic>,
+    {
+        assert!(self.is_square(), "The matrix must be square.");
+        let subspace_dim = self.eigen_subspace_dim(nev)?;
+        let v0 = self.eigen_start_vector();
+        lanczos(self, &v0, nev, target, subspace_dim, tolerance, max_niter)
+    }
+
+    /// Computes the `nev` eigenpairs of this symmetric matrix with the eigenvalues closest to
+    /// `sigma`, using the shift-invert Lanczos method.
+    ///
+    /// This computes the eigenvalues with the largest magnitude of `(self - sigma * I)⁻¹`, using
+    /// a sparse LU decomposition. This is the most efficient way of computing the smallest
+    /// eigenvalues of a large matrix. The eigenvalues are sorted by increasing distance to
+    /// `sigma`. Returns `None` if `nev` is not smaller than the dimension of this matrix, if
+    /// `sigma` is an eigenvalue of `self`, or if the eigenpairs did not converge after
+    /// `max_niter` restarts.
+    pub fn symmetric_eigen_shift_invert(
+        &self,
+        sigma: N,
+        nev: usize,
+        tolerance: N,
+        max_niter: usize,
+    ) -> Option<PartialSymmetricEigen<N, D>>
+    where
+        DefaultAllocator: Allocator<N, D, Dynamic>,
+    {
+        let subspace_dim = self.eigen_subspace_dim(nev)?;
+        let op = self.shift_invert(sigma)?;
+        let v0 = self.eigen_start_vector();
+        let mut res = lanczos(
+            &op,
+            &v0,
+            nev,
+            EigenTarget::LargestMagnitude,
+            subspace_dim,
+            tolerance,
+            max_niter,
+        )?;
+
+        res.eigenvalues.apply(|theta| sigma + N::one() / theta);
+        Some(res)
+    }
+
+    /// Computes `nev` eigenpairs of this matrix with the implicitly restarted Arnoldi method.
+    ///
+    /// Returns `None` if `nev` is not smaller than the dimension of this matrix, or if the
+    /// eigenpairs did not converge after `max_niter` restarts. See `linalg::arnoldi` for more
+    /// details.
+    pub fn eigen_partial(
+        &self,
+        nev: usize,
+        target: EigenTarget,
+        tolerance: N,
+        max_niter: usize,
+    ) -> Option<PartialEigen<N, D>>
+    where
+        DefaultAllocator: Allocator<NumComplex<N>, D, Dynamic>,
+    {
+        assert!(self.is_square(), "The matrix must be square.");
+        let subspace_dim = self.eigen_subspace_dim(nev)?;
+        let v0 = self.eigen_start_vector();
+        arnoldi(self, &v0, nev, target, subspace_dim, tolerance, max_niter)
+    }
+
+    /// Computes the `nev` eigenpairs of this matrix with the eigenvalues closest to the real
+    /// `sigma`, using the shift-invert Arnoldi method.
+    ///
+    /// The eigenvalues are sorted by increasing distance to `sigma`. See
+    /// `CsMatrix::symmetric_eigen_shift_invert` for more details.
+    pub fn eigen_shift_invert(
+        &self,
+        sigma: N,
+        nev: usize,
+        tolerance: N,
+        max_niter: usize,
+    ) -> Option<PartialEigen<N, D>>
+    where
+        DefaultAllocator: Allocator<NumComplex<N>, D, Dynamic>,
+    {
+        let subspace_dim = self.eigen_subspace_dim(nev)?;
+        let op = self.shift_invert(sigma)?;
+        let v0 = self.eigen_start_vector();
+        let mut res = arnoldi(
+            &op,
+            &v0,
+            nev,
+            EigenTarget::LargestMagnitude,
+            subspace_dim,
+            tolerance,
+            max_niter,
+        )?;
+
+        res.eigenvalues.apply(|theta| {
+            // σ + 1 / θ
+            let norm_squared = theta.re * theta.re + theta.im * theta.im;
+            NumComplex::new(sigma + theta.re / norm_squared, -theta.im / norm_squared)
+        });
+        Some(res)
+    }
+
+    fn shift_invert(&self, sigma: N) -> Option<ShiftInvert<N, D>> {
+        assert!(self.is_square(), "The matrix must be square.");
+        let (nrows, ncols) = self.data.shape;
+        let mut irows = Vec::with_capacity(self.len() + ncols.value());
+        let mut icols = Vec::with_capacity(self.len() + ncols.value());
+        let mut vals = Vec::with_capacity(self.len() + ncols.value());
+
+        for j in 0..ncols.value() {
+            for (i, val) in self.data.column_entries(j) {
+                irows.push(i);
+                icols.push(j);
+                vals.push(val);
+            }
+
+            irows.push(j);
+            icols.push(j);
+            vals.push(-sigma);
+        }
+
+        let shifted = CsMatrix::from_triplet_generic(nrows, ncols, &irows, &icols, &vals);
+        let perm = shifted.column_approximate_minimum_degree_ordering();
+        let lu = CsLU::new_with_permutation(&shifted, &perm, crate::convert(0.1))?;
+        Some(ShiftInvert { lu })
+    }
+
+    // The dimension of the Krylov subspace used to compute `nev` eigenpairs, or `None` if
+    // the subspace cannot be larger than `nev`.
+    fn eigen_subspace_dim(&self, nev: usize) -> Option<usize> {
+        if nev >= self.nrows() {
+            None
+        } else {
+            Some((2 * nev + 1).max(20).min(self.nrows()))
+        }
+    }
+
+    // A deterministic starting vector for the Krylov eigensolvers, with pseudo-random components
+    // to avoid being orthogonal to some eigenvectors.
+    fn eigen_start_vector(&self) -> VectorN<N, D> {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut res = VectorN::zeros_generic(self.data.shape.0, U1);
+
+        for e in res.iter_mut() {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            *e = crate::convert((seed >> 11) as f64 / (1u64 << 53) as f64 + 0.5);
+        }
+
+        res
+    }
+}
@@ -0,0 +1,257 @@
+use std::iter;
+
+use crate::allocator::Allocator;
+use crate::linalg::{JacobiPreconditioner, LinearOperator, Preconditioner};
+use crate::sparse::{CsMatrix, CsStorage, CsStorageIter, CsVecStorage};
+use crate::{DefaultAllocator, Dim, RealField, VectorN, U1};
+
+impl<N: RealField, D: Dim, S: CsStorage<N, D, D>> LinearOperator<N, D> for CsMatrix<N, D, D, S>
+where DefaultAllocator: Allocator<N, D>
+{
+    #[inline]
+    fn apply(&self, x: &VectorN<N, D>, out: &mut VectorN<N, D>) {
+        out.gemv_sparse(N::one(), self, x, N::zero());
+    }
+}
+
+impl<N: RealField, D: Dim> JacobiPreconditioner<N, D>
+where DefaultAllocator: Allocator<N, D>
+{
+    /// Creates the Jacobi preconditioner of the given sparse square matrix.
+    pub fn from_cs_matrix<S: CsStorage<N, D, D>>(m: &CsMatrix<N, D, D, S>) -> Self {
+        assert!(m.is_square(), "The matrix `m` must be square.");
+        let mut diagonal = VectorN::zeros_generic(m.data.shape().0, U1);
+
+        for j in 0..m.ncols() {
+            for (i, val) in m.data.column_entries(j) {
+                if i == j {
+                    diagonal[j] += val;
+                }
+            }
+        }
+
+        Self::new(&diagonal)
+    }
+}
+
+/// The zero fill-in incomplete Cholesky factorization IC(0) of a sparse symmetric
+/// positive-definite matrix, to be used as a preconditioner.
+///
+/// This computes a lower-triangular matrix `L` with the same non-zero pattern as the lower
+/// triangular part of the decomposed matrix, such that `L * L^T` approximates it.
+#[derive(Clone, Debug)]
+pub struct CsIncompleteCholesky<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    l: CsMatrix<N, D, D>,
+}
+
+impl<N: RealField, D: Dim> CsIncompleteCholesky<N, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    /// Computes the IC(0) factorization of the sparse symmetric matrix `m`.
+    ///
+    /// Only the lower-triangular part (including the diagonal) of `m` is read. Returns `None` if
+    /// a non-positive pivot is encountered, which may happen even if `m` is positive-definite.
+    pub fn new(m: &CsMatrix<N, D, D>) -> Option<Self> {
+        let mut l = lower_triangular_part(m);
+        let n = l.nrows();
+        let mut pos: Vec<_> = iter::repeat(usize::max_value()).take(n).collect();
+
+        for k in 0..n {
+            let range_k = l.data.column_range(k);
+
+            if range_k.start == range_k.end || l.data.i[range_k.start] != k {
+                return None;
+            }
+
+            let diag = l.data.vals[range_k.start];
+            if diag <= N::zero() {
+                return None;
+            }
+
+            let diag = diag.sqrt();
+            l.data.vals[range_k.start] = diag;
+
+            for p in range_k.start + 1..range_k.end {
+                l.data.vals[p] /= diag;
+            }
+
+            // Update the next columns, ignoring the fill-in.
+            for p in range_k.start + 1..range_k.end {
+                let j = l.data.i[p];
+                let ljk = l.data.vals[p];
+                let range_j = l.data.column_range(j);
+
+                for q in range_j.clone() {
+                    pos[l.data.i[q]] = q;
+                }
+
+                for p2 in p..range_k.end {
+                    let q = pos[l.data.i[p2]];
+
+                    if q != usize::max_value() {
+                        let lik = l.data.vals[p2];
+                        l.data.vals[q] -= lik * ljk;
+                    }
+                }
+
+                for q in range_j {
+                    pos[l.data.i[q]] = usize::max_value();
+                }
+            }
+        }
+
+        Some(CsIncompleteCholesky { l })
+    }
+
+    /// The lower-triangular factor `L`.
+    pub fn l(&self) -> &CsMatrix<N, D, D> {
+        &self.l
+    }
+}
+
+impl<N: RealField, D: Dim> Preconditioner<N, D> for CsIncompleteCholesky<N, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    fn solve(&self, r: &VectorN<N, D>, z: &mut VectorN<N, D>) {
+        z.copy_from(r);
+        let _ = self.l.solve_lower_triangular_mut(z);
+        let _ = self.l.tr_solve_lower_triangular_mut(z);
+    }
+}
+
+/// The zero fill-in incomplete LU factorization ILU(0) of a sparse square matrix, to be used as
+/// a preconditioner.
+///
+/// This computes a unit lower-triangular matrix `L` and an upper-triangular matrix `U` with the
+/// same non-zero pattern as the decomposed matrix, such that `L * U` approximates it.
+#[derive(Clone, Debug)]
+pub struct CsIncompleteLU<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    l: CsMatrix<N, D, D>,
+    // The transpose of `U`, so that the systems involving `U` can be solved with the
+    // lower-triangular solvers.
+    u_tr: CsMatrix<N, D, D>,
+}
+
+impl<N: RealField, D: Dim> CsIncompleteLU<N, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    /// Computes the ILU(0) factorization of the sparse square matrix `m`.
+    ///
+    /// Returns `None` if a zero pivot is encountered or if an element of the diagonal of `m` is
+    /// not part of its non-zero pattern.
+    pub fn new(m: &CsMatrix<N, D, D>) -> Option<Self> {
+        assert!(m.is_square(), "The matrix `m` must be square.");
+
+        let mut lu = m.clone();
+        lu.sort();
+        let n = lu.nrows();
+        let mut pos: Vec<_> = iter::repeat(usize::max_value()).take(n).collect();
+
+        // Left-looking factorization where the strictly lower part of `lu` is `L` and its upper
+        // part is `U`.
+        for j in 0..n {
+            let range_j = lu.data.column_range(j);
+
+            for p in range_j.clone() {
+                pos[lu.data.i[p]] = p;
+            }
+
+            for p in range_j.clone() {
+                let k = lu.data.i[p];
+
+                if k >= j {
+                    break;
+                }
+
+                let ukj = lu.data.vals[p];
+
+                for q in lu.data.column_range(k) {
+                    let i = lu.data.i[q];
+
+                    if i > k && pos[i] != usize::max_value() {
+                        let lik = lu.data.vals[q];
+                        lu.data.vals[pos[i]] -= lik * ukj;
+                    }
+                }
+            }
+
+            let diag = pos[j];
+
+            for p in range_j.clone() {
+                pos[lu.data.i[p]] = usize::max_value();
+            }
+
+            if diag == usize::max_value() || lu.data.vals[diag].is_zero() {
+                return None;
+            }
+
+            let diag_val = lu.data.vals[diag];
+            for p in diag + 1..range_j.end {
+                lu.data.vals[p] /= diag_val;
+            }
+        }
+
+        let mut l = lower_triangular_part(&lu);
+        for j in 0..n {
+            let start = l.data.column_range(j).start;
+            l.data.vals[start] = N::one();
+        }
+
+        let u_tr = lower_triangular_part(&lu.transpose());
+
+        Some(CsIncompleteLU { l, u_tr })
+    }
+
+    /// The unit lower-triangular factor `L`.
+    pub fn l(&self) -> &CsMatrix<N, D, D> {
+        &self.l
+    }
+
+    /// The upper-triangular factor `U`.
+    pub fn u(&self) -> CsMatrix<N, D, D> {
+        self.u_tr.transpose()
+    }
+}
+
+impl<N: RealField, D: Dim> Preconditioner<N, D> for CsIncompleteLU<N, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    fn solve(&self, r: &VectorN<N, D>, z: &mut VectorN<N, D>) {
+        z.copy_from(r);
+        let _ = self.l.solve_lower_triangular_mut(z);
+        let _ = self.u_tr.tr_solve_lower_triangular_mut(z);
+    }
+}
+
+// The lower-triangular part (including the diagonal) of `m`, with sorted columns.
+fn lower_triangular_part<N: RealField, D: Dim>(m: &CsMatrix<N, D, D>) -> CsMatrix<N, D, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D> {
+    let shape = m.data.shape();
+    let mut p = Vec::with_capacity(m.ncols());
+    let mut i = Vec::new();
+    let mut vals = Vec::new();
+
+    for j in 0..m.ncols() {
+        p.push(i.len());
+
+        for (irow, val) in m.data.column_entries(j) {
+            if irow >= j {
+                i.push(irow);
+                vals.push(val);
+            }
+        }
+    }
+
+    let mut res = CsMatrix::from_data(CsVecStorage {
+        shape,
+        p: VectorN::from_iterator_generic(shape.1, U1, p.into_iter()),
+        i,
+        vals,
+    });
+    res.sort();
+    res
+}
@@ -0,0 +1,416 @@
+use std::iter;
+
+use crate::allocator::Allocator;
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::linalg::LinalgError;
+use crate::sparse::cs_matrix_solve::lower_triangular_dfs;
+use crate::sparse::{CsMatrix, CsStorage, CsStorageIter, CsVecStorage};
+use crate::storage::{Storage, StorageMut};
+use crate::{DefaultAllocator, Dim, Matrix, MatrixMN, RealField, VectorN, U1};
+
+/// The LU decomposition with partial pivoting of a column compressed sparse matrix.
+///
+/// This computes `P * m * Q = L * U` where `P` is a row permutation selected during the
+/// factorization, `Q` is a user-provided fill-reducing column permutation, `L` is a
+/// unit lower-triangular matrix, and `U` is an upper-triangular matrix.
+///
+/// The factorization is left-looking (Gilbert-Peierls): each column of `L` and `U` is obtained
+/// from a sparse triangular solve whose non-zero pattern is computed by a depth-first search in
+/// the graph of `L`.
+#[derive(Clone, Debug)]
+pub struct CsLU<N: RealField, D: Dim>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    l: CsMatrix<N, D, D>,
+    u: CsMatrix<N, D, D>,
+    // The k-th row of `L * U` is the `p[k]`-th row of the original matrix.
+    p: Vec<usize>,
+    // The k-th column of `L * U` is the `q[k]`-th column of the original matrix.
+    q: Option<Vec<usize>>,
+}
+
+impl<N: RealField, D: Dim> CsLU<N, D>
+where DefaultAllocator: Allocator<usize, D> + Allocator<N, D>
+{
+    /// Computes the LU decomposition with partial pivoting of the sparse matrix `m`, without any
+    /// column permutation.
+    ///
+    /// Returns `None` if `m` is singular.
+    pub fn new(m: &CsMatrix<N, D, D>) -> Option<Self> {
+        Self::try_decompose(m, None, N::one()).ok()
+    }
+
+    /// Computes the LU decomposition with threshold partial pivoting of the sparse matrix `m`
+    /// with its columns permuted by the fill-reducing ordering `perm`.
+    ///
+    /// The `k`-th column of the factorized matrix is the `perm[k]`-th column of `m`. See
+    /// `CsMatrix::column_approximate_minimum_degree_ordering` for a way of computing `perm`.
+    /// Returns `None` if `m` is singular.
+    ///
+    /// # Arguments
+    ///
+    /// * `perm`            − the column permutation.
+    /// * `pivot_threshold` − a number in `[0, 1]`. The diagonal entry `(perm[k], perm[k])` of `m`,
+    /// which usually preserves the sparsity best, is chosen as the `k`-th pivot if its magnitude
+    /// is at least `pivot_threshold` times the largest magnitude of the candidate pivots. Use
+    /// `1.0` for standard partial pivoting.
+    pub fn new_with_permutation(
+        m: &CsMatrix<N, D, D>,
+        perm: &[usize],
+        pivot_threshold: N,
+    ) -> Option<Self>
+    {
+        Self::try_decompose(m, Some(perm), pivot_threshold).ok()
+    }
+
+    /// Computes the LU decomposition with threshold partial pivoting of the sparse matrix `m`
+    /// with its columns permuted by the optional fill-reducing ordering `perm`.
+    ///
+    /// Returns `LinalgError::Singular` with the index of the column of the factorization that
+    /// has no non-zero pivot candidate if `m` is singular. See `CsLU::new_with_permutation` for
+    /// a description of the arguments.
+    pub fn try_decompose(
+        m: &CsMatrix<N, D, D>,
+        perm: Option<&[usize]>,
+        pivot_threshold: N,
+    ) -> Result<Self, LinalgError>
+    {
+        assert!(m.is_square(), "Unable to compute the LU decomposition of a non-square matrix.");
+        assert!(
+            pivot_threshold >= N::zero() && pivot_threshold <= N::one(),
+            "The pivot threshold must be in [0, 1]."
+        );
+
+        let n = m.nrows();
+
+        if let Some(perm) = perm {
+            assert_eq!(perm.len(), n, "Invalid permutation size.");
+            let mut seen: Vec<_> = iter::repeat(false).take(n).collect();
+
+            for j in perm {
+                assert!(*j < n && !seen[*j], "The ordering `perm` is not a permutation.");
+                seen[*j] = true;
+            }
+        }
+
+        // Factors under construction. Columns of `L` start with their pivot and their rows are
+        // indexed like the rows of `m` until the end of the factorization.
+        let mut lp = Vec::with_capacity(n + 1);
+        let mut li = Vec::with_capacity(m.len());
+        let mut lvals = Vec::with_capacity(m.len());
+        let mut up = Vec::with_capacity(n + 1);
+        let mut ui = Vec::with_capacity(m.len());
+        let mut uvals = Vec::with_capacity(m.len());
+
+        // `pinv[i] = k` if the `i`-th row of `m` is the `k`-th pivot row.
+        let mut pinv: Vec<_> = iter::repeat(usize::max_value()).take(n).collect();
+        let mut x: Vec<_> = iter::repeat(N::zero()).take(n).collect();
+        let mut visited: Vec<_> = iter::repeat(false).take(n).collect();
+        let mut stack = Vec::new();
+        let mut xi = Vec::new();
+
+        lp.push(0);
+        up.push(0);
+
+        for k in 0..n {
+            let col = perm.map_or(k, |perm| perm[k]);
+
+            // Solve `L * x = m[:, col]` where `L` is the factor computed so far.
+            xi.clear();
+            for irow in m.data.column_row_indices(col) {
+                lower_triangular_dfs(irow, &lp, &li, Some(&pinv), &mut visited, &mut stack, &mut xi);
+            }
+
+            for (irow, val) in m.data.column_entries(col) {
+                x[irow] = val;
+            }
+
+            for j in xi.iter().rev().cloned() {
+                let jnew = pinv[j];
+
+                if jnew != usize::max_value() {
+                    let xj = x[j];
+
+                    for p in lp[jnew] + 1..lp[jnew + 1] {
+                        x[li[p]] -= lvals[p] * xj;
+                    }
+                }
+            }
+
+            // Select the pivot among the rows that are not pivotal yet.
+            let mut ipiv = usize::max_value();
+            let mut max = N::zero();
+
+            for j in xi.iter().rev().cloned() {
+                visited[j] = false;
+
+                if pinv[j] == usize::max_value() {
+                    let candidate = x[j].abs();
+
+                    if candidate > max {
+                        max = candidate;
+                        ipiv = j;
+                    }
+                } else {
+                    ui.push(pinv[j]);
+                    uvals.push(x[j]);
+                }
+            }
+
+            if ipiv == usize::max_value() {
+                return Err(LinalgError::Singular { pivot: k });
+            }
+
+            if pinv[col] == usize::max_value()
+                && !x[col].is_zero()
+                && x[col].abs() >= max * pivot_threshold
+            {
+                ipiv = col;
+            }
+
+            let pivot = x[ipiv];
+            pinv[ipiv] = k;
+            ui.push(k);
+            uvals.push(pivot);
+            li.push(ipiv);
+            lvals.push(N::one());
+
+            for j in xi.iter().cloned() {
+                if pinv[j] == usize::max_value() {
+                    li.push(j);
+                    lvals.push(x[j] / pivot);
+                }
+
+                x[j] = N::zero();
+            }
+
+            lp.push(li.len());
+            up.push(ui.len());
+        }
+
+        // Index the rows of `L` by pivot.
+        for irow in &mut li {
+            *irow = pinv[*irow];
+        }
+
+        let mut p: Vec<_> = iter::repeat(0).take(n).collect();
+        for (i, k) in pinv.iter().enumerate() {
+            p[*k] = i;
+        }
+
+        let shape = m.data.shape();
+        let mut l = CsMatrix::from_data(CsVecStorage {
+            shape,
+            p: VectorN::from_iterator_generic(shape.1, U1, lp.into_iter().take(n)),
+            i: li,
+            vals: lvals,
+        });
+        let mut u = CsMatrix::from_data(CsVecStorage {
+            shape,
+            p: VectorN::from_iterator_generic(shape.1, U1, up.into_iter().take(n)),
+            i: ui,
+            vals: uvals,
+        });
+        l.sort();
+        u.sort();
+
+        Ok(CsLU {
+            l,
+            u,
+            p,
+            q: perm.map(|perm| perm.to_vec()),
+        })
+    }
+
+    /// The unit lower-triangular factor `L`.
+    pub fn l(&self) -> &CsMatrix<N, D, D> {
+        &self.l
+    }
+
+    /// The upper-triangular factor `U`.
+    pub fn u(&self) -> &CsMatrix<N, D, D> {
+        &self.u
+    }
+
+    /// The row permutation `P`: the `k`-th row of `L * U` is the `p[k]`-th row of the decomposed
+    /// matrix.
+    pub fn row_permutation(&self) -> &[usize] {
+        &self.p
+    }
+
+    /// The column permutation `Q`, if any: the `k`-th column of `L * U` is the `q[k]`-th column of
+    /// the decomposed matrix.
+    pub fn column_permutation(&self) -> Option<&[usize]> {
+        self.q.as_ref().map(|q| &q[..])
+    }
+
+    /// Solves the linear system `m * x = b` where `m` is the decomposed matrix.
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<N, R2, C2, S2>) -> MatrixMN<N, R2, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<D, R2>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Solves in-place the linear system `m * x = b` where `m` is the decomposed matrix.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<D, R2>,
+    {
+        let n = self.l.nrows();
+        assert_eq!(n, b.nrows(), "Mismatched matrix dimensions.");
+        let mut column = Vec::with_capacity(n);
+
+        for j2 in 0..b.ncols() {
+            let mut b = b.column_mut(j2);
+
+            column.clear();
+            column.extend(self.p.iter().map(|i| b[*i]));
+
+            // Forward substitution with the unit diagonal stored first.
+            for j in 0..n {
+                let xj = column[j];
+
+                for (i, val) in self.l.data.column_entries(j).skip(1) {
+                    column[i] -= val * xj;
+                }
+            }
+
+            // Backward substitution with the diagonal stored last.
+            for j in (0..n).rev() {
+                let range = self.u.data.column_range(j);
+                column[j] /= self.u.data.vals[range.end - 1];
+                let xj = column[j];
+
+                for p in range.start..range.end - 1 {
+                    column[self.u.data.i[p]] -= self.u.data.vals[p] * xj;
+                }
+            }
+
+            match self.q {
+                Some(ref q) => {
+                    for (k, val) in column.iter().enumerate() {
+                        b[q[k]] = *val;
+                    }
+                }
+                None => {
+                    for (k, val) in column.iter().enumerate() {
+                        b[k] = *val;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Solves the linear system `m^T * x = b` where `m` is the decomposed matrix.
+    pub fn solve_transpose<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> MatrixMN<N, R2, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<D, R2>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_transpose_mut(&mut res);
+        res
+    }
+
+    /// Solves in-place the linear system `m^T * x = b` where `m` is the decomposed matrix.
+    pub fn solve_transpose_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<D, R2>,
+    {
+        let n = self.l.nrows();
+        assert_eq!(n, b.nrows(), "Mismatched matrix dimensions.");
+        let mut column = Vec::with_capacity(n);
+
+        for j2 in 0..b.ncols() {
+            let mut b = b.column_mut(j2);
+
+            column.clear();
+            match self.q {
+                Some(ref q) => column.extend(q.iter().map(|j| b[*j])),
+                None => column.extend(b.iter().cloned()),
+            }
+
+            // Forward substitution with `U^T`.
+            for j in 0..n {
+                let range = self.u.data.column_range(j);
+                let mut xj = column[j];
+
+                for p in range.start..range.end - 1 {
+                    xj -= self.u.data.vals[p] * column[self.u.data.i[p]];
+                }
+
+                column[j] = xj / self.u.data.vals[range.end - 1];
+            }
+
+            // Backward substitution with `L^T`.
+            for j in (0..n).rev() {
+                let mut xj = column[j];
+
+                for (i, val) in self.l.data.column_entries(j).skip(1) {
+                    xj -= val * column[i];
+                }
+
+                column[j] = xj;
+            }
+
+            for (k, val) in column.iter().enumerate() {
+                b[self.p[k]] = *val;
+            }
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N {
+        let mut res = N::one();
+
+        for j in 0..self.u.ncols() {
+            let range = self.u.data.column_range(j);
+            res *= self.u.data.vals[range.end - 1];
+        }
+
+        let mut odd = permutation_is_odd(&self.p);
+
+        if let Some(ref q) = self.q {
+            odd ^= permutation_is_odd(q);
+        }
+
+        if odd {
+            -res
+        } else {
+            res
+        }
+    }
+}
+
+// Whether the permutation `perm` has an odd signature.
+fn permutation_is_odd(perm: &[usize]) -> bool {
+    let mut visited: Vec<_> = iter::repeat(false).take(perm.len()).collect();
+    let mut odd = false;
+
+    for start in 0..perm.len() {
+        let mut i = start;
+
+        while !visited[i] {
+            visited[i] = true;
+            i = perm[i];
+
+            // Each cycle of length `l` contributes `l - 1` transpositions.
+            if i != start {
+                odd = !odd;
+            }
+        }
+    }
+
+    odd
+}
@@ -1,12 +1,13 @@
-use alga::general::{ClosedAdd, ClosedMul};
+use alga::general::{ClosedAdd, ClosedMul, ClosedNeg};
 use num::{One, Zero};
-use std::ops::{Add, Mul};
+use std::iter;
+use std::ops::{Add, Mul, Neg, Sub};
 
 use crate::allocator::Allocator;
-use crate::constraint::{AreMultipliable, DimEq, ShapeConstraint};
-use crate::sparse::{CsMatrix, CsStorage, CsStorageMut, CsVector};
-use crate::storage::StorageMut;
-use crate::{DefaultAllocator, Dim, Scalar, Vector, VectorN, U1};
+use crate::constraint::{AreMultipliable, DimEq, SameNumberOfRows, ShapeConstraint};
+use crate::sparse::{CsMatrix, CsStorage, CsStorageMut, CsVecStorage, CsVector};
+use crate::storage::{Storage, StorageMut};
+use crate::{DefaultAllocator, Dim, Matrix, MatrixMN, Scalar, Vector, VectorN, U1};
 
 impl<N: Scalar, R: Dim, C: Dim, S: CsStorage<N, R, C>> CsMatrix<N, R, C, S> {
     fn scatter<R2: Dim, C2: Dim>(
@@ -38,6 +39,122 @@ impl<N: Scalar, R: Dim, C: Dim, S: CsStorage<N, R, C>> CsMatrix<N, R, C, S> {
     }
 }
 
+impl<N: Scalar + ClosedAdd + ClosedMul + Zero, R: Dim, C: Dim, S: CsStorage<N, R, C>>
+    CsMatrix<N, R, C, S>
+{
+    /// Computes `self.transpose() * rhs` without computing the transpose of `self` explicitly.
+    ///
+    /// The result is sorted.
+    pub fn tr_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &CsMatrix<N, R2, C2, S2>) -> CsMatrix<N, C, C2>
+    where
+        S2: CsStorage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<usize, C2> + Allocator<N, C>,
+    {
+        let (nrows1, ncols1) = self.data.shape();
+        let (nrows2, ncols2) = rhs.data.shape();
+        assert_eq!(
+            nrows1.value(),
+            nrows2.value(),
+            "Mismatched dimensions for matrix multiplication."
+        );
+
+        // Index the entries of `self` row-wise: `row_entries[row_p[r]..row_p[r + 1]]` are the
+        // indices of the entries of the r-th row in the value buffer of `self`, and `row_cols`
+        // their column indices.
+        let nvals = self.len();
+        let mut row_p: Vec<usize> = iter::repeat(0).take(nrows1.value() + 1).collect();
+
+        for k in 0..nvals {
+            row_p[self.data.row_index(k) + 1] += 1;
+        }
+
+        for r in 0..nrows1.value() {
+            row_p[r + 1] += row_p[r];
+        }
+
+        let mut next = row_p.clone();
+        let mut row_entries: Vec<usize> = iter::repeat(0).take(nvals).collect();
+        let mut row_cols: Vec<usize> = iter::repeat(0).take(nvals).collect();
+
+        for j in 0..ncols1.value() {
+            for k in self.data.column_range(j) {
+                let r = self.data.row_index(k);
+                row_entries[next[r]] = k;
+                row_cols[next[r]] = j;
+                next[r] += 1;
+            }
+        }
+
+        let mut p = Vec::with_capacity(ncols2.value());
+        let mut i = Vec::new();
+        let mut vals = Vec::new();
+        let mut timestamps: Vec<usize> = iter::repeat(0).take(ncols1.value()).collect();
+        let mut workspace = VectorN::<N, C>::zeros_generic(ncols1, U1);
+
+        for j in 0..ncols2.value() {
+            let start = i.len();
+            p.push(start);
+
+            for (r, beta) in rhs.data.column_entries(j) {
+                for q in row_p[r]..row_p[r + 1] {
+                    let c = row_cols[q];
+                    let val = *self.data.get_value(row_entries[q]) * beta;
+
+                    if timestamps[c] != j + 1 {
+                        timestamps[c] = j + 1;
+                        i.push(c);
+                        workspace[c] = val;
+                    } else {
+                        workspace[c] += val;
+                    }
+                }
+            }
+
+            // Keep the output sorted.
+            i[start..].sort();
+
+            for c in &i[start..] {
+                vals.push(workspace[*c]);
+            }
+        }
+
+        CsMatrix::from_data(CsVecStorage {
+            shape: (ncols1, ncols2),
+            p: VectorN::from_iterator_generic(ncols2, U1, p.into_iter()),
+            i,
+            vals,
+        })
+    }
+
+    /// Computes the triple product `self * mid * self.transpose()`.
+    ///
+    /// This is the symmetric permutation `P A Pᵀ` of `A` if `self` is a permutation matrix
+    /// `P`, and `mid = A`. The result is sorted.
+    pub fn quadform_tr<S2>(&self, mid: &CsMatrix<N, C, C, S2>) -> CsMatrix<N, R, R>
+    where
+        S2: CsStorage<N, C, C>,
+        DefaultAllocator: Allocator<usize, R> + Allocator<N, R> + Allocator<N, C>,
+    {
+        // (midᵀ * selfᵀ)ᵀ * selfᵀ = self * mid * selfᵀ
+        let self_tr = self.transpose();
+        mid.tr_mul(&self_tr).tr_mul(&self_tr)
+    }
+
+    /// Computes the triple product `self.transpose() * mid * self`.
+    ///
+    /// This is the Galerkin operator `Pᵀ A P` if `self` is a prolongation operator `P` and
+    /// `mid = A`. The result is sorted.
+    pub fn quadform<S2>(&self, mid: &CsMatrix<N, R, R, S2>) -> CsMatrix<N, C, C>
+    where
+        S2: CsStorage<N, R, R>,
+        DefaultAllocator: Allocator<usize, R> + Allocator<usize, C> + Allocator<N, R> + Allocator<N, C>,
+    {
+        let mid_self = mid * self;
+        self.tr_mul(&mid_self)
+    }
+}
+
 /*
 impl<N: Scalar, R, S> CsVector<N, R, S> {
     pub fn axpy(&mut self, alpha: N, x: CsVector<N, R, S>, beta: N) {
@@ -105,22 +222,77 @@ impl<N: Scalar + Zero + ClosedAdd + ClosedMul, D: Dim, S: StorageMut<N, D>> Vect
         }
     }
 
-    /*
-    pub fn gemv_sparse<R2: Dim, C2: Dim, S2>(&mut self, alpha: N, a: &CsMatrix<N, R2, C2, S2>, x: &DVector<N>, beta: N)
-        where
-            S2: CsStorage<N, R2, C2> {
-        let col2 = a.column(0);
-        let val = unsafe { *x.vget_unchecked(0) };
-        self.axpy_sparse(alpha * val, &col2, beta);
-    
-        for j in 1..ncols2 {
-            let col2 = a.column(j);
-            let val = unsafe { *x.vget_unchecked(j) };
-    
-            self.axpy_sparse(alpha * val, &col2, N::one());
+    /// Perform a sparse gemv operation: `self = alpha * a * x + beta * self` where `a` is a
+    /// sparse matrix and `x` a dense vector.
+    pub fn gemv_sparse<R2: Dim, C2: Dim, D3: Dim, S2, S3>(
+        &mut self,
+        alpha: N,
+        a: &CsMatrix<N, R2, C2, S2>,
+        x: &Vector<N, D3, S3>,
+        beta: N,
+    ) where
+        S2: CsStorage<N, R2, C2>,
+        S3: Storage<N, D3>,
+        ShapeConstraint: DimEq<D, R2> + AreMultipliable<R2, C2, D3, U1>,
+    {
+        assert!(
+            a.nrows() == self.len() && a.ncols() == x.len(),
+            "Gemv: dimensions mismatch."
+        );
+
+        if beta.is_zero() {
+            self.fill(N::zero());
+        } else {
+            *self *= beta;
+        }
+
+        for j in 0..a.ncols() {
+            let val = alpha * unsafe { *x.vget_unchecked(j) };
+
+            for (i, aij) in a.data.column_entries(j) {
+                unsafe {
+                    *self.vget_unchecked_mut(i) += aij * val;
+                }
+            }
+        }
+    }
+
+    /// Perform a sparse gemv operation: `self = alpha * a.transpose() * x + beta * self` where
+    /// `a` is a sparse matrix and `x` a dense vector.
+    ///
+    /// The transpose of `a` is never computed explicitly.
+    pub fn gemv_tr_sparse<R2: Dim, C2: Dim, D3: Dim, S2, S3>(
+        &mut self,
+        alpha: N,
+        a: &CsMatrix<N, R2, C2, S2>,
+        x: &Vector<N, D3, S3>,
+        beta: N,
+    ) where
+        S2: CsStorage<N, R2, C2>,
+        S3: Storage<N, D3>,
+        ShapeConstraint: DimEq<D, C2> + AreMultipliable<C2, R2, D3, U1>,
+    {
+        assert!(
+            a.ncols() == self.len() && a.nrows() == x.len(),
+            "Gemv: dimensions mismatch."
+        );
+
+        for j in 0..a.ncols() {
+            let mut dot = N::zero();
+
+            for (i, aij) in a.data.column_entries(j) {
+                dot += aij * unsafe { *x.vget_unchecked(i) };
+            }
+
+            let y = unsafe { self.vget_unchecked_mut(j) };
+
+            if beta.is_zero() {
+                *y = alpha * dot;
+            } else {
+                *y = alpha * dot + beta * *y;
+            }
         }
     }
-    */
 }
 
 impl<'a, 'b, N, R1, R2, C1, C2, S1, S2> Mul<&'b CsMatrix<N, R2, C2, S2>>
@@ -302,3 +474,172 @@ where
         self
     }
 }
+
+impl<'a, 'b, N, R1, R2, C1, C2, S1, S2> Sub<&'b CsMatrix<N, R2, C2, S2>>
+    for &'a CsMatrix<N, R1, C1, S1>
+where
+    N: Scalar + ClosedAdd + ClosedMul + ClosedNeg + One,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    S1: CsStorage<N, R1, C1>,
+    S2: CsStorage<N, R2, C2>,
+    ShapeConstraint: DimEq<R1, R2> + DimEq<C1, C2>,
+    DefaultAllocator: Allocator<usize, C2> + Allocator<usize, R1> + Allocator<N, R1>,
+{
+    type Output = CsMatrix<N, R1, C2>;
+
+    fn sub(self, rhs: &'b CsMatrix<N, R2, C2, S2>) -> Self::Output {
+        let (nrows1, ncols1) = self.data.shape();
+        let (nrows2, ncols2) = rhs.data.shape();
+        assert_eq!(
+            (nrows1.value(), ncols1.value()),
+            (nrows2.value(), ncols2.value()),
+            "Mismatched dimensions for matrix subtraction."
+        );
+
+        let mut res = CsMatrix::new_uninitialized_generic(nrows1, ncols2, self.len() + rhs.len());
+        let mut timestamps = VectorN::zeros_generic(nrows1, U1);
+        let mut workspace = unsafe { VectorN::new_uninitialized_generic(nrows1, U1) };
+        let mut nz = 0;
+
+        for j in 0..ncols2.value() {
+            res.data.p[j] = nz;
+
+            nz = self.scatter(
+                j,
+                N::one(),
+                timestamps.as_mut_slice(),
+                j + 1,
+                workspace.as_mut_slice(),
+                nz,
+                &mut res,
+            );
+
+            nz = rhs.scatter(
+                j,
+                -N::one(),
+                timestamps.as_mut_slice(),
+                j + 1,
+                workspace.as_mut_slice(),
+                nz,
+                &mut res,
+            );
+
+            // Keep the output sorted.
+            let range = res.data.p[j]..nz;
+            res.data.i[range.clone()].sort();
+
+            for p in range {
+                res.data.vals[p] = workspace[res.data.i[p]]
+            }
+        }
+
+        res.data.i.truncate(nz);
+        res.data.i.shrink_to_fit();
+        res.data.vals.truncate(nz);
+        res.data.vals.shrink_to_fit();
+        res
+    }
+}
+
+impl<N, R, C, S> Neg for CsMatrix<N, R, C, S>
+where
+    N: Scalar + ClosedNeg,
+    R: Dim,
+    C: Dim,
+    S: CsStorageMut<N, R, C>,
+{
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for e in self.values_mut() {
+            *e = -*e
+        }
+
+        self
+    }
+}
+
+impl<'a, 'b, N, R1, R2, C1, C2, S1, S2> Mul<&'b Matrix<N, R2, C2, S2>>
+    for &'a CsMatrix<N, R1, C1, S1>
+where
+    N: Scalar + ClosedAdd + ClosedMul + Zero,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    S1: CsStorage<N, R1, C1>,
+    S2: Storage<N, R2, C2>,
+    ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    DefaultAllocator: Allocator<N, R1, C2>,
+{
+    type Output = MatrixMN<N, R1, C2>;
+
+    fn mul(self, rhs: &'b Matrix<N, R2, C2, S2>) -> Self::Output {
+        let (nrows1, ncols1) = self.data.shape();
+        let (nrows2, ncols2) = rhs.data.shape();
+        assert_eq!(
+            ncols1.value(),
+            nrows2.value(),
+            "Mismatched dimensions for matrix multiplication."
+        );
+
+        let mut res = MatrixMN::zeros_generic(nrows1, ncols2);
+
+        for j in 0..ncols2.value() {
+            for k in 0..ncols1.value() {
+                let beta = unsafe { *rhs.get_unchecked((k, j)) };
+
+                for (i, val) in self.data.column_entries(k) {
+                    unsafe {
+                        *res.get_unchecked_mut((i, j)) += val * beta;
+                    }
+                }
+            }
+        }
+
+        res
+    }
+}
+
+impl<'a, 'b, N, R1, R2, C1, C2, S1, S2> Mul<&'b CsMatrix<N, R2, C2, S2>>
+    for &'a Matrix<N, R1, C1, S1>
+where
+    N: Scalar + ClosedAdd + ClosedMul + Zero,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    S1: Storage<N, R1, C1>,
+    S2: CsStorage<N, R2, C2>,
+    ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    DefaultAllocator: Allocator<N, R1, C2>,
+{
+    type Output = MatrixMN<N, R1, C2>;
+
+    fn mul(self, rhs: &'b CsMatrix<N, R2, C2, S2>) -> Self::Output {
+        let (nrows1, ncols1) = self.data.shape();
+        let (nrows2, ncols2) = rhs.data.shape();
+        assert_eq!(
+            ncols1.value(),
+            nrows2.value(),
+            "Mismatched dimensions for matrix multiplication."
+        );
+
+        let mut res = MatrixMN::zeros_generic(nrows1, ncols2);
+
+        for j in 0..ncols2.value() {
+            for (k, beta) in rhs.data.column_entries(j) {
+                for i in 0..nrows1.value() {
+                    unsafe {
+                        *res.get_unchecked_mut((i, j)) += *self.get_unchecked((i, k)) * beta;
+                    }
+                }
+            }
+        }
+
+        res
+    }
+}
@@ -0,0 +1,450 @@
+use std::iter;
+use std::mem;
+
+use crate::sparse::{CsMatrix, CsStorage};
+use crate::{Dim, Scalar};
+
+// Parts of the graph with at most this number of nodes are not dissected further by the nested
+// dissection and are ordered by minimum degree instead.
+const NESTED_DISSECTION_LEAF_SIZE: usize = 64;
+
+impl<N: Scalar, D: Dim, S: CsStorage<N, D, D>> CsMatrix<N, D, D, S> {
+    /// Computes a fill-reducing ordering of this square matrix using an approximate minimum
+    /// degree heuristic.
+    ///
+    /// Only the non-zero pattern of `self + self^T` is taken into account. The returned
+    /// permutation `perm` is such that the `k`-th row and column of the permuted matrix are the
+    /// `perm[k]`-th row and column of `self`. It can be given to `CsCholesky::new_with_permutation`.
+    pub fn approximate_minimum_degree_ordering(&self) -> Vec<usize> {
+        assert!(self.is_square(), "Unable to order a non-square matrix.");
+        approximate_minimum_degree(self.symmetric_adjacency())
+    }
+
+    /// Computes a fill-reducing ordering of this square matrix using nested dissection.
+    ///
+    /// The graph of `self + self^T` is recursively split by vertex separators found from level
+    /// structures rooted at pseudo-peripheral nodes. Separators are ordered last and small parts
+    /// are ordered by approximate minimum degree. The returned permutation follows the same
+    /// convention as `approximate_minimum_degree_ordering`.
+    pub fn nested_dissection_ordering(&self) -> Vec<usize> {
+        assert!(self.is_square(), "Unable to order a non-square matrix.");
+        nested_dissection(&self.symmetric_adjacency())
+    }
+
+    // The sorted adjacency lists of the graph of `self + self^T`, without self-loops.
+    fn symmetric_adjacency(&self) -> Vec<Vec<usize>> {
+        let n = self.nrows();
+        let mut adj: Vec<Vec<usize>> = iter::repeat(Vec::new()).take(n).collect();
+
+        for j in 0..n {
+            for i in self.data.column_row_indices(j) {
+                if i != j {
+                    adj[i].push(j);
+                    adj[j].push(i);
+                }
+            }
+        }
+
+        for neighbors in &mut adj {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        }
+
+        adj
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S: CsStorage<N, R, C>> CsMatrix<N, R, C, S> {
+    /// Computes a fill-reducing column ordering of this matrix, suitable for its LU or QR
+    /// decomposition, using an approximate minimum degree heuristic on the pattern of
+    /// `self^T * self`.
+    ///
+    /// Rows with more than `10 * sqrt(ncols)` non-zero entries (and at least 16) are ignored since
+    /// they would make the pattern of `self^T * self` dense. The returned permutation `perm` is
+    /// such that the `k`-th column of the permuted matrix is the `perm[k]`-th column of `self`.
+    pub fn column_approximate_minimum_degree_ordering(&self) -> Vec<usize> {
+        let (nrows, ncols) = self.shape();
+        let mut rows: Vec<Vec<usize>> = iter::repeat(Vec::new()).take(nrows).collect();
+
+        for j in 0..ncols {
+            for i in self.data.column_row_indices(j) {
+                rows[i].push(j);
+            }
+        }
+
+        let mut adj: Vec<Vec<usize>> = iter::repeat(Vec::new()).take(ncols).collect();
+
+        for row in &rows {
+            let dense = row.len() > 16 && row.len() * row.len() > 100 * ncols;
+
+            if !dense {
+                for a in row {
+                    for b in row {
+                        if a != b {
+                            adj[*a].push(*b);
+                        }
+                    }
+                }
+            }
+        }
+
+        for neighbors in &mut adj {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        }
+
+        approximate_minimum_degree(adj)
+    }
+}
+
+// Doubly-linked lists of nodes bucketed by degree.
+struct DegreeLists {
+    head: Vec<usize>,
+    next: Vec<usize>,
+    prev: Vec<usize>,
+    min: usize,
+}
+
+impl DegreeLists {
+    fn new(n: usize) -> Self {
+        DegreeLists {
+            head: iter::repeat(usize::max_value()).take(n.max(1)).collect(),
+            next: iter::repeat(usize::max_value()).take(n).collect(),
+            prev: iter::repeat(usize::max_value()).take(n).collect(),
+            min: 0,
+        }
+    }
+
+    fn insert(&mut self, i: usize, degree: usize) {
+        let head = self.head[degree];
+        self.next[i] = head;
+        self.prev[i] = usize::max_value();
+
+        if head != usize::max_value() {
+            self.prev[head] = i;
+        }
+
+        self.head[degree] = i;
+        self.min = self.min.min(degree);
+    }
+
+    fn remove(&mut self, i: usize, degree: usize) {
+        let (prev, next) = (self.prev[i], self.next[i]);
+
+        if prev != usize::max_value() {
+            self.next[prev] = next;
+        } else {
+            self.head[degree] = next;
+        }
+
+        if next != usize::max_value() {
+            self.prev[next] = prev;
+        }
+    }
+
+    fn pop_min(&mut self) -> usize {
+        while self.head[self.min] == usize::max_value() {
+            self.min += 1;
+        }
+
+        let i = self.head[self.min];
+        let min = self.min;
+        self.remove(i, min);
+        i
+    }
+}
+
+// Approximate minimum degree ordering based on the quotient graph, with element absorption and
+// the approximate external degrees of Amestoy, Davis and Duff.
+//
+// Eliminated nodes become elements: element `e` is identified with the node `e` it originates
+// from. Each remaining variable `i` is adjacent to the variables `adj[i]` and to the elements
+// `elems[i]`, and each element `e` is adjacent to the variables `vars[e]`.
+fn approximate_minimum_degree(mut adj: Vec<Vec<usize>>) -> Vec<usize> {
+    let n = adj.len();
+    let mut elems: Vec<Vec<usize>> = iter::repeat(Vec::new()).take(n).collect();
+    let mut vars: Vec<Vec<usize>> = iter::repeat(Vec::new()).take(n).collect();
+    let mut absorbed: Vec<_> = iter::repeat(false).take(n).collect();
+    let mut degree: Vec<_> = adj.iter().map(|neighbors| neighbors.len()).collect();
+    let mut lists = DegreeLists::new(n);
+    // `mark[i] == stamp` iff `i` belongs to the pattern of the current pivot.
+    let mut mark: Vec<_> = iter::repeat(0).take(n).collect();
+    // `external[e]` is the number of variables of `e` outside of the pattern of the current pivot.
+    let mut external: Vec<_> = iter::repeat(0).take(n).collect();
+    let mut external_mark: Vec<_> = iter::repeat(0).take(n).collect();
+    let mut perm = Vec::with_capacity(n);
+
+    for i in 0..n {
+        lists.insert(i, degree[i]);
+    }
+
+    for k in 0..n {
+        let p = lists.pop_min();
+        let stamp = k + 1;
+        perm.push(p);
+        mark[p] = stamp;
+
+        // Compute the pattern of the new element `p` and absorb the elements adjacent to `p`.
+        let mut pattern = Vec::new();
+
+        for i in mem::replace(&mut adj[p], Vec::new()) {
+            if mark[i] != stamp {
+                mark[i] = stamp;
+                pattern.push(i);
+            }
+        }
+
+        for e in mem::replace(&mut elems[p], Vec::new()) {
+            for i in mem::replace(&mut vars[e], Vec::new()) {
+                if mark[i] != stamp {
+                    mark[i] = stamp;
+                    pattern.push(i);
+                }
+            }
+
+            absorbed[e] = true;
+        }
+
+        // Variables adjacent to `p` are now represented by the element `p`.
+        for &i in &pattern {
+            lists.remove(i, degree[i]);
+            adj[i].retain(|j| mark[*j] != stamp);
+            elems[i].retain(|e| !absorbed[*e]);
+            elems[i].push(p);
+        }
+
+        // Compute `|vars[e] \ pattern|` for all the other elements adjacent to the pattern.
+        for &i in &pattern {
+            for &e in &elems[i] {
+                if e != p {
+                    if external_mark[e] != stamp {
+                        external_mark[e] = stamp;
+                        external[e] = vars[e].len();
+                    }
+
+                    external[e] -= 1;
+                }
+            }
+        }
+
+        // Aggressive absorption of the elements included in the new one.
+        for &i in &pattern {
+            for &e in &elems[i] {
+                if e != p && external[e] == 0 && !absorbed[e] {
+                    absorbed[e] = true;
+                    vars[e] = Vec::new();
+                }
+            }
+        }
+
+        // Update the approximate degrees.
+        let remaining = n - k - 1;
+
+        for &i in &pattern {
+            elems[i].retain(|e| !absorbed[*e]);
+
+            let mut d = adj[i].len() + pattern.len() - 1;
+            for &e in &elems[i] {
+                if e != p {
+                    d += external[e];
+                }
+            }
+
+            d = d.min(degree[i] + pattern.len() - 1).min(remaining - 1);
+            degree[i] = d;
+            lists.insert(i, d);
+        }
+
+        vars[p] = pattern;
+    }
+
+    perm
+}
+
+enum DissectionTask {
+    // Order the given set of nodes.
+    Split(Vec<usize>),
+    // Append the given set of nodes to the permutation.
+    Emit(Vec<usize>),
+}
+
+// Workspace for the breadth-first searches restricted to one part of the graph.
+struct LevelStructure<'a> {
+    adj: &'a [Vec<usize>],
+    part: Vec<usize>,
+    depth: Vec<usize>,
+    visited: Vec<usize>,
+    stamp: usize,
+}
+
+impl<'a> LevelStructure<'a> {
+    fn new(adj: &'a [Vec<usize>]) -> Self {
+        let n = adj.len();
+
+        LevelStructure {
+            adj,
+            part: iter::repeat(0).take(n).collect(),
+            depth: iter::repeat(0).take(n).collect(),
+            visited: iter::repeat(0).take(n).collect(),
+            stamp: 0,
+        }
+    }
+
+    // The level sets of the breadth-first search starting at `root` and restricted to the part
+    // `part_id`.
+    fn levels(&mut self, root: usize, part_id: usize) -> Vec<Vec<usize>> {
+        self.stamp += 1;
+        self.visited[root] = self.stamp;
+        self.depth[root] = 0;
+        let mut levels = Vec::new();
+        levels.push(iter::once(root).collect::<Vec<_>>());
+
+        loop {
+            let mut next = Vec::new();
+
+            for &v in &levels[levels.len() - 1] {
+                for &u in &self.adj[v] {
+                    if self.part[u] == part_id && self.visited[u] != self.stamp {
+                        self.visited[u] = self.stamp;
+                        self.depth[u] = levels.len();
+                        next.push(u);
+                    }
+                }
+            }
+
+            if next.is_empty() {
+                return levels;
+            }
+
+            levels.push(next);
+        }
+    }
+
+    // The level structure rooted at a pseudo-peripheral node of the connected component of `root`.
+    fn pseudo_peripheral_levels(&mut self, mut root: usize, part_id: usize) -> Vec<Vec<usize>> {
+        let mut levels = self.levels(root, part_id);
+
+        loop {
+            let candidate = *levels[levels.len() - 1]
+                .iter()
+                .min_by_key(|v| self.adj[**v].len())
+                .unwrap();
+            let candidate_levels = self.levels(candidate, part_id);
+
+            if candidate_levels.len() <= levels.len() {
+                // Restore the marks of the level structure we keep.
+                return self.levels(root, part_id);
+            }
+
+            root = candidate;
+            levels = candidate_levels;
+        }
+    }
+}
+
+fn nested_dissection(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n = adj.len();
+    let mut ls = LevelStructure::new(adj);
+    let mut local: Vec<_> = iter::repeat(0).take(n).collect();
+    let mut perm = Vec::with_capacity(n);
+    let mut tasks = Vec::new();
+    tasks.push(DissectionTask::Split((0..n).collect()));
+    let mut nparts = 0;
+
+    while let Some(task) = tasks.pop() {
+        let nodes = match task {
+            DissectionTask::Emit(nodes) => {
+                perm.extend(nodes);
+                continue;
+            }
+            DissectionTask::Split(nodes) => nodes,
+        };
+
+        if nodes.is_empty() {
+            continue;
+        }
+
+        nparts += 1;
+        let part_id = nparts;
+
+        for &v in &nodes {
+            ls.part[v] = part_id;
+        }
+
+        let levels = if nodes.len() > NESTED_DISSECTION_LEAF_SIZE {
+            ls.pseudo_peripheral_levels(nodes[0], part_id)
+        } else {
+            Vec::new()
+        };
+        let nvisited: usize = levels.iter().map(|level| level.len()).sum();
+
+        if nvisited != 0 && nvisited < nodes.len() {
+            // The part is not connected: order its connected components independently.
+            let stamp = ls.stamp;
+            let rest = nodes.iter().cloned().filter(|v| ls.visited[*v] != stamp).collect();
+            tasks.push(DissectionTask::Split(rest));
+            tasks.push(DissectionTask::Split(levels.concat()));
+            continue;
+        }
+
+        if levels.len() < 3 {
+            // Small or too densely connected part.
+            for (l, &v) in nodes.iter().enumerate() {
+                local[v] = l;
+            }
+
+            let sub_adj = nodes
+                .iter()
+                .map(|v| {
+                    adj[*v]
+                        .iter()
+                        .filter(|u| ls.part[**u] == part_id)
+                        .map(|u| local[*u])
+                        .collect()
+                })
+                .collect();
+
+            perm.extend(
+                approximate_minimum_degree(sub_adj)
+                    .into_iter()
+                    .map(|l| nodes[l]),
+            );
+            continue;
+        }
+
+        // Select the level splitting the part in two halves as separator, excluding the first
+        // and last levels so that both sides are non-empty.
+        let mut sep_level = 0;
+        let mut count = 0;
+        while count + levels[sep_level].len() < nodes.len() / 2 {
+            count += levels[sep_level].len();
+            sep_level += 1;
+        }
+        sep_level = sep_level.max(1).min(levels.len() - 2);
+
+        // Nodes of the separator level that are not adjacent to the next level can be moved
+        // to the first side.
+        let mut first = levels[..sep_level].concat();
+        let mut separator = Vec::new();
+
+        for &v in &levels[sep_level] {
+            let separates = adj[v]
+                .iter()
+                .any(|u| ls.part[*u] == part_id && ls.depth[*u] == sep_level + 1);
+
+            if separates {
+                separator.push(v);
+            } else {
+                first.push(v);
+            }
+        }
+
+        let second = levels[sep_level + 1..].concat();
+
+        tasks.push(DissectionTask::Emit(separator));
+        tasks.push(DissectionTask::Split(second));
+        tasks.push(DissectionTask::Split(first));
+    }
+
+    perm
+}
@@ -0,0 +1,464 @@
+use std::iter;
+use std::ops::Range;
+
+use crate::allocator::Allocator;
+use crate::linalg::{LeastSquaresSolution, LinalgError};
+use crate::sparse::{CsMatrix, CsStorage, CsVecStorage};
+use crate::storage::Storage;
+use crate::{DefaultAllocator, Dim, Matrix, MatrixMN, RealField, VectorN, U1};
+
+/// The Householder QR decomposition of a column compressed sparse matrix with at least as many
+/// rows as columns.
+///
+/// This computes `P * m * Q = H * [R; 0]` where `Q` is a fill-reducing column permutation, `P` is a
+/// row permutation selected by the symbolic analysis, `H` is the product of the Householder
+/// reflections and `R` is upper-triangular. The orthogonal factor is kept in its implicit form,
+/// i.e., as a sparse set of Householder vectors.
+#[derive(Clone, Debug)]
+pub struct CsQR<N: RealField, R: Dim, C: Dim>
+where DefaultAllocator: Allocator<usize, C> + Allocator<N, C>
+{
+    // Householder vectors and their coefficients. The rows of the Householder vectors are the
+    // rows of the matrix permuted by `pinv`, followed by fictitious rows added when the matrix
+    // is structurally rank-deficient.
+    v_p: Vec<usize>,
+    v_i: Vec<usize>,
+    v_vals: Vec<N>,
+    beta: Vec<N>,
+    r: CsMatrix<N, C, C>,
+    // `pinv[i]` is the row of the Householder vectors corresponding to the i-th row of the matrix.
+    pinv: Vec<usize>,
+    // The k-th column of `R` corresponds to the `q[k]`-th column of the matrix.
+    q: Vec<usize>,
+    // The number of rows of the Householder vectors, including fictitious rows.
+    nrows_v: usize,
+    shape: (R, C),
+}
+
+impl<N: RealField, R: Dim, C: Dim> CsQR<N, R, C>
+where DefaultAllocator: Allocator<usize, C> + Allocator<N, C>
+{
+    /// Computes the QR decomposition of the sparse matrix `m` with its columns ordered by
+    /// `CsMatrix::column_approximate_minimum_degree_ordering`.
+    pub fn new<S: CsStorage<N, R, C>>(m: &CsMatrix<N, R, C, S>) -> Self {
+        let perm = m.column_approximate_minimum_degree_ordering();
+        Self::new_with_permutation(m, &perm)
+    }
+
+    /// Computes the QR decomposition of the sparse matrix `m` with its columns permuted by the
+    /// fill-reducing ordering `perm`.
+    ///
+    /// The `k`-th column of the factorized matrix is the `perm[k]`-th column of `m`.
+    pub fn new_with_permutation<S: CsStorage<N, R, C>>(m: &CsMatrix<N, R, C, S>, perm: &[usize]) -> Self {
+        let (nrows, ncols) = m.shape();
+        assert!(
+            nrows >= ncols,
+            "Unable to compute the sparse QR decomposition of a matrix with more columns than rows."
+        );
+        assert_eq!(perm.len(), ncols, "Invalid permutation size.");
+
+        let mut seen: Vec<_> = iter::repeat(false).take(ncols).collect();
+        for j in perm {
+            assert!(*j < ncols && !seen[*j], "The ordering `perm` is not a permutation.");
+            seen[*j] = true;
+        }
+
+        let parent = column_elimination_tree(m, perm);
+        let (pinv, leftmost, nrows_v) = householder_row_permutation(m, perm, &parent);
+
+        // Numerical factorization.
+        let n = ncols;
+        let mut v_p = Vec::with_capacity(n + 1);
+        let mut v_i = Vec::with_capacity(m.len());
+        let mut v_vals = Vec::with_capacity(m.len());
+        let mut beta = Vec::with_capacity(n);
+        let mut r_p = Vec::with_capacity(n);
+        let mut r_i = Vec::with_capacity(m.len());
+        let mut r_vals = Vec::with_capacity(m.len());
+
+        // `w[i] == k + 1` iff the column `i < k` belongs to the pattern of the k-th column of `R`,
+        // or the row `i >= k` belongs to the pattern of the k-th Householder vector.
+        let mut w: Vec<_> = iter::repeat(0).take(nrows_v).collect();
+        let mut x: Vec<_> = iter::repeat(N::zero()).take(nrows_v).collect();
+        let mut stack = Vec::new();
+        let mut pattern = Vec::new();
+
+        v_p.push(0);
+
+        for k in 0..n {
+            let mark = k + 1;
+            r_p.push(r_i.len());
+            let v_start = v_i.len();
+            w[k] = mark;
+            v_i.push(k);
+            v_vals.push(N::zero());
+
+            // The pattern of the k-th column of `R` is given by the paths of the elimination tree
+            // from the leftmost columns of the rows of the k-th column of the matrix to `k`.
+            pattern.clear();
+
+            for (irow, val) in m.data.column_entries(perm[k]) {
+                let mut i = leftmost[irow];
+
+                while w[i] != mark {
+                    stack.push(i);
+                    w[i] = mark;
+                    i = parent[i];
+                }
+
+                while let Some(i) = stack.pop() {
+                    pattern.push(i);
+                }
+
+                let i = pinv[irow];
+                x[i] = val;
+
+                if i > k && w[i] != mark {
+                    v_i.push(i);
+                    v_vals.push(N::zero());
+                    w[i] = mark;
+                }
+            }
+
+            // Apply the previous Householder reflections in topological order.
+            for i in pattern.iter().rev().cloned() {
+                householder_apply(&v_i, &v_vals, v_p[i]..v_p[i + 1], beta[i], &mut x);
+                r_i.push(i);
+                r_vals.push(x[i]);
+                x[i] = N::zero();
+
+                if parent[i] == k {
+                    for p in v_p[i]..v_p[i + 1] {
+                        let row = v_i[p];
+
+                        if w[row] != mark {
+                            w[row] = mark;
+                            v_i.push(row);
+                            v_vals.push(N::zero());
+                        }
+                    }
+                }
+            }
+
+            for p in v_start..v_i.len() {
+                v_vals[p] = x[v_i[p]];
+                x[v_i[p]] = N::zero();
+            }
+
+            let (norm, b) = householder_vector(&mut v_vals[v_start..]);
+            beta.push(b);
+            r_i.push(k);
+            r_vals.push(norm);
+            v_p.push(v_i.len());
+        }
+
+        let (_, ncols_dim) = m.data.shape();
+        let mut r = CsMatrix::from_data(CsVecStorage {
+            shape: (ncols_dim, ncols_dim),
+            p: VectorN::from_iterator_generic(ncols_dim, U1, r_p.into_iter()),
+            i: r_i,
+            vals: r_vals,
+        });
+        r.sort();
+
+        CsQR {
+            v_p,
+            v_i,
+            v_vals,
+            beta,
+            r,
+            pinv,
+            q: perm.to_vec(),
+            nrows_v,
+            shape: m.data.shape(),
+        }
+    }
+
+    /// The upper-triangular factor `R`.
+    pub fn r(&self) -> &CsMatrix<N, C, C> {
+        &self.r
+    }
+
+    /// The column permutation: the `k`-th column of `R` corresponds to the `perm[k]`-th column of
+    /// the decomposed matrix.
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.q
+    }
+
+    /// Indicates if the decomposed matrix has full column rank, i.e., if `R` has no zero on its
+    /// diagonal.
+    pub fn is_full_rank(&self) -> bool {
+        (0..self.r.ncols()).all(|j| !self.r_diagonal(j).is_zero())
+    }
+
+    /// Computes the solution `x` minimizing `‖m * x - b‖` where `m` is the decomposed matrix.
+    ///
+    /// Returns `LinalgError::Singular` with the index of the first zero diagonal element of `R`
+    /// if the decomposed matrix does not have full column rank. This index refers to the
+    /// column-permuted matrix (see `.column_permutation()`).
+    pub fn solve_least_squares<C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R, C2, S2>,
+    ) -> Result<LeastSquaresSolution<N, C, C2>, LinalgError>
+    where
+        S2: Storage<N, R, C2>,
+        DefaultAllocator: Allocator<N, C, C2>,
+    {
+        assert_eq!(
+            self.shape.0.value(),
+            b.nrows(),
+            "Least squares: matrix dimension mismatch."
+        );
+
+        if let Some(pivot) = (0..self.r.ncols()).find(|j| self.r_diagonal(*j).is_zero()) {
+            return Err(LinalgError::Singular { pivot });
+        }
+
+        let n = self.r.ncols();
+        let mut solution = MatrixMN::zeros_generic(self.shape.1, b.data.shape().1);
+        let mut x: Vec<_> = iter::repeat(N::zero()).take(self.nrows_v).collect();
+        let mut residual_norm_squared = N::zero();
+
+        for j2 in 0..b.ncols() {
+            for val in x.iter_mut() {
+                *val = N::zero();
+            }
+
+            for (i, val) in b.column(j2).iter().enumerate() {
+                x[self.pinv[i]] = *val;
+            }
+
+            // Apply `H^T`.
+            for k in 0..n {
+                householder_apply(
+                    &self.v_i,
+                    &self.v_vals,
+                    self.v_p[k]..self.v_p[k + 1],
+                    self.beta[k],
+                    &mut x,
+                );
+            }
+
+            for val in &x[n..] {
+                residual_norm_squared += *val * *val;
+            }
+
+            // Backward substitution with the diagonal stored last.
+            for j in (0..n).rev() {
+                let range = self.r.data.column_range(j);
+                x[j] /= self.r.data.vals[range.end - 1];
+                let xj = x[j];
+
+                for p in range.start..range.end - 1 {
+                    x[self.r.data.i[p]] -= self.r.data.vals[p] * xj;
+                }
+            }
+
+            for (k, j) in self.q.iter().enumerate() {
+                solution[(*j, j2)] = x[k];
+            }
+        }
+
+        Ok(LeastSquaresSolution {
+            solution,
+            residual_norm: residual_norm_squared.sqrt(),
+            rank: n,
+        })
+    }
+
+    fn r_diagonal(&self, j: usize) -> N {
+        let range = self.r.data.column_range(j);
+        self.r.data.vals[range.end - 1]
+    }
+}
+
+impl<N: RealField, R: Dim, C: Dim, S: CsStorage<N, R, C>> CsMatrix<N, R, C, S>
+where DefaultAllocator: Allocator<usize, C> + Allocator<N, C>
+{
+    /// Computes the solution `x` minimizing `‖self * x - b‖` using the sparse QR decomposition of
+    /// this matrix, which must have at least as many rows as columns.
+    ///
+    /// Returns `LinalgError::Singular` if this matrix does not have full column rank.
+    pub fn solve_least_squares<C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R, C2, S2>,
+    ) -> Result<LeastSquaresSolution<N, C, C2>, LinalgError>
+    where
+        S2: Storage<N, R, C2>,
+        DefaultAllocator: Allocator<N, C, C2>,
+    {
+        CsQR::new(self).solve_least_squares(b)
+    }
+}
+
+// The elimination tree of `m^T * m` where the columns of `m` are permuted by `perm`, computed
+// without forming `m^T * m`.
+fn column_elimination_tree<N: RealField, R: Dim, C: Dim, S: CsStorage<N, R, C>>(
+    m: &CsMatrix<N, R, C, S>,
+    perm: &[usize],
+) -> Vec<usize>
+{
+    let (nrows, ncols) = m.shape();
+    let mut parent: Vec<_> = iter::repeat(usize::max_value()).take(ncols).collect();
+    let mut ancestor: Vec<_> = iter::repeat(usize::max_value()).take(ncols).collect();
+    // The last column seen so far on each row.
+    let mut prev: Vec<_> = iter::repeat(usize::max_value()).take(nrows).collect();
+
+    for (k, j) in perm.iter().enumerate() {
+        for irow in m.data.column_row_indices(*j) {
+            let mut i = prev[irow];
+
+            while i != usize::max_value() && i < k {
+                let i_ancestor = ancestor[i];
+                ancestor[i] = k;
+
+                if i_ancestor == usize::max_value() {
+                    parent[i] = k;
+                }
+
+                i = i_ancestor;
+            }
+
+            prev[irow] = k;
+        }
+    }
+
+    parent
+}
+
+// Computes the row permutation `pinv` making the Householder vectors lower-trapezoidal, the
+// leftmost column of each row, and the number of rows of the Householder vectors (including
+// the fictitious rows added for structurally rank-deficient matrices).
+fn householder_row_permutation<N: RealField, R: Dim, C: Dim, S: CsStorage<N, R, C>>(
+    m: &CsMatrix<N, R, C, S>,
+    perm: &[usize],
+    parent: &[usize],
+) -> (Vec<usize>, Vec<usize>, usize)
+{
+    let (nrows, ncols) = m.shape();
+    let mut leftmost: Vec<_> = iter::repeat(usize::max_value()).take(nrows).collect();
+
+    for (k, j) in perm.iter().enumerate().rev() {
+        for irow in m.data.column_row_indices(*j) {
+            leftmost[irow] = k;
+        }
+    }
+
+    // Queues of the rows whose leftmost column is `k`, as linked lists.
+    let mut head: Vec<_> = iter::repeat(usize::max_value()).take(ncols).collect();
+    let mut tail: Vec<_> = iter::repeat(usize::max_value()).take(ncols).collect();
+    let mut len: Vec<_> = iter::repeat(0).take(ncols).collect();
+    let mut next: Vec<_> = iter::repeat(usize::max_value()).take(nrows).collect();
+    let mut pinv: Vec<_> = iter::repeat(usize::max_value()).take(nrows + ncols).collect();
+
+    for i in (0..nrows).rev() {
+        let k = leftmost[i];
+
+        if k != usize::max_value() {
+            if len[k] == 0 {
+                tail[k] = i;
+            }
+
+            len[k] += 1;
+            next[i] = head[k];
+            head[k] = i;
+        }
+    }
+
+    let mut nrows_v = nrows;
+
+    for k in 0..ncols {
+        // The k-th Householder vector starts at the first row of the queue, or at a new
+        // fictitious row if it is empty.
+        let mut i = head[k];
+
+        if i == usize::max_value() {
+            i = nrows_v;
+            nrows_v += 1;
+        }
+
+        pinv[i] = k;
+
+        if len[k] <= 1 {
+            continue;
+        }
+
+        len[k] -= 1;
+
+        // The remaining rows of the queue move to the parent column.
+        let pa = parent[k];
+
+        if pa != usize::max_value() {
+            if len[pa] == 0 {
+                tail[pa] = tail[k];
+            }
+
+            next[tail[k]] = head[pa];
+            head[pa] = next[i];
+            len[pa] += len[k];
+        }
+    }
+
+    let mut k = ncols;
+    for i in 0..nrows {
+        if pinv[i] == usize::max_value() {
+            pinv[i] = k;
+            k += 1;
+        }
+    }
+
+    pinv.truncate(nrows);
+    (pinv, leftmost, nrows_v)
+}
+
+// Computes in-place the Householder vector `v` and coefficient `beta` such that
+// `(I - beta * v * v^T) * x = [norm(x), 0, ..., 0]^T`. Returns `(norm(x), beta)`.
+fn householder_vector<N: RealField>(x: &mut [N]) -> (N, N) {
+    let mut sigma = N::zero();
+
+    for xi in &x[1..] {
+        sigma += *xi * *xi;
+    }
+
+    if sigma.is_zero() {
+        let norm = x[0].abs();
+        let beta = if x[0] <= N::zero() {
+            crate::convert(2.0)
+        } else {
+            N::zero()
+        };
+        x[0] = N::one();
+        (norm, beta)
+    } else {
+        let norm = (x[0] * x[0] + sigma).sqrt();
+        x[0] = if x[0] <= N::zero() {
+            x[0] - norm
+        } else {
+            -sigma / (x[0] + norm)
+        };
+        let beta = -N::one() / (norm * x[0]);
+        (norm, beta)
+    }
+}
+
+// Applies the Householder reflection stored in the entries `range` of `v_i` and `v_vals` to `x`.
+fn householder_apply<N: RealField>(
+    v_i: &[usize],
+    v_vals: &[N],
+    range: Range<usize>,
+    beta: N,
+    x: &mut [N],
+)
+{
+    let mut tau = N::zero();
+
+    for p in range.clone() {
+        tau += v_vals[p] * x[v_i[p]];
+    }
+
+    tau *= beta;
+
+    for p in range {
+        x[v_i[p]] -= v_vals[p] * tau;
+    }
+}
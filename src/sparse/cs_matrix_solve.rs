@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::allocator::Allocator;
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::sparse::{CsMatrix, CsStorage, CsVector};
@@ -52,7 +54,7 @@ impl<N: RealField, D: Dim, S: CsStorage<N, D, D>> CsMatrix<N, D, D, S> {
     {
         let (nrows, ncols) = self.data.shape();
         assert_eq!(nrows.value(), ncols.value(), "The matrix must be square.");
-        assert_eq!(nrows.value(), b.len(), "Mismatched matrix dimensions.");
+        assert_eq!(nrows.value(), b.nrows(), "Mismatched matrix dimensions.");
 
         for j2 in 0..b.ncols() {
             let mut b = b.column_mut(j2);
@@ -98,7 +100,7 @@ impl<N: RealField, D: Dim, S: CsStorage<N, D, D>> CsMatrix<N, D, D, S> {
     {
         let (nrows, ncols) = self.data.shape();
         assert_eq!(nrows.value(), ncols.value(), "The matrix must be square.");
-        assert_eq!(nrows.value(), b.len(), "Mismatched matrix dimensions.");
+        assert_eq!(nrows.value(), b.nrows(), "Mismatched matrix dimensions.");
 
         for j2 in 0..b.ncols() {
             let mut b = b.column_mut(j2);
@@ -196,54 +198,6 @@ impl<N: RealField, D: Dim, S: CsStorage<N, D, D>> CsMatrix<N, D, D, S> {
         Some(result)
     }
 
-    /*
-    // Computes the reachable, post-ordered, nodes from `b`.
-    fn lower_triangular_reach_postordered<D2: Dim, S2>(
-        &self,
-        b: &CsVector<N, D2, S2>,
-        xi: &mut Vec<usize>,
-    ) where
-        S2: CsStorage<N, D2>,
-        DefaultAllocator: Allocator<bool, D>,
-    {
-        let mut visited = VectorN::repeat_generic(self.data.shape().1, U1, false);
-        let mut stack = Vec::new();
-
-        for i in b.data.column_range(0) {
-            let row_index = b.data.row_index(i);
-
-            if !visited[row_index] {
-                let rng = self.data.column_range(row_index);
-                stack.push((row_index, rng));
-                self.lower_triangular_dfs(visited.as_mut_slice(), &mut stack, xi);
-            }
-        }
-    }
-
-    fn lower_triangular_dfs(
-        &self,
-        visited: &mut [bool],
-        stack: &mut Vec<(usize, Range<usize>)>,
-        xi: &mut Vec<usize>,
-    )
-    {
-        'recursion: while let Some((j, rng)) = stack.pop() {
-            visited[j] = true;
-
-            for i in rng.clone() {
-                let row_id = self.data.row_index(i);
-                if row_id > j && !visited[row_id] {
-                    stack.push((j, (i + 1)..rng.end));
-                    stack.push((row_id, self.data.column_range(row_id)));
-                    continue 'recursion;
-                }
-            }
-
-            xi.push(j)
-        }
-    }
-    */
-
     // Computes the nodes reachable from `b` in an arbitrary order.
     fn lower_triangular_reach<D2: Dim, S2>(&self, b: &CsVector<N, D2, S2>, xi: &mut Vec<usize>)
     where
@@ -284,3 +238,54 @@ impl<N: RealField, D: Dim, S: CsStorage<N, D, D>> CsMatrix<N, D, D, S> {
         }
     }
 }
+
+// Appends to `xi`, in post-order (i.e. in reverse topological order), the nodes not yet
+// `visited` that are reachable from `start` in the graph of a lower-triangular matrix given by
+// its column pointers `p` (including the final sentinel) and row indices `i`.
+//
+// If a row permutation `pinv` is given, the node `irow` corresponds to the column `pinv[irow]`,
+// and nodes with `pinv[irow] == usize::max_value()` have no outgoing edges. The elements of
+// `xi` must have their `visited` flag reset before the next traversal of a different graph.
+pub(crate) fn lower_triangular_dfs(
+    start: usize,
+    p: &[usize],
+    i: &[usize],
+    pinv: Option<&[usize]>,
+    visited: &mut [bool],
+    stack: &mut Vec<(usize, Range<usize>)>,
+    xi: &mut Vec<usize>,
+)
+{
+    let column_range = |irow: usize| {
+        let j = pinv.map_or(irow, |pinv| pinv[irow]);
+
+        if j == usize::max_value() {
+            0..0
+        } else {
+            p[j]..p[j + 1]
+        }
+    };
+
+    if visited[start] {
+        return;
+    }
+
+    stack.clear();
+    stack.push((start, column_range(start)));
+
+    'recursion: while let Some((j, rng)) = stack.pop() {
+        visited[j] = true;
+
+        for k in rng.clone() {
+            let row_id = i[k];
+
+            if !visited[row_id] {
+                stack.push((j, (k + 1)..rng.end));
+                stack.push((row_id, column_range(row_id)));
+                continue 'recursion;
+            }
+        }
+
+        xi.push(j)
+    }
+}
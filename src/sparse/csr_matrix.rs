@@ -0,0 +1,175 @@
+use num::Zero;
+use std::ops::Range;
+
+use crate::allocator::Allocator;
+use crate::sparse::{CsMatrix, CsStorage, CsStorageIter};
+use crate::storage::Storage;
+use crate::{DefaultAllocator, Dim, Dynamic, Matrix, MatrixMN, Scalar};
+
+/// A compressed sparse row matrix.
+///
+/// This is the row-major counterpart of `CsMatrix`, giving efficient access to the entries of
+/// each row. It is stored as the compressed sparse column representation of its transpose, so
+/// converting between both formats amounts to a transposition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<N: Scalar, R: Dim = Dynamic, C: Dim = Dynamic>
+where DefaultAllocator: Allocator<usize, R>
+{
+    transposed: CsMatrix<N, C, R>,
+}
+
+impl<N: Scalar, R: Dim, C: Dim> CsrMatrix<N, R, C>
+where DefaultAllocator: Allocator<usize, R>
+{
+    /// Creates the compressed sparse row matrix whose transpose is the compressed sparse column
+    /// matrix `m`.
+    ///
+    /// This does not copy or move any entry of `m`.
+    #[inline]
+    pub fn from_transposed_csc(m: CsMatrix<N, C, R>) -> Self {
+        CsrMatrix { transposed: m }
+    }
+
+    /// Converts this matrix into the compressed sparse column representation of its transpose.
+    ///
+    /// This does not copy or move any entry of `self`.
+    #[inline]
+    pub fn into_transposed_csc(self) -> CsMatrix<N, C, R> {
+        self.transposed
+    }
+
+    /// The compressed sparse column representation of the transpose of this matrix.
+    #[inline]
+    pub fn transposed_csc(&self) -> &CsMatrix<N, C, R> {
+        &self.transposed
+    }
+
+    /// Converts this matrix into a compressed sparse column matrix.
+    pub fn to_csc(&self) -> CsMatrix<N, R, C>
+    where DefaultAllocator: Allocator<usize, C> {
+        self.transposed.transpose()
+    }
+
+    /// Computes the transpose of this matrix.
+    pub fn transpose(&self) -> CsrMatrix<N, C, R>
+    where DefaultAllocator: Allocator<usize, C> {
+        CsrMatrix::from_transposed_csc(self.to_csc())
+    }
+
+    /// The size of the data buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.transposed.len()
+    }
+
+    /// The number of rows of this matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.transposed.ncols()
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.transposed.nrows()
+    }
+
+    /// The shape of this matrix.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    /// Whether this matrix is square or not.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.transposed.is_square()
+    }
+
+    /// Checks that the column indices of each row of this matrix are sorted.
+    ///
+    /// This should always return `true`, see `CsMatrix::is_sorted`.
+    pub fn is_sorted(&self) -> bool {
+        self.transposed.is_sorted()
+    }
+
+    /// The offsets of each row in the column index and value buffers.
+    #[inline]
+    pub fn row_offsets(&self) -> &[usize] {
+        self.transposed.data.p()
+    }
+
+    /// The column index buffer.
+    #[inline]
+    pub fn col_indices(&self) -> &[usize] {
+        self.transposed.data.i()
+    }
+
+    /// The value buffer.
+    #[inline]
+    pub fn values(&self) -> &[N] {
+        self.transposed.data.values()
+    }
+
+    /// The range of indices of the entries of the `i`-th row in the column index and value
+    /// buffers.
+    #[inline]
+    pub fn row_range(&self, i: usize) -> Range<usize> {
+        self.transposed.data.column_range(i)
+    }
+
+    /// Iterates through the entries of the `i`-th row, given as `(col_index, value)`.
+    #[inline]
+    pub fn row_entries<'a>(&'a self, i: usize) -> impl Iterator<Item = (usize, N)> + 'a {
+        self.transposed.data.column_entries(i)
+    }
+
+    /// Iterates through the column indices of the entries of the `i`-th row.
+    #[inline]
+    pub fn row_col_indices<'a>(&'a self, i: usize) -> impl Iterator<Item = usize> + 'a {
+        self.transposed.data.column_row_indices(i)
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> From<CsMatrix<N, R, C>> for CsrMatrix<N, R, C>
+where DefaultAllocator: Allocator<usize, R> + Allocator<usize, C>
+{
+    fn from(m: CsMatrix<N, R, C>) -> Self {
+        CsrMatrix::from_transposed_csc(m.transpose())
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> From<CsrMatrix<N, R, C>> for CsMatrix<N, R, C>
+where DefaultAllocator: Allocator<usize, R> + Allocator<usize, C>
+{
+    fn from(m: CsrMatrix<N, R, C>) -> Self {
+        m.to_csc()
+    }
+}
+
+impl<N: Scalar + Zero, R: Dim, C: Dim> From<CsrMatrix<N, R, C>> for MatrixMN<N, R, C>
+where DefaultAllocator: Allocator<usize, R> + Allocator<N, R, C>
+{
+    fn from(m: CsrMatrix<N, R, C>) -> Self {
+        let (nrows, ncols) = m.transposed.data.shape();
+        let mut res = MatrixMN::zeros_generic(ncols, nrows);
+
+        for i in 0..m.nrows() {
+            for (j, val) in m.row_entries(i) {
+                res[(i, j)] = val;
+            }
+        }
+
+        res
+    }
+}
+
+impl<N: Scalar + Zero, R: Dim, C: Dim, S> From<Matrix<N, R, C, S>> for CsrMatrix<N, R, C>
+where
+    S: Storage<N, R, C>,
+    DefaultAllocator: Allocator<usize, R> + Allocator<N, C, R>,
+{
+    fn from(m: Matrix<N, R, C, S>) -> Self {
+        CsrMatrix::from_transposed_csc(m.transpose().into())
+    }
+}
@@ -1,13 +1,26 @@
 //! Sparse matrices.
 
+pub use self::coo_matrix::CooMatrix;
 pub use self::cs_matrix::{
     CsMatrix, CsStorage, CsStorageIter, CsStorageIterMut, CsStorageMut, CsVecStorage, CsVector,
 };
 pub use self::cs_matrix_cholesky::CsCholesky;
+pub use self::cs_matrix_iterative::{CsIncompleteCholesky, CsIncompleteLU};
+pub use self::cs_matrix_lu::CsLU;
+pub use self::cs_matrix_qr::CsQR;
+pub use self::csr_matrix::CsrMatrix;
 
+mod coo_matrix;
 mod cs_matrix;
 mod cs_matrix_cholesky;
 mod cs_matrix_conversion;
+mod cs_matrix_edition;
+mod cs_matrix_eigen;
+mod cs_matrix_iterative;
+mod cs_matrix_lu;
 mod cs_matrix_ops;
+mod cs_matrix_ordering;
+mod cs_matrix_qr;
 mod cs_matrix_solve;
+mod csr_matrix;
 pub(crate) mod cs_utils;
@@ -756,6 +756,21 @@ fn partial_cmp() {
     assert_eq!(a.partial_cmp(&d), None);
 }
 
+#[test]
+fn induced_norms() {
+    let m = Matrix2x3::new(
+        1.0, -2.0, 3.0,
+        -4.0, 5.0, -6.0);
+
+    assert_eq!(m.induced_norm_1(), 9.0);
+    assert_eq!(m.induced_norm_inf(), 15.0);
+    assert_eq!(m.transpose().induced_norm_1(), m.induced_norm_inf());
+
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    assert_eq!(v.induced_norm_1(), v.lp_norm(1));
+    assert_eq!(v.induced_norm_inf(), v.apply_norm(&na::UniformNorm));
+}
+
 #[test]
 fn swizzle() {
     let a = Vector2::new(1.0f32, 2.0);
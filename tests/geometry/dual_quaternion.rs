@@ -0,0 +1,120 @@
+#![cfg(feature = "arbitrary")]
+#![allow(non_snake_case)]
+
+use na::{
+    DualQuaternion, Isometry3, Point3, Translation3, UnitDualQuaternion, UnitQuaternion, Vector3,
+};
+
+quickcheck!(
+    fn isometry_equivalence(iso: Isometry3<f64>, p: Point3<f64>, v: Vector3<f64>) -> bool {
+        let dq = UnitDualQuaternion::from_isometry(&iso);
+
+        relative_eq!(dq.to_isometry(), iso, epsilon = 1.0e-7)
+            && relative_eq!(dq * p, iso * p, epsilon = 1.0e-7)
+            && relative_eq!(dq * v, iso * v, epsilon = 1.0e-7)
+            && relative_eq!(dq.to_homogeneous(), iso.to_homogeneous(), epsilon = 1.0e-7)
+    }
+
+    fn composition(
+        iso1: Isometry3<f64>,
+        iso2: Isometry3<f64>,
+        r: UnitQuaternion<f64>,
+        t: Translation3<f64>
+    ) -> bool {
+        let dq1 = UnitDualQuaternion::from_isometry(&iso1);
+        let dq2 = UnitDualQuaternion::from_isometry(&iso2);
+
+        relative_eq!((dq1 * dq2).to_isometry(), iso1 * iso2, epsilon = 1.0e-7)
+            && relative_eq!((dq1 / dq2).to_isometry(), iso1 / iso2, epsilon = 1.0e-7)
+            && relative_eq!((dq1 * r).to_isometry(), iso1 * r, epsilon = 1.0e-7)
+            && relative_eq!((r * dq1).to_isometry(), r * iso1, epsilon = 1.0e-7)
+            && relative_eq!((dq1 * t).to_isometry(), iso1 * t, epsilon = 1.0e-7)
+            && relative_eq!((t * dq1).to_isometry(), t * iso1, epsilon = 1.0e-7)
+            && relative_eq!(dq1 * iso2, UnitDualQuaternion::from_isometry(&(iso1 * iso2)), epsilon = 1.0e-7)
+            && relative_eq!(iso1 * dq2, UnitDualQuaternion::from_isometry(&(iso1 * iso2)), epsilon = 1.0e-7)
+    }
+
+    fn inverse_is_identity(dq: UnitDualQuaternion<f64>, p: Point3<f64>, v: Vector3<f64>) -> bool {
+        let ii = dq.inverse();
+
+        relative_eq!(dq * ii, UnitDualQuaternion::identity(), epsilon = 1.0e-7)
+            && relative_eq!(ii * dq, UnitDualQuaternion::identity(), epsilon = 1.0e-7)
+            && relative_eq!((dq * ii) * p, p, epsilon = 1.0e-7)
+            && relative_eq!((ii * dq) * v, v, epsilon = 1.0e-7)
+            && relative_eq!(dq.inverse_transform_point(&(dq * p)), p, epsilon = 1.0e-7)
+            && relative_eq!(dq.inverse_transform_vector(&(dq * v)), v, epsilon = 1.0e-7)
+    }
+
+    fn multiply_equals_alga_transform(dq: UnitDualQuaternion<f64>, v: Vector3<f64>, p: Point3<f64>) -> bool {
+        use alga::linear::{ProjectiveTransformation, Transformation};
+
+        dq * v == Transformation::transform_vector(&dq, &v)
+            && dq * p == Transformation::transform_point(&dq, &p)
+            && relative_eq!(
+                dq.inverse() * v,
+                ProjectiveTransformation::inverse_transform_vector(&dq, &v),
+                epsilon = 1.0e-7
+            )
+            && relative_eq!(
+                dq.inverse() * p,
+                ProjectiveTransformation::inverse_transform_point(&dq, &p),
+                epsilon = 1.0e-7
+            )
+    }
+
+    fn normalize_gives_unit(real: UnitQuaternion<f64>, dual: na::Quaternion<f64>, s: f64) -> bool {
+        let s = s.abs() + 0.1;
+        let dq = DualQuaternion::from_real_and_dual(real.into_inner() * s, dual);
+        let normalized = dq.normalize();
+
+        relative_eq!(normalized.real.norm(), 1.0, epsilon = 1.0e-7)
+            && relative_eq!(normalized.real.dot(&normalized.dual), 0.0, epsilon = 1.0e-7)
+    }
+
+    fn sclerp_endpoints(dq1: UnitDualQuaternion<f64>, dq2: UnitDualQuaternion<f64>) -> bool {
+        match (dq1.try_sclerp(&dq2, 0.0, 1.0e-6), dq1.try_sclerp(&dq2, 1.0, 1.0e-6)) {
+            (Some(start), Some(end)) => {
+                relative_eq!(start, dq1, epsilon = 1.0e-7) && relative_eq!(end, dq2, epsilon = 1.0e-7)
+            }
+            _ => true,
+        }
+    }
+
+    fn sclerp_screw_motion(axis: Vector3<f64>, angle: f64, pitch: f64, t: f64) -> bool {
+        let angle = angle % 3.0;
+        let t = t.fract().abs();
+        let axis = match na::Unit::try_new(axis, 1.0e-3) {
+            Some(axis) => axis,
+            None => return true,
+        };
+
+        // A screw motion along `axis` with the given pitch interpolates to the same screw
+        // motion with a scaled angle and translation.
+        let screw = |s: f64| {
+            UnitDualQuaternion::from_parts(
+                Translation3::from(axis.into_inner() * (pitch * s)),
+                UnitQuaternion::from_axis_angle(&axis, angle * s),
+            )
+        };
+
+        relative_eq!(
+            UnitDualQuaternion::identity().sclerp(&screw(1.0), t),
+            screw(t),
+            epsilon = 1.0e-7
+        )
+    }
+
+    fn conversions(iso: Isometry3<f64>, r: UnitQuaternion<f64>, t: Translation3<f64>) -> bool {
+        let dq: UnitDualQuaternion<f64> = iso.into();
+        let iso2: Isometry3<f64> = dq.into();
+        let dqr: UnitDualQuaternion<f64> = r.into();
+        let dqt: UnitDualQuaternion<f64> = t.into();
+        let iso_f32: Isometry3<f32> = na::convert(dq);
+
+        relative_eq!(iso2, iso, epsilon = 1.0e-7)
+            && relative_eq!(dqr.rotation(), r, epsilon = 1.0e-7)
+            && relative_eq!(dqr.translation(), Translation3::identity(), epsilon = 1.0e-7)
+            && relative_eq!(dqt.translation(), t, epsilon = 1.0e-7)
+            && relative_eq!(iso_f32, na::convert(iso), epsilon = 1.0e-3)
+    }
+);
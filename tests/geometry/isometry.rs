@@ -2,8 +2,8 @@
 #![allow(non_snake_case)]
 
 use na::{
-    Isometry2, Isometry3, Point2, Point3, Rotation2, Rotation3, Translation2, Translation3,
-    UnitComplex, UnitQuaternion, Vector2, Vector3,
+    Isometry2, Isometry3, Matrix3, Matrix6, Point2, Point3, Rotation2, Rotation3, Translation2,
+    Translation3, UnitComplex, UnitQuaternion, Vector2, Vector3, Vector6,
 };
 
 quickcheck!(
@@ -263,4 +263,172 @@ quickcheck!(
             && uqMt == uq * &t
             && uqMt == &uq * t
     }
+
+    fn exp_ln_2(iso: Isometry2<f64>, twist: Vector3<f64>) -> bool {
+        let twist = Vector3::new(twist.x, twist.y, twist.z % 3.0);
+
+        relative_eq!(Isometry2::exp(&iso.ln()), iso, epsilon = 1.0e-7)
+            && relative_eq!(Isometry2::exp(&twist).ln(), twist, epsilon = 1.0e-7)
+            && relative_eq!(
+                Isometry2::exp(&twist).to_homogeneous(),
+                Isometry2::hat(&twist).exp(),
+                epsilon = 1.0e-7
+            )
+            && Isometry2::vee(&Isometry2::hat(&twist)) == twist
+    }
+
+    fn exp_ln_3(iso: Isometry3<f64>, twist: Vector6<f64>) -> bool {
+        let twist = bounded_angle(twist);
+
+        relative_eq!(Isometry3::exp(&iso.ln()), iso, epsilon = 1.0e-7)
+            && relative_eq!(Isometry3::exp(&twist).ln(), twist, epsilon = 1.0e-7)
+            && relative_eq!(
+                Isometry3::exp(&twist).to_homogeneous(),
+                Isometry3::hat(&twist).exp(),
+                epsilon = 1.0e-7
+            )
+            && Isometry3::vee(&Isometry3::hat(&twist)) == twist
+    }
+
+    fn adjoint_2(iso: Isometry2<f64>, twist: Vector3<f64>) -> bool {
+        relative_eq!(
+            Isometry2::exp(&(iso.adjoint() * twist)),
+            iso * Isometry2::exp(&twist) * iso.inverse(),
+            epsilon = 1.0e-7
+        )
+    }
+
+    fn adjoint_3(iso: Isometry3<f64>, twist: Vector6<f64>) -> bool {
+        relative_eq!(
+            Isometry3::exp(&(iso.adjoint() * twist)),
+            iso * Isometry3::exp(&twist) * iso.inverse(),
+            epsilon = 1.0e-7
+        ) && relative_eq!(
+            (iso * iso).adjoint(),
+            iso.adjoint() * iso.adjoint(),
+            epsilon = 1.0e-7
+        )
+    }
+
+    fn jacobians_2(twist: Vector3<f64>) -> bool {
+        let twist = Vector3::new(twist.x, twist.y, twist.z % 3.0);
+        let h = 1.0e-6;
+        let iso = Isometry2::exp(&twist);
+        let mut left = Matrix3::zeros();
+        let mut right = Matrix3::zeros();
+
+        for i in 0..3 {
+            let mut dt = Vector3::zeros();
+            dt[i] = h;
+            let plus = Isometry2::exp(&(twist + dt));
+            let minus = Isometry2::exp(&(twist - dt));
+
+            left.set_column(i, &(((plus / iso).ln() - (minus / iso).ln()) / (2.0 * h)));
+            right.set_column(
+                i,
+                &(((iso.inverse() * plus).ln() - (iso.inverse() * minus).ln()) / (2.0 * h)),
+            );
+        }
+
+        let eps = 1.0e-6 * (1.0 + twist.norm());
+        relative_eq!(Isometry2::left_jacobian(&twist), left, epsilon = eps)
+            && relative_eq!(Isometry2::right_jacobian(&twist), right, epsilon = eps)
+            && relative_eq!(
+                Isometry2::left_jacobian(&twist) * Isometry2::left_jacobian_inverse(&twist),
+                Matrix3::identity(),
+                epsilon = 1.0e-7
+            )
+            && relative_eq!(
+                Isometry2::right_jacobian(&twist) * Isometry2::right_jacobian_inverse(&twist),
+                Matrix3::identity(),
+                epsilon = 1.0e-7
+            )
+    }
+
+    fn jacobians_3(twist: Vector6<f64>) -> bool {
+        let twist = bounded_angle(twist);
+        let h = 1.0e-6;
+        let iso = Isometry3::exp(&twist);
+        let mut left = Matrix6::zeros();
+        let mut right = Matrix6::zeros();
+
+        for i in 0..6 {
+            let mut dt = Vector6::zeros();
+            dt[i] = h;
+            let plus = Isometry3::exp(&(twist + dt));
+            let minus = Isometry3::exp(&(twist - dt));
+
+            left.set_column(i, &(((plus / iso).ln() - (minus / iso).ln()) / (2.0 * h)));
+            right.set_column(
+                i,
+                &(((iso.inverse() * plus).ln() - (iso.inverse() * minus).ln()) / (2.0 * h)),
+            );
+        }
+
+        let eps = 1.0e-6 * (1.0 + twist.norm());
+        relative_eq!(Isometry3::left_jacobian(&twist), left, epsilon = eps)
+            && relative_eq!(Isometry3::right_jacobian(&twist), right, epsilon = eps)
+            && relative_eq!(
+                Isometry3::left_jacobian(&twist) * Isometry3::left_jacobian_inverse(&twist),
+                Matrix6::identity(),
+                epsilon = 1.0e-7
+            )
+            && relative_eq!(
+                Isometry3::right_jacobian(&twist) * Isometry3::right_jacobian_inverse(&twist),
+                Matrix6::identity(),
+                epsilon = 1.0e-7
+            )
+    }
+
+    fn mean_3(r: UnitQuaternion<f64>, ts: Vec<Translation3<f64>>, w: f64) -> bool {
+        let w = w.abs() + 0.1;
+        let isometries: Vec<_> = ts.iter().map(|t| Isometry3::from_parts(*t, r)).collect();
+        let weights: Vec<_> = (0..ts.len()).map(|i| if i == 0 { w } else { 1.0 }).collect();
+
+        match Isometry3::mean(&isometries, Some(&weights)) {
+            Some(mean) => {
+                let sum = ts
+                    .iter()
+                    .zip(weights.iter())
+                    .fold(Vector3::zeros(), |acc, (t, w)| acc + t.vector * *w);
+                let total = weights.iter().sum::<f64>();
+
+                relative_eq!(mean.rotation, r, epsilon = 1.0e-7)
+                    && relative_eq!(mean.translation.vector, sum / total, epsilon = 1.0e-7)
+            }
+            None => ts.is_empty(),
+        }
+    }
 );
+
+// Rescales the rotational part of `twist` so that its angle is smaller than `pi`.
+fn bounded_angle(twist: Vector6<f64>) -> Vector6<f64> {
+    let w = Vector3::new(twist[3], twist[4], twist[5]);
+    let angle = w.norm();
+
+    if angle > 3.0 {
+        let w = w * ((angle % 3.0) / angle);
+        Vector6::new(twist[0], twist[1], twist[2], w.x, w.y, w.z)
+    } else {
+        twist
+    }
+}
+
+#[test]
+fn jacobians_small_angles() {
+    // The series expansions used for small angles must agree with the closed-form expressions.
+    let axis = Vector3::new(1.0, -2.0, 0.5).normalize();
+
+    for angle in &[0.0, 1.0e-12, 1.0e-6, 1.0e-3, 0.02, 0.03, 0.1] {
+        let w = axis * *angle;
+        let twist = Vector6::new(1.0, 2.0, -3.0, w.x, w.y, w.z);
+        let jac = Isometry3::left_jacobian(&twist) * Isometry3::left_jacobian_inverse(&twist);
+        assert_relative_eq!(jac, Matrix6::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(Isometry3::exp(&twist).ln(), twist, epsilon = 1.0e-12);
+
+        let twist = Vector3::new(1.0, 2.0, *angle);
+        let jac = Isometry2::left_jacobian(&twist) * Isometry2::left_jacobian_inverse(&twist);
+        assert_relative_eq!(jac, Matrix3::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(Isometry2::exp(&twist).ln(), twist, epsilon = 1.0e-12);
+    }
+}
@@ -1,7 +1,9 @@
+mod dual_quaternion;
 mod isometry;
 mod point;
 mod projection;
 mod quaternion;
+mod registration;
 mod rotation;
 mod similarity;
 mod unit_complex;
@@ -113,6 +113,24 @@ quickcheck!(
         mq == q && mq.angle() == q.angle() && mq.axis() == q.axis()
     }
 
+    /*
+     *
+     * Unit quaternion averaging.
+     *
+     */
+    fn unit_quaternion_mean(q1: UnitQuaternion<f64>, q2: UnitQuaternion<f64>, w: f64) -> bool {
+        let w = w.abs() + 0.1;
+        let mean = UnitQuaternion::mean(&[q1, q2], None).unwrap();
+        let mean_of_same = UnitQuaternion::mean(&[q1, -q1, q1], Some(&[w, 1.0, 0.0])).unwrap();
+
+        relative_eq!(mean_of_same.into_inner(), q1.into_inner(), epsilon = 1.0e-7)
+            && relative_eq!(mean, UnitQuaternion::mean(&[q1, -q2], None).unwrap(), epsilon = 1.0e-7)
+            && (q1.angle_to(&q2) > 3.0 || relative_eq!(mean, q1.slerp(&q2, 0.5), epsilon = 1.0e-7))
+            && UnitQuaternion::<f64>::mean(&[], None).is_none()
+            && UnitQuaternion::mean(&[q1, q2], Some(&[w])).is_none()
+            && UnitQuaternion::mean(&[q1, q2], Some(&[w, -1.0])).is_none()
+    }
+
     // Test that all operators (incl. all combinations of references) work.
     // See the top comment on `geometry/quaternion_ops.rs` for details on which operations are
     // supported.
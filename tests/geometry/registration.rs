@@ -0,0 +1,140 @@
+#![cfg(feature = "arbitrary")]
+
+use na::{
+    Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, Matrix3, Point2, Point3, Similarity3,
+    SimilarityMatrix3, UnitQuaternion, Vector3,
+};
+
+fn points3() -> Vec<Point3<f64>> {
+    vec![
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(-1.0, 0.0, 1.0),
+        Point3::new(0.0, 4.0, -2.0),
+        Point3::new(3.0, -1.0, 0.5),
+        Point3::new(-2.0, -3.0, -1.0),
+    ]
+}
+
+fn points2() -> Vec<Point2<f64>> {
+    vec![
+        Point2::new(1.0, 2.0),
+        Point2::new(-1.0, 0.0),
+        Point2::new(0.0, 4.0),
+        Point2::new(3.0, -1.0),
+    ]
+}
+
+#[test]
+fn fit_never_returns_reflection() {
+    let from = points3();
+    let mirror = Matrix3::new(-1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    let to: Vec<_> = from.iter().map(|p| Point3::from(mirror * p.coords)).collect();
+
+    let (rot, rmsd) = na::fit_rotation(&from, &to, None).unwrap();
+    let (iso, _) = na::fit_isometry(&from, &to, None).unwrap();
+    let (sim, _) = na::fit_similarity(&from, &to, None).unwrap();
+
+    assert_relative_eq!(rot.matrix().determinant(), 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(iso.rotation.matrix().determinant(), 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(sim.isometry.rotation.matrix().determinant(), 1.0, epsilon = 1.0e-7);
+    assert!(sim.scaling() > 0.0);
+    assert!(rmsd > 1.0e-3);
+}
+
+#[test]
+fn fit_zero_weights_ignore_outliers() {
+    let iso = IsometryMatrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let mut from = points3();
+    let mut to: Vec<_> = from.iter().map(|p| iso * p).collect();
+    let mut weights = vec![2.0, 1.0, 0.5, 1.0, 3.0];
+
+    from.push(Point3::new(10.0, 10.0, 10.0));
+    to.push(Point3::new(-50.0, 20.0, 0.0));
+    weights.push(0.0);
+
+    let (fitted, rmsd) = na::fit_isometry(&from, &to, Some(&weights)).unwrap();
+    assert_relative_eq!(fitted, iso, epsilon = 1.0e-7);
+    assert_relative_eq!(rmsd, 0.0, epsilon = 1.0e-7);
+
+    let (_, rmsd) = na::fit_isometry(&from, &to, None).unwrap();
+    assert!(rmsd > 1.0);
+}
+
+#[test]
+fn fit_invalid_inputs() {
+    let from = points3();
+    let to = points3();
+    let no_points: [Point3<f64>; 0] = [];
+
+    assert!(na::fit_rotation(&no_points, &no_points, None).is_none());
+    assert!(na::fit_isometry(&from, &to[..4], None).is_none());
+    assert!(na::fit_isometry(&from, &to, Some(&[1.0; 4])).is_none());
+    assert!(na::fit_isometry(&from, &to, Some(&[1.0, 1.0, -1.0, 1.0, 1.0])).is_none());
+    assert!(na::fit_isometry(&from, &to, Some(&[0.0; 5])).is_none());
+    assert!(na::fit_similarity(&[from[0]; 3], &to[..3], None).is_none());
+}
+
+quickcheck!(
+    fn fit_rotation_3(r: UnitQuaternion<f64>) -> bool {
+        let rot = r.to_rotation_matrix();
+        let from = points3();
+        let to: Vec<_> = from.iter().map(|p| rot * p).collect();
+        let (fitted, rmsd) = na::fit_rotation(&from, &to, None).unwrap();
+
+        relative_eq!(fitted, rot, epsilon = 1.0e-7) && relative_eq!(rmsd, 0.0, epsilon = 1.0e-7)
+    }
+
+    fn fit_isometry_2(iso: Isometry2<f64>) -> bool {
+        let iso: IsometryMatrix2<f64> = na::convert(iso);
+        let from = points2();
+        let to: Vec<_> = from.iter().map(|p| iso * p).collect();
+        let (fitted, rmsd) = na::fit_isometry(&from, &to, None).unwrap();
+
+        relative_eq!(fitted, iso, epsilon = 1.0e-7) && relative_eq!(rmsd, 0.0, epsilon = 1.0e-7)
+    }
+
+    fn fit_isometry_3(iso: Isometry3<f64>) -> bool {
+        let iso: IsometryMatrix3<f64> = na::convert(iso);
+        let from = points3();
+        let to: Vec<_> = from.iter().map(|p| iso * p).collect();
+        let (fitted, rmsd) = na::fit_isometry(&from, &to, None).unwrap();
+
+        relative_eq!(fitted, iso, epsilon = 1.0e-7) && relative_eq!(rmsd, 0.0, epsilon = 1.0e-7)
+    }
+
+    fn fit_similarity_3(sim: Similarity3<f64>) -> bool {
+        let sim = SimilarityMatrix3::from_isometry(na::convert(sim.isometry), sim.scaling().abs() + 0.1);
+        let from = points3();
+        let to: Vec<_> = from.iter().map(|p| sim * p).collect();
+        let (fitted, rmsd) = na::fit_similarity(&from, &to, None).unwrap();
+
+        relative_eq!(fitted, sim, epsilon = 1.0e-7) && relative_eq!(rmsd, 0.0, epsilon = 1.0e-7)
+    }
+
+    fn fit_rmsd_is_minimal(iso: Isometry3<f64>, noise: Vec<Vector3<f64>>, weights: Vec<f64>) -> bool {
+        let iso: IsometryMatrix3<f64> = na::convert(iso);
+        let from = points3();
+        let to: Vec<_> = from
+            .iter()
+            .zip(noise.iter().chain(std::iter::repeat(&Vector3::zeros())))
+            .map(|(p, n)| iso * p + n)
+            .collect();
+        let weights: Vec<_> = weights
+            .iter()
+            .map(|w| w.abs() + 0.1)
+            .chain(std::iter::repeat(1.0))
+            .take(from.len())
+            .collect();
+        let (fitted, rmsd) = na::fit_isometry(&from, &to, Some(&weights)).unwrap();
+
+        let rmsd_of = |t: &IsometryMatrix3<f64>| {
+            let (sum, total) = from.iter().zip(to.iter()).zip(weights.iter()).fold(
+                (0.0, 0.0),
+                |(sum, total), ((p, q), w)| (sum + (t * p - q).norm_squared() * w, total + w),
+            );
+            (sum / total).sqrt()
+        };
+
+        relative_eq!(rmsd, rmsd_of(&fitted), epsilon = 1.0e-7) && rmsd <= rmsd_of(&iso) + 1.0e-7
+    }
+);
@@ -30,10 +30,42 @@ fn quaternion_euler_angles_issue_494() {
     assert_eq!(angs.2, 0.0);
 }
 
+#[test]
+fn jacobians_small_angles() {
+    // The series expansions used for small angles must agree with the closed-form expressions.
+    let axis = Vector3::new(1.0, -2.0, 0.5).normalize();
+
+    for angle in &[0.0, 1.0e-12, 1.0e-6, 1.0e-3, 0.02, 0.03, 0.1] {
+        let v = axis * *angle;
+        let jl = na::Rotation3::left_jacobian(&v);
+        let jl_inv = na::Rotation3::left_jacobian_inverse(&v);
+        let expected = na::Matrix3::identity() + v.cross_matrix() * 0.5;
+
+        assert_relative_eq!(jl * jl_inv, na::Matrix3::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(jl, expected, epsilon = angle * angle);
+    }
+}
+
+#[test]
+fn from_matrix_nearest_reflection() {
+    // The nearest rotation of a reflection flips the axis with the smallest singular value.
+    let m = na::Matrix3::new(
+        2.0, 0.0, 0.0,
+        0.0, 3.0, 0.0,
+        0.0, 0.0, -1.0,
+    );
+    let rot = na::Rotation3::from_matrix_nearest(&m);
+
+    assert_relative_eq!(rot, na::Rotation3::identity(), epsilon = 1.0e-10);
+}
+
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
     use alga::general::RealField;
-    use na::{self, Rotation2, Rotation3, Unit, Vector2, Vector3};
+    use na::{
+        self, EulerAxes, EulerOrder, Matrix3, Rotation2, Rotation3, Unit, UnitQuaternion, Vector2,
+        Vector3,
+    };
     use std::f64;
 
     quickcheck! {
@@ -219,5 +251,209 @@ mod quickcheck_tests {
                 r == Rotation3::identity()
             }
         }
+
+        /*
+         *
+         * Nearest rotation matrix.
+         *
+         */
+        fn from_matrix_nearest_rotation(r: Rotation3<f64>) -> bool {
+            relative_eq!(Rotation3::from_matrix_nearest(r.matrix()), r, epsilon = 1.0e-7)
+        }
+
+        fn from_matrix_nearest(m: Matrix3<f64>) -> bool {
+            let rot = Rotation3::from_matrix_nearest(&m);
+            let rt_m = rot.matrix().tr_mul(&m);
+
+            // The optimal rotation is such that `Rᵀ * m` is symmetric.
+            (rot.matrix().tr_mul(rot.matrix())).is_identity(1.0e-7) &&
+            relative_eq!(rot.matrix().determinant(), 1.0, epsilon = 1.0e-7) &&
+            relative_eq!(rt_m, rt_m.transpose(), epsilon = 1.0e-7)
+        }
+
+        fn euler_angles_ordered(a: f64, b: f64, c: f64) -> bool {
+            all_euler_orders().iter().all(|order| {
+                let rot = Rotation3::from_euler_angles_ordered(a, b, c, *order);
+                let uq = UnitQuaternion::from_euler_angles_ordered(a, b, c, *order);
+                let (a2, b2, c2) = rot.euler_angles_ordered(*order);
+                let (a3, b3, c3) = uq.euler_angles_ordered(*order);
+
+                relative_eq!(Rotation3::from_euler_angles_ordered(a2, b2, c2, *order), rot, epsilon = 1.0e-7) &&
+                relative_eq!(UnitQuaternion::from_euler_angles_ordered(a3, b3, c3, *order), uq, epsilon = 1.0e-7) &&
+                relative_eq!(uq.to_rotation_matrix(), rot, epsilon = 1.0e-7)
+            })
+        }
+
+        fn euler_angles_ordered_gimbal_lock(a: f64, c: f64) -> bool {
+            all_euler_orders().iter().all(|order| {
+                let locks = if order.axes().is_tait_bryan() {
+                    [f64::consts::FRAC_PI_2, -f64::consts::FRAC_PI_2]
+                } else {
+                    [0.0, f64::consts::PI]
+                };
+
+                locks.iter().all(|b| {
+                    let rot = Rotation3::from_euler_angles_ordered(a, *b, c, *order);
+                    let (a2, b2, c2) = rot.euler_angles_ordered(*order);
+                    let zero = if order.is_intrinsic() { a2 } else { c2 };
+
+                    zero == 0.0 &&
+                    relative_eq!(b2, *b, epsilon = 1.0e-7) &&
+                    relative_eq!(Rotation3::from_euler_angles_ordered(a2, b2, c2, *order), rot, epsilon = 1.0e-7)
+                })
+            })
+        }
+
+        fn euler_angles_ordered_intrinsic_extrinsic(a: f64, b: f64, c: f64) -> bool {
+            let x = Vector3::x_axis();
+            let y = Vector3::y_axis();
+            let z = Vector3::z_axis();
+            let intrinsic = Rotation3::from_euler_angles_ordered(a, b, c, EulerOrder::Intrinsic(EulerAxes::ZYX));
+            let extrinsic = Rotation3::from_euler_angles_ordered(c, b, a, EulerOrder::Extrinsic(EulerAxes::XYZ));
+            let zyz = Rotation3::from_euler_angles_ordered(a, b, c, EulerOrder::Intrinsic(EulerAxes::ZYZ));
+
+            relative_eq!(intrinsic, extrinsic, epsilon = 1.0e-7) &&
+            relative_eq!(extrinsic, Rotation3::from_euler_angles(c, b, a), epsilon = 1.0e-7) &&
+            relative_eq!(
+                intrinsic,
+                Rotation3::from_axis_angle(&z, a) * Rotation3::from_axis_angle(&y, b) * Rotation3::from_axis_angle(&x, c),
+                epsilon = 1.0e-7
+            ) &&
+            relative_eq!(
+                zyz,
+                Rotation3::from_axis_angle(&z, a) * Rotation3::from_axis_angle(&y, b) * Rotation3::from_axis_angle(&z, c),
+                epsilon = 1.0e-7
+            )
+        }
+
+        /*
+         *
+         * Lie group.
+         *
+         */
+        fn hat_vee_2(angle: f64) -> bool {
+            let hat = Rotation2::hat(angle);
+
+            Rotation2::vee(&hat) == angle &&
+            relative_eq!(hat.exp(), *Rotation2::new(angle).matrix(), epsilon = 1.0e-7)
+        }
+
+        fn hat_vee_3(v: Vector3<f64>) -> bool {
+            let hat = Rotation3::hat(&v);
+
+            Rotation3::vee(&hat) == v &&
+            hat == -hat.transpose() &&
+            relative_eq!(hat.exp(), *Rotation3::new(v).matrix(), epsilon = 1.0e-7)
+        }
+
+        fn jacobians_3(v: Vector3<f64>) -> bool {
+            let v = bounded_angle(v);
+            let (left, right) = numerical_jacobians(&v);
+
+            relative_eq!(Rotation3::left_jacobian(&v), left, epsilon = 1.0e-6) &&
+            relative_eq!(Rotation3::right_jacobian(&v), right, epsilon = 1.0e-6) &&
+            relative_eq!(
+                Rotation3::left_jacobian(&v) * Rotation3::left_jacobian_inverse(&v),
+                Matrix3::identity(),
+                epsilon = 1.0e-7
+            ) &&
+            relative_eq!(
+                Rotation3::right_jacobian(&v) * Rotation3::right_jacobian_inverse(&v),
+                Matrix3::identity(),
+                epsilon = 1.0e-7
+            )
+        }
+
+        /*
+         *
+         * Rotation averaging.
+         *
+         */
+        fn chordal_geodesic_means_symmetric(r: Rotation3<f64>, v: Vector3<f64>) -> bool {
+            let delta = Rotation3::new(bounded_angle(v) * 0.5);
+            let rotations = [r * delta, r * delta.inverse(), r];
+
+            relative_eq!(Rotation3::chordal_mean(&rotations, None).unwrap(), r, epsilon = 1.0e-7) &&
+            relative_eq!(
+                Rotation3::geodesic_mean(&rotations, None, 1.0e-12, 100).unwrap(),
+                r,
+                epsilon = 1.0e-7
+            )
+        }
+
+        fn geodesic_mean_is_stationary(r: Rotation3<f64>, vs: Vec<Vector3<f64>>, ws: Vec<f64>) -> bool {
+            let rotations: Vec<_> = vs
+                .iter()
+                .map(|v| r * Rotation3::new(bounded_angle(*v) * 0.25))
+                .collect();
+            let weights: Vec<_> = ws
+                .iter()
+                .map(|w| w.abs() + 0.1)
+                .chain(std::iter::repeat(1.0))
+                .take(vs.len())
+                .collect();
+
+            match Rotation3::geodesic_mean(&rotations, Some(&weights), 1.0e-12, 0) {
+                Some(mean) => {
+                    // The weighted sum of the logarithms of the rotations from the mean vanishes.
+                    let mean = UnitQuaternion::from_rotation_matrix(&mean);
+                    let gradient = rotations.iter().zip(weights.iter()).fold(Vector3::zeros(), |acc, (r, w)| {
+                        let q = UnitQuaternion::from_rotation_matrix(r);
+                        acc + mean.rotation_to(&q).scaled_axis() * *w
+                    });
+
+                    relative_eq!(gradient, Vector3::zeros(), epsilon = 1.0e-7) &&
+                    relative_eq!(mean.angle_to(&UnitQuaternion::from_rotation_matrix(&r)), 0.0, epsilon = 0.75)
+                }
+                None => vs.is_empty(),
+            }
+        }
+    }
+
+    const ALL_EULER_AXES: [EulerAxes; 12] = [
+        EulerAxes::XYZ, EulerAxes::XZY, EulerAxes::YXZ, EulerAxes::YZX, EulerAxes::ZXY, EulerAxes::ZYX,
+        EulerAxes::XYX, EulerAxes::XZX, EulerAxes::YXY, EulerAxes::YZY, EulerAxes::ZXZ, EulerAxes::ZYZ,
+    ];
+
+    fn all_euler_orders() -> Vec<EulerOrder> {
+        ALL_EULER_AXES
+            .iter()
+            .flat_map(|axes| vec![EulerOrder::Extrinsic(*axes), EulerOrder::Intrinsic(*axes)])
+            .collect()
+    }
+
+    // Rescales `v` so that its norm is smaller than `pi`.
+    fn bounded_angle(v: Vector3<f64>) -> Vector3<f64> {
+        match Unit::try_new_and_get(v, 1.0e-10) {
+            Some((axis, angle)) => axis.into_inner() * (angle % 3.0),
+            None => Vector3::zeros(),
+        }
+    }
+
+    // Central finite differences of the left and right perturbations of `Rotation3::new(v)`.
+    fn numerical_jacobians(v: &Vector3<f64>) -> (Matrix3<f64>, Matrix3<f64>) {
+        let h = 1.0e-6;
+        let rot = UnitQuaternion::new(*v);
+        let mut left = Matrix3::zeros();
+        let mut right = Matrix3::zeros();
+
+        for i in 0..3 {
+            let mut dv = Vector3::zeros();
+            dv[i] = h;
+            let plus = UnitQuaternion::new(v + dv);
+            let minus = UnitQuaternion::new(v - dv);
+
+            left.set_column(
+                i,
+                &(((plus / rot).scaled_axis() - (minus / rot).scaled_axis()) / (2.0 * h)),
+            );
+            right.set_column(
+                i,
+                &(((rot.inverse() * plus).scaled_axis() - (rot.inverse() * minus).scaled_axis())
+                    / (2.0 * h)),
+            );
+        }
+
+        (left, right)
     }
 }
@@ -17,5 +17,5 @@ extern crate num_complex;
 mod core;
 mod geometry;
 mod linalg;
-//#[cfg(feature = "sparse")]
-//mod sparse;
+#[cfg(feature = "sparse")]
+mod sparse;
@@ -1,5 +1,15 @@
 #![cfg(all(feature = "arbitrary", feature = "debug"))]
 
+use na::{LinalgError, Matrix2};
+
+#[test]
+fn cholesky_not_positive_definite() {
+    let m = Matrix2::new(1.0, 2.0, 2.0, 1.0);
+
+    assert!(m.cholesky().is_none());
+    assert_eq!(m.try_cholesky().err(), Some(LinalgError::NotPositiveDefinite));
+    assert!(Matrix2::<f64>::identity().try_cholesky().is_ok());
+}
 
 macro_rules! gen_tests(
     ($module: ident, $scalar: ty) => {
@@ -7,6 +17,7 @@ macro_rules! gen_tests(
             use na::debug::RandomSDP;
             use na::dimension::{U4, Dynamic};
             use na::{DMatrix, DVector, Matrix4x3, Vector4};
+            use na::ComplexField;
             use rand::random;
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
@@ -78,6 +89,93 @@ macro_rules! gen_tests(
 
                     id1.is_identity(1.0e-7) && id2.is_identity(1.0e-7)
                 }
+
+                fn cholesky_rank_one_update(_n: usize) -> bool {
+                    let m = RandomSDP::new(U4, || random::<$scalar>().0).unwrap();
+                    let x = Vector4::<$scalar>::new_random().map(|e| e.0);
+                    let sigma = random::<$scalar>().0.real().abs();
+
+                    let mut chol = m.clone().cholesky().unwrap();
+                    chol.rank_one_update(&x, sigma);
+
+                    let m_updated = m + (x * x.adjoint()).scale(sigma);
+                    let l = chol.unpack();
+                    relative_eq!(m_updated, &l * l.adjoint(), epsilon = 1.0e-7)
+                }
+
+                fn cholesky_rank_one_downdate(n: usize) -> bool {
+                    let n = n.max(1).min(20);
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let x = DVector::<$scalar>::new_random(n).map(|e| e.0);
+                    let sigma = random::<$scalar>().0.real().abs();
+
+                    let m_updated = &m + (&x * x.adjoint()).scale(sigma);
+                    let mut chol = m_updated.cholesky().unwrap();
+
+                    if !chol.rank_one_downdate(&x, sigma) {
+                        return false;
+                    }
+
+                    let l = chol.unpack();
+                    relative_eq!(m, &l * l.adjoint(), epsilon = 1.0e-7)
+                }
+
+                fn cholesky_rank_one_downdate_not_definite_positive(n: usize) -> bool {
+                    let n = n.max(1).min(20);
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let x = DVector::<$scalar>::new_random(n).map(|e| e.0);
+                    let sigma = m.norm() / x.norm_squared() * 100.0;
+
+                    let mut chol = m.clone().cholesky().unwrap();
+                    let l_before = chol.l();
+
+                    !chol.rank_one_downdate(&x, sigma) && chol.l() == l_before
+                }
+
+                fn cholesky_insert_column(n: usize) -> bool {
+                    let n = n.max(1).min(20);
+                    let j = random::<usize>() % (n + 1);
+                    let m_updated = RandomSDP::new(Dynamic::new(n + 1), || random::<$scalar>().0).unwrap();
+
+                    // Build the matrix without the column j and decompose it.
+                    let m = m_updated.clone().remove_column(j).remove_row(j);
+                    let chol = m.cholesky().unwrap();
+
+                    let chol = chol.insert_column(j, &m_updated.column(j)).unwrap();
+                    let l = chol.unpack();
+                    relative_eq!(m_updated, &l * l.adjoint(), epsilon = 1.0e-7)
+                }
+
+                fn cholesky_remove_column(n: usize) -> bool {
+                    let n = n.max(2).min(20);
+                    let j = random::<usize>() % n;
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let chol = m.clone().cholesky().unwrap().remove_column(j);
+
+                    let m_updated = m.remove_column(j).remove_row(j);
+                    let l = chol.unpack();
+                    relative_eq!(m_updated, &l * l.adjoint(), epsilon = 1.0e-7)
+                }
+
+                fn cholesky_insert_column_static(_n: usize) -> bool {
+                    let j = random::<usize>() % 4;
+                    let m_updated = RandomSDP::new(U4, || random::<$scalar>().0).unwrap();
+                    let m = m_updated.remove_column(j).remove_row(j);
+                    let chol = m.cholesky().unwrap().insert_column(j, &m_updated.column(j)).unwrap();
+
+                    let l = chol.unpack();
+                    relative_eq!(m_updated, l * l.adjoint(), epsilon = 1.0e-7)
+                }
+
+                fn cholesky_remove_column_static(_n: usize) -> bool {
+                    let j = random::<usize>() % 4;
+                    let m = RandomSDP::new(U4, || random::<$scalar>().0).unwrap();
+                    let chol = m.clone().cholesky().unwrap().remove_column(j);
+
+                    let m_updated = m.remove_column(j).remove_row(j);
+                    let l = chol.unpack();
+                    relative_eq!(m_updated, l * l.adjoint(), epsilon = 1.0e-7)
+                }
             }
         }
     }
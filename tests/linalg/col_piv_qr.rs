@@ -0,0 +1,197 @@
+#![cfg(feature = "arbitrary")]
+
+use na::{DMatrix, Matrix3, Matrix4};
+
+#[test]
+fn col_piv_qr_rank_deficient() {
+    let m = DMatrix::from_row_slice(4, 3, &[
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 9.0,
+        7.0, 8.0, 15.0,
+        2.0, 1.0, 3.0,
+    ]);
+
+    let col_piv_qr = m.clone().col_piv_qr();
+    let null_space = col_piv_qr.null_space(1.0e-10);
+
+    assert_eq!(col_piv_qr.rank(1.0e-10), 2);
+    assert_eq!(null_space.ncols(), 1);
+    assert!((&m * &null_space).norm() < 1.0e-10);
+    assert_relative_eq!(null_space.norm(), 1.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn col_piv_qr_null_space_of_zero_matrix() {
+    let m = Matrix4::<f64>::zeros();
+    let null_space = m.col_piv_qr().null_space(1.0e-10);
+
+    assert_eq!(null_space.ncols(), 4);
+    assert!(null_space.is_orthogonal(1.0e-10));
+}
+
+#[test]
+fn col_piv_qr_norm_downdating_cancellation() {
+    // After the first reflection, the trailing norms of the other columns are much smaller than
+    // their initial norms so they have to be recomputed instead of downdated.
+    let m = Matrix3::<f64>::new(
+        1.0, 1.0,     1.0,
+        0.0, 1.0e-10, 0.0,
+        0.0, 0.0,     1.0e-9,
+    );
+
+    let col_piv_qr = m.col_piv_qr();
+    let r = col_piv_qr.r();
+    let (q, r2, p) = col_piv_qr.unpack();
+    let mut qr = q * r2;
+    p.inv_permute_columns(&mut qr);
+
+    // The pivot has the greatest trailing norm.
+    assert!(r[(1, 1)].abs() >= r.slice_range(1.., 2).norm() * (1.0 - 1.0e-10));
+    assert_relative_eq!((r[(0, 0)] * r[(1, 1)] * r[(2, 2)]).abs(), 1.0e-19, max_relative = 1.0e-6);
+    assert_relative_eq!(qr, m, epsilon = 1.0e-14);
+}
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: ty) => {
+        mod $module {
+            use na::{ComplexField, DMatrix, DVector, Matrix3x5, Matrix4, Matrix4x3, Matrix5x3, Vector4};
+            use std::cmp;
+            #[allow(unused_imports)]
+            use crate::core::helper::{RandScalar, RandComplex};
+
+            quickcheck! {
+                fn col_piv_qr(m: DMatrix<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let col_piv_qr = m.clone().col_piv_qr();
+                    let (q, r, p) = col_piv_qr.unpack();
+                    let mut qr = &q * &r;
+                    p.inv_permute_columns(&mut qr);
+
+                    relative_eq!(m, qr, epsilon = 1.0e-7) &&
+                    q.is_orthogonal(1.0e-7)
+                }
+
+                fn col_piv_qr_static_5_3(m: Matrix5x3<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let col_piv_qr = m.col_piv_qr();
+                    let (q, r, p) = col_piv_qr.unpack();
+                    let mut qr = q * r;
+                    p.inv_permute_columns(&mut qr);
+
+                    relative_eq!(m, qr, epsilon = 1.0e-7) &&
+                    q.is_orthogonal(1.0e-7)
+                }
+
+                fn col_piv_qr_static_3_5(m: Matrix3x5<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let col_piv_qr = m.col_piv_qr();
+                    let (q, r, p) = col_piv_qr.unpack();
+                    let mut qr = q * r;
+                    p.inv_permute_columns(&mut qr);
+
+                    relative_eq!(m, qr, epsilon = 1.0e-7) &&
+                    q.is_orthogonal(1.0e-7)
+                }
+
+                fn col_piv_qr_decreasing_diagonal(m: DMatrix<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let r = m.col_piv_qr().r();
+
+                    (1..r.nrows().min(r.ncols())).all(|i| {
+                        r[(i, i)].modulus() <= r[(i - 1, i - 1)].modulus() * (1.0 + 1.0e-10)
+                    })
+                }
+
+                fn col_piv_qr_null_space(n: usize, rank: usize) -> bool {
+                    let n    = cmp::max(1, cmp::min(n, 10));
+                    let rank = cmp::max(1, cmp::min(rank, n));
+                    let a = DMatrix::<$scalar>::new_random(n, rank).map(|e| e.0);
+                    let b = DMatrix::<$scalar>::new_random(rank, n).map(|e| e.0);
+                    let m = a * b;
+
+                    let col_piv_qr = m.clone().col_piv_qr();
+                    let null_space = col_piv_qr.null_space(1.0e-8);
+
+                    col_piv_qr.rank(1.0e-8) == rank &&
+                    null_space.ncols() == n - rank &&
+                    (&m * &null_space).norm() < 1.0e-6 &&
+                    null_space.is_orthogonal(1.0e-7)
+                }
+
+                fn col_piv_qr_solve(n: usize, nb: usize) -> bool {
+                    if n != 0 && nb != 0 {
+                        let n  = cmp::min(n, 50);  // To avoid slowing down the test too much.
+                        let nb = cmp::min(nb, 50); // To avoid slowing down the test too much.
+                        let m  = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+
+                        let col_piv_qr = m.clone().col_piv_qr();
+                        let b1 = DVector::<$scalar>::new_random(n).map(|e| e.0);
+                        let b2 = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+
+                        if col_piv_qr.is_invertible() {
+                            let sol1 = col_piv_qr.solve(&b1).unwrap();
+                            let sol2 = col_piv_qr.solve(&b2).unwrap();
+
+                            return relative_eq!(&m * sol1, b1, epsilon = 1.0e-6) &&
+                                relative_eq!(&m * sol2, b2, epsilon = 1.0e-6)
+                        }
+                    }
+
+                    return true;
+                }
+
+                fn col_piv_qr_solve_static(m: Matrix4<$scalar>) -> bool {
+                     let m = m.map(|e| e.0);
+                     let col_piv_qr = m.col_piv_qr();
+                     let b1 = Vector4::<$scalar>::new_random().map(|e| e.0);
+                     let b2 = Matrix4x3::<$scalar>::new_random().map(|e| e.0);
+
+                     if col_piv_qr.is_invertible() {
+                         let sol1 = col_piv_qr.solve(&b1).unwrap();
+                         let sol2 = col_piv_qr.solve(&b2).unwrap();
+
+                         relative_eq!(m * sol1, b1, epsilon = 1.0e-6) &&
+                         relative_eq!(m * sol2, b2, epsilon = 1.0e-6)
+                     }
+                     else {
+                         false
+                     }
+                }
+
+                fn col_piv_qr_inverse(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+
+                    if let Some(m1) = m.clone().col_piv_qr().try_inverse() {
+                        let id1 = &m  * &m1;
+                        let id2 = &m1 * &m;
+
+                        id1.is_identity(1.0e-5) && id2.is_identity(1.0e-5)
+                    }
+                    else {
+                        true
+                    }
+                }
+
+                fn col_piv_qr_determinant(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 10)); // To avoid slowing down the test too much.
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+
+                    let det1 = m.clone().col_piv_qr().determinant();
+                    let det2 = m.lu().determinant();
+
+                    relative_eq!(det1, det2, epsilon = 1.0e-7, max_relative = 1.0e-7)
+                }
+
+                fn col_piv_qr_determinant_static(m: Matrix4<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+
+                    relative_eq!(m.col_piv_qr().determinant(), m.determinant(), epsilon = 1.0e-7, max_relative = 1.0e-7)
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, RandComplex<f64>);
+gen_tests!(f64, RandScalar<f64>);
@@ -1,6 +1,7 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
-use na::DMatrix;
+use na::allocator::Allocator;
+use na::{Complex, ComplexField, DMatrix, DefaultAllocator, Dim, Eigen, Matrix3, Matrix4, MatrixN};
 
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
@@ -109,88 +110,139 @@ fn symmetric_eigen_singular_24x24() {
     );
 }
 
-//  #[cfg(feature = "arbitrary")]
-//  quickcheck! {
-// FIXME: full eigendecomposition is not implemented yet because of its complexity when some
-// eigenvalues have multiplicity > 1.
-//
-//    /*
-//     * NOTE: for the following tests, we use only upper-triangular matrices.
-//     * Thes ensures the schur decomposition will work, and allows use to test the eigenvector
-//     * computation.
-//     */
-//    fn eigen(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_adjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some adjascent diagonal elements.
-//        for i in 0 .. n / 2 {
-//            m[(i * 2 + 1, i * 2 + 1)] = m[(i * 2, i * 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_nonadjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(3, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some diagonal elements.
-//        for i in n / 2 .. n {
-//            m[(i, i)] = m[(i - n / 2, i - n / 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_4x4(m: Matrix4<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_3x3(m: Matrix3<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_2x2(m: Matrix2<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        println!("{}", m);
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//  }
-//
-// fn verify_eigenvectors<D: Dim>(m: MatrixN<f64, D>, mut eig: RealEigen<f64, D>) -> bool
-//     where DefaultAllocator: Allocator<f64, D, D>   +
-//                             Allocator<f64, D>      +
-//                             Allocator<usize, D, D> +
-//                             Allocator<usize, D>,
-//           MatrixN<f64, D>: Display,
-//           VectorN<f64, D>: Display {
-//     let mv = &m * &eig.eigenvectors;
-//
-//     println!("eigenvalues: {}eigenvectors: {}", eig.eigenvalues, eig.eigenvectors);
-//
-//     let dim = m.nrows();
-//     for i in 0 .. dim {
-//         let mut col = eig.eigenvectors.column_mut(i);
-//         col *= eig.eigenvalues[i];
-//     }
-//
-//     println!("{}{:.5}{:.5}", m, mv, eig.eigenvectors);
-//
-//     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
-// }
+#[test]
+fn symmetric_eigen_nearly_decoupled_2x2_block() {
+    // The last 2x2 block deflated during the QR iterations has a tiny off-diagonal element.
+    let m = Matrix3::new(-0.01934569612542821,  -0.0353095845904985,  0.6721263640227778,
+                         -0.0353095845904985,  -0.08472237465005163,  1.698135303838603,
+                          0.6721263640227778,   1.698135303838603,  -35.31837307552834);
+
+    let eig = m.symmetric_eigen();
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_general_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix2, Matrix3, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::linalg::eigen::{verify_eigenvectors, verify_left_eigenvectors};
+                use std::cmp;
+
+                quickcheck! {
+                    fn eigen(n: usize) -> bool {
+                        let n   = cmp::max(1, cmp::min(n, 10));
+                        let m   = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let eig = m.clone().eigen();
+
+                        verify_eigenvectors(&m, &eig) && verify_left_eigenvectors(&m, &eig)
+                    }
+
+                    fn eigen_with_duplicate_diagonals(n: usize) -> bool {
+                        let n     = cmp::max(2, cmp::min(n, 10));
+                        let mut m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).upper_triangle();
+
+                        // Duplicate some diagonal elements.
+                        for i in n / 2 .. n {
+                            m[(i, i)] = m[(i - n / 2, i - n / 2)];
+                        }
+
+                        let eig = m.clone().eigen();
+                        verify_eigenvectors(&m, &eig) && verify_left_eigenvectors(&m, &eig)
+                    }
+
+                    fn eigen_static_square_4x4(m: Matrix4<$scalar>) -> bool {
+                        let m   = m.map(|e| e.0);
+                        let eig = m.eigen();
+                        verify_eigenvectors(&m, &eig) && verify_left_eigenvectors(&m, &eig)
+                    }
+
+                    fn eigen_static_square_3x3(m: Matrix3<$scalar>) -> bool {
+                        let m   = m.map(|e| e.0);
+                        let eig = m.eigen();
+                        verify_eigenvectors(&m, &eig) && verify_left_eigenvectors(&m, &eig)
+                    }
+
+                    fn eigen_static_square_2x2(m: Matrix2<$scalar>) -> bool {
+                        let m   = m.map(|e| e.0);
+                        let eig = m.eigen();
+                        verify_eigenvectors(&m, &eig) && verify_left_eigenvectors(&m, &eig)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
+
+#[test]
+fn eigen_rotation_has_complex_eigenvalues() {
+    let m = Matrix3::new(0.0, -1.0, 0.0,
+                         1.0,  0.0, 0.0,
+                         0.0,  0.0, 2.0);
+    let eig = m.eigen();
+
+    let mut ims: Vec<f64> = eig.eigenvalues.iter().map(|e| e.im).collect();
+    ims.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert_relative_eq!(ims[0], -1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(ims[1], 0.0, epsilon = 1.0e-7);
+    assert_relative_eq!(ims[2], 1.0, epsilon = 1.0e-7);
+    assert!(verify_eigenvectors(&m, &eig));
+    assert!(verify_left_eigenvectors(&m, &eig));
+}
+
+#[test]
+fn eigen_identity() {
+    let m = DMatrix::<f64>::identity(5, 5);
+    let eig = m.clone().eigen();
+
+    for val in eig.eigenvalues.iter() {
+        assert_relative_eq!(val.re, 1.0, epsilon = 1.0e-7);
+        assert_relative_eq!(val.im, 0.0, epsilon = 1.0e-7);
+    }
+
+    assert!(verify_eigenvectors(&m, &eig));
+    assert!(verify_left_eigenvectors(&m, &eig));
+}
+
+#[test]
+fn eigen_defective() {
+    // A single Jordan block.
+    let m = Matrix4::new(2.0, 1.0, 0.0, 0.0,
+                         0.0, 2.0, 1.0, 0.0,
+                         0.0, 0.0, 2.0, 1.0,
+                         0.0, 0.0, 0.0, 2.0);
+    let eig = m.eigen();
+
+    for val in eig.eigenvalues.iter() {
+        assert_relative_eq!(val.re, 2.0, epsilon = 1.0e-7);
+        assert_relative_eq!(val.im, 0.0, epsilon = 1.0e-7);
+    }
+
+    assert!(verify_eigenvectors(&m, &eig));
+    assert!(verify_left_eigenvectors(&m, &eig));
+}
+
+pub fn verify_eigenvectors<N: ComplexField, D: Dim>(m: &MatrixN<N, D>, eig: &Eigen<N, D>) -> bool
+where DefaultAllocator: Allocator<N, D, D> + Allocator<Complex<N::RealField>, D, D> + Allocator<Complex<N::RealField>, D> {
+    let m = m.map(|e| Complex::new(e.real(), e.imaginary()));
+    let mv = &m * &eig.eigenvectors;
+    let vl = &eig.eigenvectors * MatrixN::from_diagonal(&eig.eigenvalues);
+
+    (mv - vl).norm() <= na::convert(1.0e-5)
+}
+
+pub fn verify_left_eigenvectors<N: ComplexField, D: Dim>(m: &MatrixN<N, D>, eig: &Eigen<N, D>) -> bool
+where DefaultAllocator: Allocator<N, D, D> + Allocator<Complex<N::RealField>, D, D> + Allocator<Complex<N::RealField>, D> {
+    let m = m.map(|e| Complex::new(e.real(), e.imaginary()));
+    let um = eig.left_eigenvectors.adjoint() * &m;
+    let lu = MatrixN::from_diagonal(&eig.eigenvalues) * eig.left_eigenvectors.adjoint();
+
+    (um - lu).norm() <= na::convert(1.0e-5)
+}
@@ -0,0 +1,94 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{DMatrix, Matrix2, Matrix3};
+
+#[test]
+fn exp_zero() {
+    let m = Matrix3::<f64>::zeros();
+    assert_eq!(m.exp(), Matrix3::identity());
+}
+
+#[test]
+fn exp_diagonal() {
+    let m = Matrix3::new(1.0, 0.0,  0.0,
+                         0.0, 2.0,  0.0,
+                         0.0, 0.0, -3.0);
+    let expected = Matrix3::new(1.0f64.exp(), 0.0,          0.0,
+                                0.0,          2.0f64.exp(), 0.0,
+                                0.0,          0.0,          (-3.0f64).exp());
+
+    assert!(relative_eq!(m.exp(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn exp_nilpotent() {
+    let m = Matrix2::new(0.0, 1.0,
+                         0.0, 0.0);
+    let expected = Matrix2::new(1.0, 1.0,
+                                0.0, 1.0);
+
+    assert!(relative_eq!(m.exp(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn exp_rotation_generator() {
+    // Large enough to require some squarings.
+    let angle = 10.0f64;
+    let m = Matrix2::new(0.0, -angle,
+                         angle, 0.0);
+    let expected = Matrix2::new(angle.cos(), -angle.sin(),
+                                angle.sin(),  angle.cos());
+
+    assert!(relative_eq!(m.exp(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn exp_dynamic() {
+    let m = DMatrix::from_row_slice(3, 3, &[
+        0.0, 1.0, 2.0,
+        0.0, 0.0, 3.0,
+        0.0, 0.0, 0.0]);
+    // For this nilpotent matrix, exp(m) = I + m + m² / 2.
+    let expected = DMatrix::identity(3, 3) + &m + &m * &m * 0.5;
+
+    assert!(relative_eq!(m.exp(), expected, epsilon = 1.0e-7));
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use std::cmp;
+                use na::{DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn exp_inverse(n: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let id = DMatrix::identity(n, n);
+
+                        (m.exp() * (-m).exp() - id).norm() < 1.0e-7
+                    }
+
+                    fn exp_inverse_static(_n: usize) -> bool {
+                        let m = Matrix4::<$scalar>::new_random().map(|e| e.0);
+                        (m.exp() * (-m).exp() - Matrix4::identity()).norm() < 1.0e-7
+                    }
+
+                    fn exp_sum_of_commuting_matrices(_n: usize) -> bool {
+                        let m = Matrix4::<$scalar>::new_random().map(|e| e.0);
+                        let m2 = m * m;
+
+                        ((m + m2).exp() - m.exp() * m2.exp()).norm() < 1.0e-7 * (m + m2).exp().norm()
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
@@ -0,0 +1,150 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::allocator::Allocator;
+use na::{Complex, ComplexField, DMatrix, DefaultAllocator, Dim, GeneralizedEigen, LinalgError, Matrix2, Matrix3, MatrixN, SymmetricGeneralizedEigen};
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix3, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use std::cmp;
+
+                quickcheck! {
+                    fn generalized_eigen(n: usize) -> bool {
+                        let n   = cmp::max(1, cmp::min(n, 10));
+                        let a   = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let b   = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let eig = a.clone().generalized_eigen(b.clone());
+
+                        super::super::verify_generalized_eigenvectors(&a, &b, &eig) &&
+                        super::super::verify_generalized_left_eigenvectors(&a, &b, &eig)
+                    }
+
+                    fn generalized_eigen_static_square_4x4(a: Matrix4<$scalar>, b: Matrix4<$scalar>) -> bool {
+                        let a   = a.map(|e| e.0);
+                        let b   = b.map(|e| e.0);
+                        let eig = a.generalized_eigen(b);
+
+                        super::super::verify_generalized_eigenvectors(&a, &b, &eig) &&
+                        super::super::verify_generalized_left_eigenvectors(&a, &b, &eig)
+                    }
+
+                    fn generalized_eigen_singular_b(a: Matrix3<$scalar>, b: Matrix3<$scalar>) -> bool {
+                        let a     = a.map(|e| e.0);
+                        let mut b = b.map(|e| e.0);
+                        b.row_mut(1).fill(na::zero());
+                        let eig   = a.generalized_eigen(b);
+
+                        eig.betas.iter().filter(|beta| **beta < 1.0e-10).count() >= 1 &&
+                        super::super::verify_generalized_eigenvectors(&a, &b, &eig) &&
+                        super::super::verify_generalized_left_eigenvectors(&a, &b, &eig)
+                    }
+
+                    fn symmetric_generalized_eigen(n: usize) -> bool {
+                        let n   = cmp::max(1, cmp::min(n, 10));
+                        let a   = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let b   = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let b   = b.ad_mul(&b) + DMatrix::identity(n, n);
+                        let eig = a.clone().symmetric_generalized_eigen(b.clone()).unwrap();
+                        let x   = &eig.eigenvectors;
+                        let mut bx_lambda = &b * x;
+                        for j in 0..n {
+                            bx_lambda.column_mut(j).scale_mut(eig.eigenvalues[j]);
+                        }
+
+                        (&a * x - bx_lambda).norm() <= 1.0e-7 * (a.norm() + b.norm() * eig.eigenvalues.amax()) * x.norm() &&
+                        x.ad_mul(&(&b * x)).is_identity(1.0e-7)
+                    }
+
+                    fn symmetric_generalized_eigen_static_square_3x3(a: Matrix3<$scalar>, b: Matrix3<$scalar>) -> bool {
+                        let a   = a.map(|e| e.0).hermitian_part();
+                        let b   = b.map(|e| e.0);
+                        let b   = b.ad_mul(&b) + Matrix3::identity();
+                        let eig = a.symmetric_generalized_eigen(b).unwrap();
+                        let x   = eig.eigenvectors;
+                        let mut bx_lambda = b * x;
+                        for j in 0..3 {
+                            bx_lambda.column_mut(j).scale_mut(eig.eigenvalues[j]);
+                        }
+
+                        (a * x - bx_lambda).norm() <= 1.0e-7 * (a.norm() + b.norm() * eig.eigenvalues.amax()) * x.norm() &&
+                        x.ad_mul(&(b * x)).is_identity(1.0e-7)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
+
+#[test]
+fn generalized_eigen_identity_b() {
+    // With `B = I`, the generalized eigenvalues are the standard eigenvalues.
+    let a = Matrix3::<f64>::new(0.0, -1.0, 0.0,
+                         1.0,  0.0, 0.0,
+                         0.0,  0.0, 2.0);
+    let eig = a.generalized_eigen(Matrix3::identity());
+    let mut eigenvalues: Vec<_> = eig.eigenvalues().iter().cloned().collect();
+    eigenvalues.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+
+    assert!((eigenvalues[0] - Complex::new(0.0, -1.0)).modulus() < 1.0e-10);
+    assert!((eigenvalues[1] - Complex::new(0.0, 1.0)).modulus() < 1.0e-10);
+    assert!((eigenvalues[2] - Complex::new(2.0, 0.0)).modulus() < 1.0e-10);
+    assert!(verify_generalized_eigenvectors(&a, &Matrix3::identity(), &eig));
+}
+
+#[test]
+fn generalized_eigen_infinite_eigenvalue() {
+    // `det(A - λ B) = 2 - 2 * λ` so there is one finite eigenvalue `1` and one infinite one.
+    let a = Matrix2::<f64>::new(1.0, 0.0,
+                         0.0, 2.0);
+    let b = Matrix2::new(1.0, 0.0,
+                         0.0, 0.0);
+    let eig = a.generalized_eigen(b);
+    let (finite, infinite) = if eig.betas[0] > eig.betas[1] { (0, 1) } else { (1, 0) };
+
+    assert_relative_eq!(eig.betas[infinite], 0.0, epsilon = 1.0e-10);
+    assert!((eig.eigenvalues()[finite] - Complex::new(1.0, 0.0)).modulus() < 1.0e-10);
+    assert!(!eig.eigenvalues()[infinite].re.is_finite() || !eig.eigenvalues()[infinite].im.is_finite());
+    assert!(verify_generalized_eigenvectors(&a, &b, &eig));
+    assert!(verify_generalized_left_eigenvectors(&a, &b, &eig));
+}
+
+#[test]
+fn symmetric_generalized_eigen_not_positive_definite() {
+    let a = DMatrix::<f64>::identity(3, 3);
+    let b = DMatrix::from_diagonal_element(3, 3, -1.0);
+
+    assert!(a.clone().symmetric_generalized_eigen(b.clone()).is_none());
+    assert_eq!(SymmetricGeneralizedEigen::try_decompose(a, b, 1.0e-15, 0).err(), Some(LinalgError::NotPositiveDefinite));
+}
+
+pub fn verify_generalized_eigenvectors<N: ComplexField, D: Dim>(a: &MatrixN<N, D>, b: &MatrixN<N, D>, eig: &GeneralizedEigen<N, D>) -> bool
+where DefaultAllocator: Allocator<N, D, D> + Allocator<Complex<N::RealField>, D, D> + Allocator<Complex<N::RealField>, D> + Allocator<N::RealField, D> {
+    let a = a.map(|e| Complex::new(e.real(), e.imaginary()));
+    let b = b.map(|e| Complex::new(e.real(), e.imaginary()));
+    let alphas = MatrixN::from_diagonal(&eig.alphas);
+    let betas = MatrixN::from_diagonal(&eig.betas.map(|e| Complex::new(e, na::zero())));
+    let av = &a * &eig.eigenvectors * betas;
+    let bv = &b * &eig.eigenvectors * alphas;
+
+    (av - bv).norm() <= na::convert(1.0e-5)
+}
+
+pub fn verify_generalized_left_eigenvectors<N: ComplexField, D: Dim>(a: &MatrixN<N, D>, b: &MatrixN<N, D>, eig: &GeneralizedEigen<N, D>) -> bool
+where DefaultAllocator: Allocator<N, D, D> + Allocator<Complex<N::RealField>, D, D> + Allocator<Complex<N::RealField>, D> + Allocator<N::RealField, D> {
+    let a = a.map(|e| Complex::new(e.real(), e.imaginary()));
+    let b = b.map(|e| Complex::new(e.real(), e.imaginary()));
+    let alphas = MatrixN::from_diagonal(&eig.alphas);
+    let betas = MatrixN::from_diagonal(&eig.betas.map(|e| Complex::new(e, na::zero())));
+    let ua = betas * eig.left_eigenvectors.adjoint() * &a;
+    let ub = alphas * eig.left_eigenvectors.adjoint() * &b;
+
+    (ua - ub).norm() <= na::convert(1.0e-5)
+}
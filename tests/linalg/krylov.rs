@@ -0,0 +1,107 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{self, DMatrix, DVector, JacobiPreconditioner, Matrix2, Matrix4, Vector2, Vector4};
+
+// A random symmetric positive-definite matrix with a moderate condition number.
+fn random_spd(n: usize) -> DMatrix<f64> {
+    let m = DMatrix::<f64>::new_random(n, n);
+    m.transpose() * &m + DMatrix::identity(n, n) * (n as f64)
+}
+
+// A random diagonally dominant unsymmetric matrix.
+fn random_unsymmetric(n: usize) -> DMatrix<f64> {
+    DMatrix::<f64>::new_random(n, n) + DMatrix::identity(n, n) * (n as f64)
+}
+
+#[test]
+fn krylov_conjugate_gradient() {
+    for n in &[1, 5, 30] {
+        let a = random_spd(*n);
+        let b = DVector::from_fn(*n, |i, _| i as f64 - 2.0);
+        let jacobi = JacobiPreconditioner::from_matrix(&a);
+
+        for preconditioner in &[None, Some(&jacobi as &dyn na::Preconditioner<_, _>)] {
+            let mut x = DVector::zeros(*n);
+            let report = na::conjugate_gradient(&a, &b, &mut x, *preconditioner, 1.0e-12, 100);
+
+            assert!(report.converged);
+            assert_eq!(report.niter, report.residual_history.len());
+            assert!(report.residual_norm <= 1.0e-10 * b.norm());
+            assert_relative_eq!(x, a.clone().lu().solve(&b).unwrap(), epsilon = 1.0e-8);
+        }
+    }
+}
+
+#[test]
+fn krylov_minres_indefinite() {
+    let a = Matrix4::new(
+        4.0, 1.0, 0.0, 0.0,
+        1.0, -3.0, 1.0, 0.0,
+        0.0, 1.0, 2.0, 1.0,
+        0.0, 0.0, 1.0, -5.0,
+    );
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let mut x = Vector4::zeros();
+    let report = na::minres(&a, &b, &mut x, None, 1.0e-12, 100);
+
+    assert!(report.converged);
+    assert_relative_eq!(x, a.lu().solve(&b).unwrap(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn krylov_unsymmetric() {
+    for n in &[1, 5, 30] {
+        let a = random_unsymmetric(*n);
+        let b = DVector::from_fn(*n, |i, _| i as f64 - 2.0);
+        let expected = a.clone().lu().solve(&b).unwrap();
+        let jacobi = JacobiPreconditioner::from_matrix(&a);
+
+        for preconditioner in &[None, Some(&jacobi as &dyn na::Preconditioner<_, _>)] {
+            let mut x = DVector::zeros(*n);
+            let report = na::bicgstab(&a, &b, &mut x, *preconditioner, 1.0e-12, 200);
+            assert!(report.converged);
+            assert_relative_eq!(x, expected, epsilon = 1.0e-8);
+
+            for restart in &[5, 40] {
+                let mut x = DVector::zeros(*n);
+                let report = na::gmres(&a, &b, &mut x, *preconditioner, *restart, 1.0e-12, 200);
+                assert!(report.converged);
+                assert!(report.residual_norm <= 1.0e-10 * b.norm());
+                assert_relative_eq!(x, expected, epsilon = 1.0e-8);
+            }
+        }
+    }
+}
+
+#[test]
+fn krylov_gmres_singular_operator() {
+    // `b` is not in the range of `a`: the best achievable residual is `(0, 1)`.
+    let a = Matrix2::new(
+        1.0, 0.0,
+        0.0, 0.0,
+    );
+    let b = Vector2::new(1.0, 1.0);
+
+    for restart in &[1, 2, 5] {
+        let mut x = Vector2::<f64>::zeros();
+        let report = na::gmres(&a, &b, &mut x, None, *restart, 1.0e-12, 20);
+
+        assert!(!report.converged);
+        assert!(x.iter().all(|e: &f64| e.is_finite()));
+        assert!(report.residual_history.iter().all(|e: &f64| e.is_finite()));
+        assert_relative_eq!(x[0], 1.0, epsilon = 1.0e-10);
+        assert_relative_eq!(report.residual_norm, 1.0, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn krylov_not_converged() {
+    let a = random_spd(30);
+    let b = DVector::from_element(30, 1.0);
+    let mut x = DVector::zeros(30);
+    let report = na::conjugate_gradient(&a, &b, &mut x, None, 1.0e-14, 2);
+
+    assert!(!report.converged);
+    assert_eq!(report.niter, 2);
+    assert_eq!(report.residual_history.len(), 2);
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "arbitrary")]
+
+use na::{DMatrix, LinalgError, Matrix4, Vector4};
+
+#[test]
+fn ldlt_indefinite_needs_pivoting() {
+    // The first diagonal element is zero so an unpivoted LDLᵀ would fail.
+    let m = Matrix4::new(
+        0.0, 1.0, 2.0, 3.0,
+        1.0, 0.0, 4.0, 5.0,
+        2.0, 4.0, -1.0, 6.0,
+        3.0, 5.0, 6.0, 2.0,
+    );
+
+    let ldlt = m.ldlt();
+    let (l, d) = (ldlt.l(), ldlt.d());
+    let mut pm = m;
+    ldlt.p().permute_rows(&mut pm);
+    ldlt.p().permute_columns(&mut pm);
+    assert_relative_eq!(pm, l * d * l.transpose(), epsilon = 1.0e-10);
+
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let x = ldlt.solve(&b).unwrap();
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    assert_relative_eq!(ldlt.determinant(), m.determinant(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn ldlt_kkt_inertia() {
+    // Saddle-point matrix `[H, Aᵀ; A, 0]` with `H` positive-definite of size 3 and `A` of full
+    // rank 2: it has 3 positive and 2 negative eigenvalues.
+    let m = DMatrix::from_row_slice(5, 5, &[
+        4.0, 1.0, 0.0, 1.0, 0.0,
+        1.0, 3.0, 1.0, 0.0, 1.0,
+        0.0, 1.0, 2.0, 1.0, 1.0,
+        1.0, 0.0, 1.0, 0.0, 0.0,
+        0.0, 1.0, 1.0, 0.0, 0.0,
+    ]);
+
+    let ldlt = m.ldlt();
+    assert_eq!(ldlt.inertia(1.0e-10), (3, 2, 0));
+}
+
+#[test]
+fn ldlt_singular() {
+    let m = DMatrix::from_row_slice(3, 3, &[
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        3.0, 6.0, 9.0,
+    ]);
+
+    let ldlt = m.ldlt();
+    assert_eq!(ldlt.inertia(1.0e-10), (1, 0, 2));
+    assert!(ldlt.try_inverse().is_none());
+    assert!(ldlt.solve(&DMatrix::<f64>::identity(3, 1)).is_none());
+    match ldlt.try_solve(&DMatrix::<f64>::identity(3, 1)) {
+        Err(LinalgError::Singular { .. }) => {}
+        _ => panic!("expected a singular matrix error"),
+    }
+    assert_relative_eq!(ldlt.determinant(), 0.0, epsilon = 1.0e-10);
+}
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: ty) => {
+        mod $module {
+            use na::{DMatrix, DVector, Matrix4, Matrix4x3, Vector4, ComplexField};
+            use std::cmp;
+            #[allow(unused_imports)]
+            use crate::core::helper::{RandScalar, RandComplex};
+
+            quickcheck! {
+                fn ldlt(m: DMatrix<$scalar>) -> bool {
+                    let n = cmp::min(m.nrows(), 25);
+                    let m = m.map(|e| e.0).resize(n, n, na::zero());
+                    let m = &m + m.adjoint();
+
+                    let ldlt = m.clone().ldlt();
+                    let (l, d) = (ldlt.l(), ldlt.d());
+                    let mut pm = m.clone();
+                    ldlt.p().permute_rows(&mut pm);
+                    ldlt.p().permute_columns(&mut pm);
+
+                    relative_eq!(pm, &l * d * l.adjoint(), epsilon = 1.0e-7)
+                }
+
+                fn ldlt_static(m: Matrix4<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let m = m + m.adjoint();
+
+                    let ldlt = m.ldlt();
+                    let (l, d) = (ldlt.l(), ldlt.d());
+                    let mut pm = m;
+                    ldlt.p().permute_rows(&mut pm);
+                    ldlt.p().permute_columns(&mut pm);
+
+                    relative_eq!(pm, l * d * l.adjoint(), epsilon = 1.0e-7)
+                }
+
+                fn ldlt_solve(n: usize, nb: usize) -> bool {
+                    let n  = cmp::max(1, cmp::min(n, 25));
+                    let nb = cmp::min(nb, 25);
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                    let m = &m + m.adjoint();
+
+                    let ldlt = m.clone().ldlt();
+                    let b1 = DVector::<$scalar>::new_random(n).map(|e| e.0);
+                    let b2 = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+
+                    let sol1 = ldlt.solve(&b1);
+                    let sol2 = ldlt.solve(&b2);
+
+                    match (sol1, sol2) {
+                        (Some(sol1), Some(sol2)) => {
+                            relative_eq!(&m * sol1, b1, epsilon = 1.0e-6) &&
+                            relative_eq!(&m * sol2, b2, epsilon = 1.0e-6)
+                        }
+                        _ => false
+                    }
+                }
+
+                fn ldlt_solve_static(m: Matrix4<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let m = m + m.adjoint();
+
+                    let ldlt = m.ldlt();
+                    let b1 = Vector4::<$scalar>::new_random().map(|e| e.0);
+                    let b2 = Matrix4x3::<$scalar>::new_random().map(|e| e.0);
+
+                    match (ldlt.solve(&b1), ldlt.solve(&b2)) {
+                        (Some(sol1), Some(sol2)) => {
+                            relative_eq!(m * sol1, b1, epsilon = 1.0e-6) &&
+                            relative_eq!(m * sol2, b2, epsilon = 1.0e-6)
+                        }
+                        _ => false
+                    }
+                }
+
+                fn ldlt_inverse(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 15));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                    let m = &m + m.adjoint();
+
+                    let m1 = m.clone().ldlt().try_inverse().unwrap();
+                    let id1 = &m  * &m1;
+                    let id2 = &m1 * &m;
+
+                    id1.is_identity(1.0e-5) && id2.is_identity(1.0e-5)
+                }
+
+                fn ldlt_determinant(m: Matrix4<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let m = m + m.adjoint();
+
+                    relative_eq!(m.ldlt().determinant(), m.determinant().real(), epsilon = 1.0e-7, max_relative = 1.0e-7)
+                }
+
+                fn ldlt_inertia(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 15));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                    let m = &m + m.adjoint();
+
+                    let eigvals = m.clone().symmetric_eigenvalues();
+                    let pos = eigvals.iter().filter(|e| **e > 0.0).count();
+                    let neg = eigvals.iter().filter(|e| **e < 0.0).count();
+
+                    m.ldlt().inertia(1.0e-10) == (pos, neg, 0)
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, RandComplex<f64>);
+gen_tests!(f64, RandScalar<f64>);
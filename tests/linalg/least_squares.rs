@@ -0,0 +1,132 @@
+#![cfg(feature = "arbitrary")]
+
+use na::{DMatrix, DVector, LeastSquaresMethod, Matrix3x2, Vector3};
+
+#[test]
+fn least_squares_line_fitting() {
+    // Fit `y = a * x + b` to the points (0, 1), (1, 3), (2, 4).
+    let m = Matrix3x2::new(
+        0.0, 1.0,
+        1.0, 1.0,
+        2.0, 1.0,
+    );
+    let b = Vector3::new(1.0, 3.0, 4.0);
+
+    for method in &[LeastSquaresMethod::QR, LeastSquaresMethod::COD, LeastSquaresMethod::SVD] {
+        let lsq = m.solve_least_squares_with(&b, *method, 1.0e-10);
+
+        assert_eq!(lsq.rank, 2);
+        assert_relative_eq!(lsq.solution[0], 1.5, epsilon = 1.0e-10);
+        assert_relative_eq!(lsq.solution[1], 7.0 / 6.0, epsilon = 1.0e-10);
+        assert_relative_eq!(lsq.residual_norm, (1.0f64 / 6.0).sqrt(), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn least_squares_minimum_norm() {
+    // Infinitely many solutions `x + y = 2`. The one with the smallest norm is `(1, 1)`.
+    let m = DMatrix::from_row_slice(2, 2, &[1.0, 1.0, 1.0, 1.0]);
+    let b = DVector::from_row_slice(&[2.0, 2.0]);
+
+    let lsq = m.solve_least_squares(&b);
+    assert_eq!(lsq.rank, 1);
+    assert_relative_eq!(lsq.solution, DVector::from_row_slice(&[1.0, 1.0]), epsilon = 1.0e-10);
+    assert_relative_eq!(lsq.residual_norm, 0.0, epsilon = 1.0e-10);
+
+    let basic = m.solve_least_squares_with(&b, LeastSquaresMethod::QR, 1.0e-10);
+    assert_eq!(basic.rank, 1);
+    assert_eq!(basic.solution.iter().filter(|e| **e != 0.0).count(), 1);
+    assert_relative_eq!(basic.residual_norm, 0.0, epsilon = 1.0e-10);
+}
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: ty) => {
+        mod $module {
+            use na::{DMatrix, LeastSquaresMethod, Matrix5x3, Matrix3x5, Matrix5x2, Matrix3x2};
+            use std::cmp;
+            #[allow(unused_imports)]
+            use crate::core::helper::{RandScalar, RandComplex};
+
+            quickcheck! {
+                fn least_squares_overdetermined(nrows: usize, ncols: usize, nb: usize) -> bool {
+                    let ncols = cmp::max(1, cmp::min(ncols, 10));
+                    let nrows = ncols + cmp::min(nrows, 10);
+                    let nb    = cmp::max(1, cmp::min(nb, 5));
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+                    let b = DMatrix::<$scalar>::new_random(nrows, nb).map(|e| e.0);
+
+                    let qr  = m.solve_least_squares_with(&b, LeastSquaresMethod::QR, 1.0e-10);
+                    let cod = m.solve_least_squares_with(&b, LeastSquaresMethod::COD, 1.0e-10);
+                    let svd = m.solve_least_squares_with(&b, LeastSquaresMethod::SVD, 1.0e-10);
+
+                    // The residual must be orthogonal to the column space.
+                    let normal = m.ad_mul(&(&m * &cod.solution - &b));
+
+                    cod.rank == ncols && qr.rank == ncols && svd.rank == ncols &&
+                    normal.norm() < 1.0e-7 &&
+                    (&qr.solution - &cod.solution).norm() < 1.0e-7 &&
+                    (&svd.solution - &cod.solution).norm() < 1.0e-7 &&
+                    (cod.residual_norm - (&m * &cod.solution - &b).norm()).abs() < 1.0e-7
+                }
+
+                fn least_squares_underdetermined(nrows: usize, ncols: usize) -> bool {
+                    let nrows = cmp::max(1, cmp::min(nrows, 10));
+                    let ncols = nrows + cmp::min(ncols, 10);
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+                    let b = DMatrix::<$scalar>::new_random(nrows, 2).map(|e| e.0);
+
+                    let qr  = m.solve_least_squares_with(&b, LeastSquaresMethod::QR, 1.0e-10);
+                    let cod = m.solve_least_squares_with(&b, LeastSquaresMethod::COD, 1.0e-10);
+                    let svd = m.solve_least_squares_with(&b, LeastSquaresMethod::SVD, 1.0e-10);
+
+                    // All solutions are exact but only the COD and SVD ones have a minimum norm.
+                    qr.residual_norm < 1.0e-7 && cod.residual_norm < 1.0e-7 && svd.residual_norm < 1.0e-7 &&
+                    (&svd.solution - &cod.solution).norm() < 1.0e-7 &&
+                    cod.solution.norm() <= qr.solution.norm() + 1.0e-7
+                }
+
+                fn least_squares_rank_deficient(n: usize, rank: usize) -> bool {
+                    let n    = cmp::max(2, cmp::min(n, 10));
+                    let rank = cmp::max(1, cmp::min(rank, n - 1));
+                    let a = DMatrix::<$scalar>::new_random(n + 2, rank).map(|e| e.0);
+                    let c = DMatrix::<$scalar>::new_random(rank, n).map(|e| e.0);
+                    let m = a * c;
+                    let b = DMatrix::<$scalar>::new_random(n + 2, 1).map(|e| e.0);
+
+                    let lsq = m.solve_least_squares_with(&b, LeastSquaresMethod::COD, 1.0e-8);
+                    let svd = m.solve_least_squares_with(&b, LeastSquaresMethod::SVD, 1.0e-8);
+                    let qr  = m.solve_least_squares_with(&b, LeastSquaresMethod::QR, 1.0e-8);
+
+                    lsq.rank == rank && svd.rank == rank && qr.rank == rank &&
+                    (&lsq.solution - &svd.solution).norm() < 1.0e-6 &&
+                    (lsq.residual_norm - qr.residual_norm).abs() < 1.0e-6
+                }
+
+                fn least_squares_static_5_3(m: Matrix5x3<$scalar>, b: Matrix5x2<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let b = b.map(|e| e.0);
+
+                    let cod = m.solve_least_squares(&b);
+                    let svd = m.solve_least_squares_with(&b, LeastSquaresMethod::SVD, 1.0e-10);
+
+                    (&svd.solution - &cod.solution).norm() < 1.0e-7 * (1.0 + cod.solution.norm()) &&
+                    m.ad_mul(&(m * cod.solution - b)).norm() < 1.0e-7 * m.norm() * b.norm()
+                }
+
+                fn least_squares_static_3_5(m: Matrix3x5<$scalar>, b: Matrix3x2<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let b = b.map(|e| e.0);
+
+                    let cod = m.solve_least_squares(&b);
+                    let svd = m.solve_least_squares_with(&b, LeastSquaresMethod::SVD, 1.0e-10);
+
+                    (&svd.solution - &cod.solution).norm() < 1.0e-7 * (1.0 + cod.solution.norm()) &&
+                    cod.residual_norm < 1.0e-7 * b.norm()
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, RandComplex<f64>);
+gen_tests!(f64, RandScalar<f64>);
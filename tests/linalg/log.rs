@@ -0,0 +1,145 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::f64;
+
+use na::{DMatrix, Matrix2, Matrix3, Rotation3, Unit, Vector3};
+
+#[test]
+fn ln_identity() {
+    let m = Matrix3::<f64>::identity();
+    assert!(relative_eq!(m.ln().unwrap(), Matrix3::zeros(), epsilon = 1.0e-7));
+}
+
+#[test]
+fn ln_diagonal() {
+    let m = Matrix3::new(1.0, 0.0,  0.0,
+                         0.0, 5.0,  0.0,
+                         0.0, 0.0, 1.0e5);
+    let expected = Matrix3::new(0.0, 0.0,          0.0,
+                                0.0, 5.0f64.ln(), 0.0,
+                                0.0, 0.0,          1.0e5f64.ln());
+
+    assert!(relative_eq!(m.ln().unwrap(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn ln_rotation() {
+    let angle = 2.5f64;
+    let m = Matrix2::new(angle.cos(), -angle.sin(),
+                         angle.sin(),  angle.cos());
+    let expected = Matrix2::new(0.0, -angle,
+                                angle, 0.0);
+
+    assert!(relative_eq!(m.ln().unwrap(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn ln_quarter_turn() {
+    // The real Schur form of this matrix has zero diagonal elements but it is invertible.
+    let m = Matrix2::new(0.0, -1.0,
+                         1.0,  0.0);
+    let expected = Matrix2::new(0.0,                    -f64::consts::FRAC_PI_2,
+                                f64::consts::FRAC_PI_2,  0.0);
+
+    assert!(relative_eq!(m.ln().unwrap(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn ln_rotation3_quarter_turn() {
+    let axes = [Vector3::z_axis(), Vector3::x_axis(), Unit::new_normalize(Vector3::new(1.0, 2.0, 3.0))];
+
+    for axis in axes.iter() {
+        let rot = Rotation3::from_axis_angle(axis, f64::consts::FRAC_PI_2);
+        let expected = (axis.into_inner() * f64::consts::FRAC_PI_2).cross_matrix();
+
+        assert!(relative_eq!(rot.matrix().ln().unwrap(), expected, epsilon = 1.0e-7));
+    }
+
+    // The same rotation around the `z` axis, without rounding errors.
+    let m = Matrix3::new(0.0, -1.0, 0.0,
+                         1.0,  0.0, 0.0,
+                         0.0,  0.0, 1.0);
+    let expected = (Vector3::z() * f64::consts::FRAC_PI_2).cross_matrix();
+    assert!(relative_eq!(m.ln().unwrap(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn ln_singular() {
+    let m = Matrix2::new(1.0, 2.0,
+                         2.0, 4.0);
+    assert!(m.ln().is_none());
+}
+
+#[test]
+fn ln_exp() {
+    let m = DMatrix::from_row_slice(3, 3, &[
+         0.1, -0.5, 0.3,
+         0.5,  0.2, 0.0,
+        -0.3,  0.1, 0.4]);
+
+    assert!(relative_eq!(m.exp().ln().unwrap(), m, epsilon = 1.0e-7));
+}
+
+#[test]
+fn powf() {
+    let m = Matrix3::new(4.0, 1.0, 0.0,
+                         1.0, 3.0, 1.0,
+                         0.0, 1.0, 2.0);
+
+    assert!(relative_eq!(m.powf(0.0).unwrap(), Matrix3::identity(), epsilon = 1.0e-7));
+    assert!(relative_eq!(m.powf(1.0).unwrap(), m, epsilon = 1.0e-7));
+    assert!(relative_eq!(m.powf(2.0).unwrap(), m * m, epsilon = 1.0e-7));
+    assert!(relative_eq!(m.powf(-1.0).unwrap(), m.try_inverse().unwrap(), epsilon = 1.0e-7));
+    assert!(relative_eq!(m.powf(0.5).unwrap(), m.sqrtm().unwrap(), epsilon = 1.0e-7));
+}
+
+#[test]
+fn powf_quarter_turn() {
+    let m = Matrix2::new(0.0, -1.0,
+                         1.0,  0.0);
+    let angle = f64::consts::FRAC_PI_4;
+    let eighth_turn = Matrix2::new(angle.cos(), -angle.sin(),
+                                   angle.sin(),  angle.cos());
+
+    assert!(relative_eq!(m.powf(2.0).unwrap(), -Matrix2::identity(), epsilon = 1.0e-7));
+    assert!(relative_eq!(m.powf(0.5).unwrap(), eighth_turn, epsilon = 1.0e-7));
+
+    let rot = Rotation3::from_axis_angle(&Vector3::z_axis(), f64::consts::FRAC_PI_2);
+    let expected = Rotation3::from_axis_angle(&Vector3::z_axis(), 1.5 * f64::consts::FRAC_PI_2);
+    assert!(relative_eq!(rot.matrix().powf(1.5).unwrap(), *expected.matrix(), epsilon = 1.0e-7));
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use std::cmp;
+                use na::{DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn exp_ln(n: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        // Ensures all the eigenvalues have positive real parts.
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let m = &m + DMatrix::identity(n, n).scale(n as f64);
+
+                        (m.ln().unwrap().exp() - &m).norm() < 1.0e-7 * m.norm()
+                    }
+
+                    fn exp_ln_static(m: Matrix4<$scalar>) -> bool {
+                        let m = m.map(|e| e.0);
+                        let m = m * m.adjoint() + Matrix4::identity();
+
+                        (m.ln().unwrap().exp() - m).norm() < 1.0e-7 * m.norm()
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
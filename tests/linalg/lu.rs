@@ -1,6 +1,6 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
-use na::Matrix3;
+use na::{LinalgError, Matrix3, Vector3};
 
 #[test]
 fn lu_simple() {
@@ -38,6 +38,38 @@ fn lu_simple_with_pivot() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+fn lu_rcond_estimate() {
+    // The Hilbert matrix is notoriously ill-conditioned.
+    let hilbert = na::DMatrix::from_fn(10, 10, |i, j| 1.0 / ((i + j + 1) as f64));
+    assert!(hilbert.rcond_estimate() < 1.0e-12);
+
+    let singular = Matrix3::new(
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        0.0, 1.0, 2.0);
+    assert_eq!(singular.rcond_estimate(), 0.0);
+
+    assert_eq!(Matrix3::<f64>::identity().rcond_estimate(), 1.0);
+}
+
+#[test]
+fn lu_try_solve_singular() {
+    let m = Matrix3::new(
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        0.0, 1.0, 2.0);
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    let mut x = b;
+
+    assert_eq!(m.lu().try_solve(&b), Err(LinalgError::Singular { pivot: 2 }));
+    assert_eq!(m.full_piv_lu().try_solve_mut(&mut x), Err(LinalgError::Singular { pivot: 2 }));
+    assert_eq!(x, b);
+
+    let x = Matrix3::<f64>::identity().lu().try_solve(&b).unwrap();
+    assert_eq!(x, b);
+}
+
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
     #[allow(unused_imports)]
@@ -150,6 +182,21 @@ mod quickcheck_tests {
                         return id1.is_identity(1.0e-5) && id2.is_identity(1.0e-5);
                     }
 
+                    fn lu_rcond_estimate(n: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 15));
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+
+                        match m.clone().try_inverse() {
+                            Some(m1) => {
+                                // The estimate is a lower bound of the norm of the inverse.
+                                let rcond = 1.0 / (m.induced_norm_1() * m1.induced_norm_1());
+                                let est = m.rcond_estimate();
+                                est >= rcond * (1.0 - 1.0e-7) && est <= rcond * 10.0
+                            }
+                            None => true
+                        }
+                    }
+
                     fn lu_inverse_static(m: Matrix4<$scalar>) -> bool {
                         let m = m.map(|e| e.0);
                         let lu  = m.lu();
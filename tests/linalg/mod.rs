@@ -1,14 +1,24 @@
 mod balancing;
 mod bidiagonal;
 mod cholesky;
+mod col_piv_qr;
 mod eigen;
+mod exp;
 mod full_piv_lu;
+mod generalized_eigen;
 mod hessenberg;
 mod inverse;
+mod krylov;
+mod ldlt;
+mod least_squares;
+mod log;
 mod lu;
+mod partial_eigen;
+mod polar;
 mod qr;
 mod schur;
 mod solve;
+mod sqrt;
 mod svd;
 mod tridiagonal;
 mod convolution;
\ No newline at end of file
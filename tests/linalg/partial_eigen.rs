@@ -0,0 +1,111 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{self, Complex, ComplexField, DMatrix, DVector, EigenTarget};
+
+// A symmetric matrix with well-separated eigenvalues of both signs.
+fn symmetric(n: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n, n, |i, j| {
+        let coupling = ((i * 7 + j * 7 + i * j) % 11) as f64 / 11.0 - 0.5;
+        if i == j { (i as f64 - n as f64 / 3.0) * 2.0 + coupling } else { coupling }
+    })
+}
+
+// An unsymmetric matrix with complex eigenvalues.
+fn unsymmetric(n: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            (i % 13) as f64 + i as f64 / n as f64
+        } else if i + 1 == j {
+            1.5
+        } else if j + 1 == i {
+            -1.5
+        } else {
+            ((i * 5 + j * 3) % 7) as f64 / 70.0
+        }
+    })
+}
+
+fn start_vector(n: usize) -> DVector<f64> {
+    DVector::from_fn(n, |i, _| 1.0 + ((i * 37) % 17) as f64 / 17.0)
+}
+
+fn sorted_eigenvalues(values: Vec<Complex<f64>>, target: EigenTarget) -> Vec<Complex<f64>> {
+    let key = |z: &Complex<f64>| match target {
+        EigenTarget::LargestMagnitude => -z.norm_sqr(),
+        EigenTarget::SmallestMagnitude => z.norm_sqr(),
+        EigenTarget::LargestReal => -z.re,
+        EigenTarget::SmallestReal => z.re,
+    };
+    let mut values = values;
+    values.sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap());
+    values
+}
+
+#[test]
+fn partial_eigen_lanczos() {
+    let n = 80;
+    let nev = 4;
+    let a = symmetric(n);
+    let all: Vec<_> = a.clone().symmetric_eigenvalues().iter().map(|e| Complex::new(*e, 0.0)).collect();
+
+    for target in &[EigenTarget::LargestMagnitude, EigenTarget::LargestReal, EigenTarget::SmallestReal] {
+        let res = na::lanczos(&a, &start_vector(n), nev, *target, 20, 1.0e-12, 500).unwrap();
+        let expected = sorted_eigenvalues(all.clone(), *target);
+
+        for k in 0..nev {
+            let x = res.eigenvectors.column(k);
+            let lambda = res.eigenvalues[k];
+            assert_relative_eq!(lambda, expected[k].re, epsilon = 1.0e-8);
+            assert_relative_eq!(x.norm(), 1.0, epsilon = 1.0e-10);
+            assert!((&a * x - x * lambda).norm() <= 1.0e-8 * lambda.abs());
+        }
+    }
+}
+
+#[test]
+fn partial_eigen_arnoldi() {
+    let n = 70;
+    let nev = 5;
+    let a = unsymmetric(n);
+    let all: Vec<_> = a.complex_eigenvalues().iter().cloned().collect();
+    let ac = a.map(|e| Complex::new(e, 0.0));
+
+    for target in &[EigenTarget::LargestMagnitude, EigenTarget::LargestReal, EigenTarget::SmallestReal] {
+        let res = na::arnoldi(&a, &start_vector(n), nev, *target, 25, 1.0e-12, 500).unwrap();
+        let expected = sorted_eigenvalues(all.clone(), *target);
+
+        for k in 0..nev {
+            let x = res.eigenvectors.column(k);
+            let lambda = res.eigenvalues[k];
+
+            // Conjugate pairs may be returned in any order.
+            assert!(expected.iter().take(nev + 1).any(|e| (e - lambda).modulus() <= 1.0e-8 * lambda.modulus()));
+            assert!((target == &EigenTarget::LargestMagnitude) || (lambda.re - expected[k].re).abs() <= 1.0e-8 * lambda.modulus());
+            assert!((&ac * x - x * lambda).norm() <= 1.0e-8 * lambda.modulus());
+        }
+    }
+}
+
+#[test]
+fn partial_eigen_full_subspace() {
+    // The Krylov subspace spans the whole space so no restart is needed.
+    let n = 10;
+    let a = symmetric(n);
+    let res = na::lanczos(&a, &start_vector(n), 3, EigenTarget::LargestMagnitude, n, 1.0e-12, 1).unwrap();
+    let expected = sorted_eigenvalues(
+        a.clone().symmetric_eigenvalues().iter().map(|e| Complex::new(*e, 0.0)).collect(),
+        EigenTarget::LargestMagnitude,
+    );
+
+    assert_eq!(res.niter, 0);
+    for k in 0..3 {
+        assert_relative_eq!(res.eigenvalues[k], expected[k].re, epsilon = 1.0e-10);
+    }
+
+    // A starting vector in an invariant subspace.
+    let a = DMatrix::from_diagonal(&DVector::from_fn(n, |i, _| i as f64 + 1.0));
+    let v0 = DVector::from_fn(n, |i, _| if i < 2 { 1.0 } else { 0.0 });
+    let res = na::arnoldi(&a, &v0, 2, EigenTarget::LargestReal, 6, 1.0e-12, 100).unwrap();
+    assert_relative_eq!(res.eigenvalues[0].re, 10.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.eigenvalues[1].re, 9.0, epsilon = 1.0e-10);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "arbitrary")]
+
+use na::Matrix2;
+
+#[test]
+fn polar_newton_singular() {
+    let m = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+    assert!(m.try_polar_newton(1.0e-12, 100).is_none());
+
+    let polar = m.polar();
+    assert_relative_eq!(polar.recompose(), m, epsilon = 1.0e-10);
+}
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: ty) => {
+        mod $module {
+            use na::{DMatrix, Matrix3, Matrix4x3, Matrix3x4};
+            use std::cmp;
+            #[allow(unused_imports)]
+            use crate::core::helper::{RandScalar, RandComplex};
+
+            quickcheck! {
+                fn polar(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 15));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                    let polar = m.clone().polar();
+
+                    polar.u.ad_mul(&polar.u).is_identity(1.0e-7) &&
+                    relative_eq!(polar.p, polar.p.adjoint(), epsilon = 1.0e-7) &&
+                    polar.p.clone().symmetric_eigenvalues().iter().all(|e| *e > -1.0e-7) &&
+                    relative_eq!(polar.recompose(), m, epsilon = 1.0e-7)
+                }
+
+                fn polar_rectangular(m1: Matrix4x3<$scalar>, m2: Matrix3x4<$scalar>) -> bool {
+                    let m1 = m1.map(|e| e.0);
+                    let m2 = m2.map(|e| e.0);
+                    let polar1 = m1.polar();
+                    let polar2 = m2.polar();
+
+                    polar1.u.ad_mul(&polar1.u).is_identity(1.0e-7) &&
+                    (polar2.u * polar2.u.adjoint()).is_identity(1.0e-7) &&
+                    relative_eq!(polar1.recompose(), m1, epsilon = 1.0e-7) &&
+                    relative_eq!(polar2.recompose(), m2, epsilon = 1.0e-7)
+                }
+
+                fn polar_newton(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 15));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                    let svd = m.clone().polar();
+
+                    match m.try_polar_newton(1.0e-12, 100) {
+                        Some(newton) => {
+                            relative_eq!(newton.u, svd.u, epsilon = 1.0e-6) &&
+                            relative_eq!(newton.p, svd.p, epsilon = 1.0e-6)
+                        }
+                        None => false
+                    }
+                }
+
+                fn polar_newton_static(m: Matrix3<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+
+                    match m.try_polar_newton(1.0e-12, 100) {
+                        Some(newton) => {
+                            newton.u.ad_mul(&newton.u).is_identity(1.0e-7) &&
+                            relative_eq!(newton.recompose(), m, epsilon = 1.0e-7)
+                        }
+                        None => false
+                    }
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, RandComplex<f64>);
+gen_tests!(f64, RandScalar<f64>);
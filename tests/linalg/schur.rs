@@ -1,6 +1,6 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
-use na::{DMatrix, Matrix3, Matrix4};
+use na::{DMatrix, LinalgError, Matrix3, Matrix4, Schur};
 
 #[test]
 fn schur_simpl_mat3() {
@@ -149,3 +149,15 @@ fn schur_singular() {
     let (vecs, vals) = m.clone().schur().unpack();
     assert!(relative_eq!(&vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7))
 }
+
+#[test]
+fn schur_no_convergence() {
+    let m = Matrix4::new(
+        -2.0, 1.0, 3.0, 4.0,
+        5.0, -6.0, 7.0, 8.0,
+        9.0, 10.0, -11.0, 12.0,
+        13.0, 14.0, 15.0, -16.0);
+
+    assert_eq!(Schur::try_decompose(m, 1.0e-15, 1).err(), Some(LinalgError::NoConvergence { niter: 1 }));
+    assert!(Schur::try_decompose(m, 1.0e-15, 0).is_ok());
+}
@@ -0,0 +1,89 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{Complex, DMatrix, Matrix2, Matrix3};
+
+#[test]
+fn sqrtm_diagonal() {
+    let m = Matrix3::new(4.0, 0.0, 0.0,
+                         0.0, 9.0, 0.0,
+                         0.0, 0.0, 2.0);
+    let expected = Matrix3::new(2.0, 0.0, 0.0,
+                                0.0, 3.0, 0.0,
+                                0.0, 0.0, 2.0f64.sqrt());
+
+    assert!(relative_eq!(m.sqrtm().unwrap(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn sqrtm_rotation() {
+    // The square root of a rotation is the rotation with half the angle.
+    let angle = 2.0f64;
+    let m = Matrix2::new(angle.cos(), -angle.sin(),
+                         angle.sin(),  angle.cos());
+    let expected = Matrix2::new((angle / 2.0).cos(), -(angle / 2.0).sin(),
+                                (angle / 2.0).sin(),  (angle / 2.0).cos());
+
+    assert!(relative_eq!(m.sqrtm().unwrap(), expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn sqrtm_negative_eigenvalue() {
+    let m = Matrix2::new(-1.0, 0.0,
+                          0.0, 1.0);
+    assert!(m.sqrtm().is_none());
+
+    // This has a square root with complex entries.
+    let m = m.map(|e| Complex::new(e, 0.0));
+    let sqrt = m.sqrtm().unwrap();
+    assert!((sqrt * sqrt - m).norm() < 1.0e-7);
+}
+
+#[test]
+fn sqrtm_mixed_blocks() {
+    // Has both real eigenvalues and a pair of complex conjugate eigenvalues.
+    let m = DMatrix::from_row_slice(4, 4, &[
+        4.0, -3.0, 1.0, 0.5,
+        3.0,  4.0, 2.0, 0.0,
+        0.0,  0.0, 5.0, 1.0,
+        0.0,  0.0, 0.0, 2.0]);
+    let sqrt = m.sqrtm().unwrap();
+
+    assert!(relative_eq!(&sqrt * &sqrt, m, epsilon = 1.0e-7));
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use std::cmp;
+                use na::{DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn sqrtm(n: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        // Ensures all the eigenvalues have positive real parts.
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let m = &m + DMatrix::identity(n, n).scale(n as f64);
+                        let sqrt = m.sqrtm().unwrap();
+
+                        (&sqrt * &sqrt - &m).norm() < 1.0e-7 * m.norm()
+                    }
+
+                    fn sqrtm_static(m: Matrix4<$scalar>) -> bool {
+                        let m = m.map(|e| e.0);
+                        let m = m * m.adjoint() + Matrix4::identity();
+                        let sqrt = m.sqrtm().unwrap();
+
+                        (sqrt * sqrt - m).norm() < 1.0e-7 * m.norm()
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
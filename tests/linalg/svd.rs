@@ -1,5 +1,5 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
-use na::{DMatrix, Matrix6};
+use na::{DMatrix, LinalgError, Matrix6};
 
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
@@ -120,6 +120,25 @@ mod quickcheck_tests {
                         }
                     }
 
+                    fn svd_subspaces(nrows: usize, ncols: usize, rank: usize) -> bool {
+                        let nrows = cmp::max(1, cmp::min(nrows, 10));
+                        let ncols = cmp::max(1, cmp::min(ncols, 10));
+                        let rank  = cmp::max(1, cmp::min(rank, cmp::min(nrows, ncols)));
+                        let a = DMatrix::<$scalar>::new_random(nrows, rank).map(|e| e.0);
+                        let b = DMatrix::<$scalar>::new_random(rank, ncols).map(|e| e.0);
+                        let m = a * b;
+
+                        let null_space = m.null_space(1.0e-7).unwrap();
+                        let column_space = m.column_space(1.0e-7).unwrap();
+
+                        null_space.ncols() == ncols - rank &&
+                        column_space.ncols() == rank &&
+                        (&m * &null_space).norm() < 1.0e-7 &&
+                        null_space.ad_mul(&null_space).is_identity(1.0e-7) &&
+                        column_space.ad_mul(&column_space).is_identity(1.0e-7) &&
+                        relative_eq!(&column_space * column_space.ad_mul(&m), m, epsilon = 1.0e-7)
+                    }
+
                     fn svd_solve(n: usize, nb: usize) -> bool {
                         let n = cmp::max(1, cmp::min(n, 10));
                         let nb = cmp::min(nb, 10);
@@ -353,4 +372,100 @@ fn svd_err() {
     let svd = m.clone().svd(false, false);
     assert_eq!(Err("SVD recomposition: U and V^t have not been computed."), svd.clone().recompose());
     assert_eq!(Err("SVD pseudo inverse: the epsilon must be non-negative."), svd.clone().pseudo_inverse(-1.0));
-}
\ No newline at end of file
+}
+
+#[test]
+fn svd_rank_one() {
+    let m = DMatrix::from_column_slice(3, 2, &[
+        0.0038577217202183647, 0.04104821976110996, 0.08963356892042577,
+        0.008076478956110566,  0.08593805026134457, 0.18765574233952975]);
+    let svd = m.clone().svd(true, true);
+    assert_eq!(svd.rank(1.0e-10), 1);
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_condition_number() {
+    let m = na::Matrix2::new(
+        3.0, 0.0,
+        4.0, 5.0);
+
+    assert_relative_eq!(m.spectral_norm(), 45.0f64.sqrt(), epsilon = 1.0e-10);
+    assert_relative_eq!(m.condition_number(), 3.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_subspaces() {
+    // Rank 2 matrix with the null space spanned by `(1, 1, -1)`.
+    let m = DMatrix::<f64>::from_row_slice(4, 3, &[
+        1.0, 0.0, 1.0,
+        0.0, 1.0, 1.0,
+        1.0, 1.0, 2.0,
+        2.0, 1.0, 3.0]);
+
+    let null_space = m.null_space(1.0e-10).unwrap();
+    let column_space = m.column_space(1.0e-10).unwrap();
+
+    assert_eq!(null_space.ncols(), 1);
+    assert_eq!(column_space.ncols(), 2);
+    assert_relative_eq!(null_space.column(0).dot(&na::Vector3::new(1.0, 1.0, -1.0)).abs(), 3.0f64.sqrt(), epsilon = 1.0e-10);
+
+    // Each column of the matrix is a combination of the column space basis.
+    let proj = &column_space * column_space.transpose();
+    assert_relative_eq!(&proj * &m, m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_subspaces_negative_eps() {
+    let m = na::Matrix3::<f64>::identity();
+
+    assert_eq!(Err(LinalgError::InvalidTolerance), m.null_space(-1.0).map(|_| ()));
+    assert_eq!(Err(LinalgError::InvalidTolerance), m.column_space(-1.0).map(|_| ()));
+    assert_eq!(Err(LinalgError::MissingU), m.svd(false, true).column_space(1.0e-10).map(|_| ()));
+    assert_eq!(Err(LinalgError::MissingVt), m.svd(true, false).null_space(1.0e-10).map(|_| ()));
+}
+
+#[test]
+fn svd_column_space_sorted() {
+    let m = na::Matrix3::new(
+        1.0, 0.0, 0.0,
+        0.0, 3.0, 0.0,
+        0.0, 0.0, 2.0);
+
+    let column_space = m.column_space(1.0e-10).unwrap();
+    let expected = na::Matrix3::new(
+        0.0, 0.0, 1.0,
+        1.0, 0.0, 0.0,
+        0.0, 1.0, 0.0);
+
+    assert_eq!(column_space.ncols(), 3);
+
+    for j in 0..3 {
+        assert_relative_eq!(column_space.column(j).abs(), expected.column(j).into_owned(), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn svd_condition_number_empty() {
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).condition_number(), 0.0);
+}
+
+
+#[test]
+fn svd_missing_singular_vectors() {
+    let m = na::Matrix2x3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0);
+    let b = na::Vector2::new(1.0, 2.0);
+
+    assert_eq!(m.svd(false, true).try_recompose(), Err(LinalgError::MissingU));
+    assert_eq!(m.svd(true, false).try_pseudo_inverse(1.0e-10), Err(LinalgError::MissingVt));
+    assert_eq!(m.svd(false, false).try_solve(&b, 1.0e-10), Err(LinalgError::MissingU));
+
+    let svd = m.svd(true, true);
+    assert_eq!(svd.try_solve(&b, -1.0), Err(LinalgError::InvalidTolerance));
+    assert_eq!(svd.try_pseudo_inverse(-1.0), Err(LinalgError::InvalidTolerance));
+    assert_eq!(svd.solve(&b, -1.0), Err("SVD solve: the epsilon must be non-negative."));
+    assert_relative_eq!(svd.try_solve(&b, 1.0e-10).unwrap(), svd.solve(&b, 1.0e-10).unwrap());
+    assert_relative_eq!(svd.try_recompose().unwrap(), m, epsilon = 1.0e-10);
+}
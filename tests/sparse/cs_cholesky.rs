@@ -1,6 +1,6 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
-use na::{CsMatrix, CsVector, CsCholesky, Cholesky, Matrix5, Vector5};
+use na::{CsMatrix, CsCholesky, Cholesky, Matrix5, Vector5};
 
 #[test]
 fn cs_cholesky() {
@@ -58,11 +58,11 @@ fn test_cholesky_variant(a: Matrix5<f32>, option: usize) {
         0 => chol_cs_a = CsCholesky::new(&cs_a),
         1 => {
             chol_cs_a = CsCholesky::new_symbolic(&cs_a);
-            chol_cs_a.decompose_up_looking(cs_a.data.values());
+            chol_cs_a.decompose_up_looking(cs_a.values());
         }
         _ => {
             chol_cs_a = CsCholesky::new_symbolic(&cs_a);
-            chol_cs_a.decompose_left_looking(cs_a.data.values());
+            chol_cs_a.decompose_left_looking(cs_a.values());
         }
     };
 
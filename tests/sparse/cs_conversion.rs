@@ -32,15 +32,7 @@ fn cs_matrix_from_triplet() {
         0.0, 0.0, 13.0, 0.0, 0.0,
         0.0, 1.0, 4.0, 0.0, 14.0,
     ]);
-    let cs_expected = CsMatrix::from_parts(
-        4,
-        5,
-        vec![0, 2, 5, 8, 10],
-        vec![0, 1, 0, 1, 3, 1, 2, 3, 0, 1, 0, 3],
-        vec![
-            5.0, 9.0, 6.0, 10.0, 1.0, 11.0, 13.0, 4.0, 8.0, 12.0, 15.0, 14.0,
-        ],
-    );
+    let cs_expected: CsMatrix<_> = expected.clone().into();
 
     let cs_mat = CsMatrix::from_triplet(4, 5, &irows, &icols, &vals);
     println!("Mat from triplet: {:?}", cs_mat);
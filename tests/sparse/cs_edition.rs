@@ -0,0 +1,97 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{CsMatrix, DMatrix, Dynamic, Matrix4x5, MatrixMN, U4};
+
+fn matrix() -> Matrix4x5<f64> {
+    Matrix4x5::new(
+        4.0, 1.0, 4.0, 0.0, 9.0,
+        5.0, 6.0, 0.0, 8.0, 10.0,
+        0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0, 10.0
+    )
+}
+
+#[test]
+fn cs_columns_range() {
+    let m = matrix();
+    let cs: CsMatrix<_, _, _> = m.into();
+    let cols = cs.columns_range(1..4);
+
+    assert!(cols.is_sorted());
+    assert_eq!(cols.shape(), (4, 3));
+    assert_eq!(MatrixMN::<f64, U4, Dynamic>::from(cols), m.columns(1, 3).into_owned());
+    assert_eq!(cs.columns_range(2..2).shape(), (4, 0));
+}
+
+#[test]
+fn cs_select() {
+    let m = DMatrix::from_column_slice(4, 5, matrix().as_slice());
+    let cs: CsMatrix<f64> = m.clone().into();
+
+    let rows = [3, 0, 3, 1];
+    let cols = [4, 2, 0];
+
+    let selected_rows = cs.select_rows(&rows);
+    assert!(selected_rows.is_sorted());
+    assert_eq!(DMatrix::from(selected_rows), m.select_rows(&rows));
+
+    let selected_cols = cs.select_columns(&cols);
+    assert!(selected_cols.is_sorted());
+    assert_eq!(DMatrix::from(selected_cols), m.select_columns(&cols));
+
+    let selected = cs.select(&rows, &cols);
+    assert!(selected.is_sorted());
+    assert_eq!(DMatrix::from(selected), m.select_rows(&rows).select_columns(&cols));
+}
+
+#[test]
+fn cs_stack() {
+    let a = DMatrix::from_row_slice(2, 2, &[4.0, 1.0, 1.0, 3.0]);
+    let b = DMatrix::from_row_slice(1, 2, &[1.0, 2.0]);
+    let cs_a: CsMatrix<f64> = a.clone().into();
+    let cs_b: CsMatrix<f64> = b.clone().into();
+    let cs_bt = cs_b.transpose();
+
+    // The saddle-point system [A Bᵀ; B 0].
+    let top = CsMatrix::hstack(&[&cs_a, &cs_bt]);
+    let bottom = CsMatrix::hstack(&[&cs_b, &CsMatrix::zeros(1, 1)]);
+    let saddle = CsMatrix::vstack(&[&top, &bottom]);
+
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        4.0, 1.0, 1.0,
+        1.0, 3.0, 2.0,
+        1.0, 2.0, 0.0,
+    ]);
+
+    assert!(saddle.is_sorted());
+    assert_eq!(saddle.len(), 8);
+    assert_eq!(DMatrix::from(saddle), expected);
+
+    let diag = CsMatrix::block_diagonal(&[&cs_a, &cs_b, &cs_a]);
+    let mut expected = DMatrix::zeros(5, 6);
+    expected.slice_mut((0, 0), (2, 2)).copy_from(&a);
+    expected.slice_mut((2, 2), (1, 2)).copy_from(&b);
+    expected.slice_mut((3, 4), (2, 2)).copy_from(&a);
+
+    assert!(diag.is_sorted());
+    assert_eq!(DMatrix::from(diag), expected);
+}
+
+#[test]
+fn cs_prune() {
+    let m = matrix();
+    let mut cs = CsMatrix::from_triplet(2, 3, &[0, 1, 0, 1, 1], &[0, 0, 1, 2, 2], &[1.0, 0.0, 2.0, 3.0, -3.0]);
+    assert_eq!(cs.len(), 4);
+    cs.drop_zeros();
+    assert_eq!(cs.len(), 2);
+    assert_eq!(DMatrix::from(cs), DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 0.0, 0.0, 0.0, 0.0]));
+
+    // Keep the strictly upper-triangular part with values smaller than 9.
+    let mut cs: CsMatrix<_, _, _> = m.into();
+    cs.prune(|i, j, val| i < j && *val < 9.0);
+    assert!(cs.is_sorted());
+
+    let mut expected = m.map(|e| if e < 9.0 { e } else { 0.0 });
+    expected.fill_lower_triangle(0.0, 0);
+    assert_eq!(Matrix4x5::from(cs), expected);
+}
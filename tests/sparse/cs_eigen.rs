@@ -0,0 +1,104 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::f64::consts::PI;
+use na::{Complex, ComplexField, CooMatrix, CsMatrix, DMatrix, EigenTarget};
+
+// The 5-point laplacian of a `nx x ny` grid, with an optional convection term along `x`.
+fn grid_laplacian_2d(nx: usize, ny: usize, convection: f64) -> CsMatrix<f64> {
+    let size = nx * ny;
+    let mut coo = CooMatrix::new(size, size);
+
+    for x in 0..nx {
+        for y in 0..ny {
+            let i = x * ny + y;
+            coo.push(i, i, 4.0);
+
+            if x + 1 < nx {
+                coo.push(i, i + ny, -1.0 + convection);
+                coo.push(i + ny, i, -1.0 - convection);
+            }
+
+            if y + 1 < ny {
+                coo.push(i, i + 1, -1.0);
+                coo.push(i + 1, i, -1.0);
+            }
+        }
+    }
+
+    coo.to_cs_matrix()
+}
+
+// The exact eigenvalues of the laplacian of a `nx x ny` grid, in increasing order.
+fn laplacian_eigenvalues(nx: usize, ny: usize) -> Vec<f64> {
+    let hx = PI / (nx + 1) as f64;
+    let hy = PI / (ny + 1) as f64;
+    let mut res = Vec::new();
+
+    for i in 1..=nx {
+        for j in 1..=ny {
+            res.push(4.0 - 2.0 * (hx * i as f64).cos() - 2.0 * (hy * j as f64).cos());
+        }
+    }
+
+    res.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    res
+}
+
+#[test]
+fn cs_eigen_symmetric() {
+    // Different grid dimensions avoid multiple eigenvalues, that Krylov methods may not find.
+    let (nx, ny) = (30, 23);
+    let m = grid_laplacian_2d(nx, ny, 0.0);
+    let expected = laplacian_eigenvalues(nx, ny);
+
+    let res = m.symmetric_eigen_shift_invert(0.0, 6, 1.0e-10, 100).unwrap();
+    for k in 0..6 {
+        let x = res.eigenvectors.column(k);
+        assert_relative_eq!(res.eigenvalues[k], expected[k], epsilon = 1.0e-8);
+        assert!((&m * &x - x * res.eigenvalues[k]).norm() <= 1.0e-8);
+    }
+
+    let res = m.symmetric_eigen_partial(3, EigenTarget::LargestReal, 1.0e-10, 1000).unwrap();
+    for k in 0..3 {
+        assert_relative_eq!(res.eigenvalues[k], expected[nx * ny - 1 - k], epsilon = 1.0e-8);
+    }
+}
+
+#[test]
+fn cs_eigen_unsymmetric() {
+    let m = grid_laplacian_2d(12, 10, 1.5);
+    let dense = DMatrix::from(m.clone());
+    let all: Vec<_> = dense.complex_eigenvalues().iter().cloned().collect();
+    let mc = dense.map(|e| Complex::new(e, 0.0));
+
+    // The eigenvalues closest to `sigma` up to conjugation.
+    let sigma = 1.0;
+    let mut distances: Vec<_> = all.iter().map(|e| (e - sigma).modulus()).collect();
+    distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let res = m.eigen_shift_invert(sigma, 4, 1.0e-10, 500).unwrap();
+    for k in 0..4 {
+        let x = res.eigenvectors.column(k);
+        let lambda = res.eigenvalues[k];
+        assert_relative_eq!((lambda - sigma).modulus(), distances[k], epsilon = 1.0e-7);
+        assert!(all.iter().any(|e| (e - lambda).modulus() <= 1.0e-7));
+        assert!((&mc * x - x * lambda).norm() <= 1.0e-7);
+    }
+
+    let largest = all.iter().map(|e| e.modulus()).fold(0.0, f64::max);
+    let res = m.eigen_partial(2, EigenTarget::LargestMagnitude, 1.0e-10, 1000).unwrap();
+    assert_relative_eq!(res.eigenvalues[0].modulus(), largest, epsilon = 1.0e-7);
+}
+
+#[test]
+fn cs_eigen_too_many_eigenpairs() {
+    let m = grid_laplacian_2d(2, 2, 0.0);
+
+    assert!(m.symmetric_eigen_partial(4, EigenTarget::LargestReal, 1.0e-10, 100).is_none());
+    assert!(m.eigen_partial(5, EigenTarget::LargestMagnitude, 1.0e-10, 100).is_none());
+    assert!(m.symmetric_eigen_shift_invert(0.5, 4, 1.0e-10, 100).is_none());
+    assert!(m.eigen_shift_invert(0.5, 4, 1.0e-10, 100).is_none());
+
+    let res = m.symmetric_eigen_partial(3, EigenTarget::LargestReal, 1.0e-10, 100).unwrap();
+    assert_eq!(res.eigenvalues.len(), 3);
+}
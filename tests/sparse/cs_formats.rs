@@ -0,0 +1,79 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{CooMatrix, CsMatrix, CsrMatrix, DMatrix, Matrix4x5, Matrix5x4};
+
+#[test]
+fn coo_push_and_convert() {
+    let mut coo = CooMatrix::new(3, 4);
+    coo.push(2, 1, 1.0);
+    coo.push(0, 3, 2.0);
+    coo.push(2, 1, 3.0); // Duplicate, summed on conversion.
+    coo.push(1, 0, -1.0);
+    coo.extend(vec![(0, 0, 5.0), (0, 3, 0.5)]);
+
+    assert_eq!(coo.len(), 6);
+    assert_eq!(coo.triplet_iter().next(), Some((2, 1, 1.0)));
+
+    let expected = DMatrix::from_row_slice(3, 4, &[
+        5.0, 0.0, 0.0, 2.5,
+        -1.0, 0.0, 0.0, 0.0,
+        0.0, 4.0, 0.0, 0.0,
+    ]);
+
+    let csc: CsMatrix<f64> = coo.clone().into();
+    assert!(csc.is_sorted());
+    assert_eq!(csc.len(), 4);
+    assert_eq!(DMatrix::from(csc.clone()), expected);
+
+    let csr: CsrMatrix<f64> = coo.clone().into();
+    assert!(csr.is_sorted());
+    assert_eq!(csr.len(), 4);
+    assert_eq!(DMatrix::from(csr), expected);
+
+    let back = CooMatrix::from(&csc);
+    assert_eq!(back.len(), 4);
+    assert_eq!(DMatrix::from(back.to_cs_matrix()), expected);
+
+    coo.clear();
+    assert!(coo.is_empty());
+    assert_eq!(coo.to_cs_matrix().len(), 0);
+}
+
+#[test]
+fn cs_from_triplet_duplicates() {
+    let m = CsMatrix::from_triplet(2, 2, &[1, 0, 0, 1, 0], &[1, 0, 0, 0, 0], &[5.0, 1.0, 2.0, 4.0, 3.0]);
+
+    assert!(m.is_sorted());
+    assert_eq!(m.len(), 3);
+    assert_eq!(DMatrix::from(m), DMatrix::from_row_slice(2, 2, &[6.0, 0.0, 4.0, 5.0]));
+}
+
+#[test]
+fn csr_conversions() {
+    let m = Matrix4x5::new(
+        4.0, 1.0, 4.0, 0.0, 9.0,
+        5.0, 6.0, 0.0, 8.0, 10.0,
+        0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0, 10.0
+    );
+
+    let csc: CsMatrix<_, _, _> = m.into();
+    let csr: CsrMatrix<_, _, _> = csc.clone().into();
+    assert!(csr.is_sorted());
+    assert_eq!(csr.shape(), (4, 5));
+    assert_eq!(csr.row_offsets(), &[0, 4, 8, 8]);
+    assert_eq!(csr.row_range(1), 4..8);
+    assert_eq!(csr.row_entries(3).collect::<Vec<_>>(), vec![(2, 1.0), (4, 10.0)]);
+    assert_eq!(csr.row_col_indices(0).collect::<Vec<_>>(), vec![0, 1, 2, 4]);
+    assert_eq!(csr.row_entries(2).count(), 0);
+
+    assert_eq!(CsMatrix::from(csr.clone()), csc);
+    assert_eq!(Matrix4x5::from(csr.clone()), m);
+    assert_eq!(CsrMatrix::from(m), csr);
+
+    // The transpose of a CSR matrix shares its buffers with a CSC matrix.
+    let transposed = csr.clone().into_transposed_csc();
+    assert_eq!(Matrix5x4::from(transposed.clone()), m.transpose());
+    assert_eq!(CsrMatrix::from_transposed_csc(transposed), csr);
+    assert_eq!(Matrix5x4::from(csr.transpose()), m.transpose());
+}
@@ -0,0 +1,145 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{self, CsIncompleteCholesky, CsIncompleteLU, CsMatrix, DMatrix, DVector, JacobiPreconditioner};
+
+// The 5-point Laplacian on a `n x n` grid.
+fn grid_laplacian(n: usize) -> DMatrix<f64> {
+    let mut m = DMatrix::zeros(n * n, n * n);
+
+    for i in 0..n {
+        for j in 0..n {
+            let k = i * n + j;
+            m[(k, k)] = 4.0;
+
+            if i + 1 < n {
+                m[(k, k + n)] = -1.0;
+                m[(k + n, k)] = -1.0;
+            }
+
+            if j + 1 < n {
+                m[(k, k + 1)] = -1.0;
+                m[(k + 1, k)] = -1.0;
+            }
+        }
+    }
+
+    m
+}
+
+// A sparse unsymmetric convection-diffusion-like matrix.
+fn convection_diffusion(n: usize) -> DMatrix<f64> {
+    let mut m = grid_laplacian(n);
+
+    for k in 0..n * n - 1 {
+        m[(k, k + 1)] += 0.5;
+        m[(k + 1, k)] -= 0.5;
+    }
+
+    m
+}
+
+#[test]
+fn cs_conjugate_gradient_incomplete_cholesky() {
+    let m = grid_laplacian(20);
+    let cs_m: CsMatrix<f64> = m.clone().into();
+    let b = DVector::from_fn(400, |i, _| (i % 7) as f64 - 3.0);
+    let expected = m.cholesky().unwrap().solve(&b);
+
+    let mut x = DVector::zeros(400);
+    let plain = na::conjugate_gradient(&cs_m, &b, &mut x, None, 1.0e-10, 1000);
+    assert!(plain.converged);
+    assert_relative_eq!(x, expected, epsilon = 1.0e-7);
+
+    let ic0 = CsIncompleteCholesky::new(&cs_m).unwrap();
+    assert!(ic0.l().is_sorted());
+    assert_eq!(ic0.l().len(), (cs_m.len() + 400) / 2);
+
+    let mut x = DVector::zeros(400);
+    let report = na::conjugate_gradient(&cs_m, &b, &mut x, Some(&ic0), 1.0e-10, 1000);
+    assert!(report.converged);
+    assert!(report.niter < plain.niter);
+    assert!(report.residual_norm <= 1.0e-8 * b.norm());
+    assert_relative_eq!(x, expected, epsilon = 1.0e-7);
+
+    let jacobi = JacobiPreconditioner::from_cs_matrix(&cs_m);
+    let mut x = DVector::zeros(400);
+    let report = na::minres(&cs_m, &b, &mut x, Some(&jacobi), 1.0e-10, 1000);
+    assert!(report.converged);
+    assert_relative_eq!(x, expected, epsilon = 1.0e-7);
+}
+
+#[test]
+fn cs_incomplete_cholesky_exact_on_tridiagonal() {
+    // IC(0) has no fill-in to drop on a tridiagonal matrix, so it is exact.
+    let m = DMatrix::from_fn(10, 10, |i, j| if i == j { 3.0 } else if i + 1 == j || j + 1 == i { -1.0 } else { 0.0 });
+    let cs_m: CsMatrix<f64> = m.clone().into();
+    let ic0 = CsIncompleteCholesky::new(&cs_m).unwrap();
+    let l: DMatrix<f64> = ic0.l().clone().into();
+
+    assert_relative_eq!(&l * l.transpose(), m, epsilon = 1.0e-12);
+}
+
+#[test]
+fn cs_minres_indefinite() {
+    let n = 100;
+    let m = DMatrix::from_fn(n, n, |i, j| if i == j { if i % 2 == 0 { 4.0 } else { -3.0 } } else if i + 1 == j || j + 1 == i { 1.0 } else { 0.0 });
+    let cs_m: CsMatrix<f64> = m.clone().into();
+    let b = DVector::from_fn(n, |i, _| 1.0 + i as f64 / n as f64);
+    let mut x = DVector::zeros(n);
+    let report = na::minres(&cs_m, &b, &mut x, None, 1.0e-12, 500);
+
+    assert!(report.converged);
+    assert_relative_eq!(x, m.lu().solve(&b).unwrap(), epsilon = 1.0e-8);
+}
+
+#[test]
+fn cs_unsymmetric_incomplete_lu() {
+    let m = convection_diffusion(15);
+    let n = m.nrows();
+    let cs_m: CsMatrix<f64> = m.clone().into();
+    let b = DVector::from_fn(n, |i, _| (i % 5) as f64 - 2.0);
+    let expected = m.clone().lu().solve(&b).unwrap();
+
+    let ilu0 = CsIncompleteLU::new(&cs_m).unwrap();
+    let l: DMatrix<f64> = ilu0.l().clone().into();
+    let u: DMatrix<f64> = ilu0.u().into();
+    assert_eq!(l.upper_triangle(), DMatrix::identity(n, n));
+    assert_eq!(u.lower_triangle(), DMatrix::from_diagonal(&u.diagonal()));
+
+    // `L * U` matches `m` on its non-zero pattern.
+    let lu = &l * &u;
+    for i in 0..n {
+        for j in 0..n {
+            if m[(i, j)] != 0.0 {
+                assert_relative_eq!(lu[(i, j)], m[(i, j)], epsilon = 1.0e-12);
+            }
+        }
+    }
+
+    let mut x = DVector::zeros(n);
+    let plain = na::bicgstab(&cs_m, &b, &mut x, None, 1.0e-10, 1000);
+    assert!(plain.converged);
+    assert_relative_eq!(x, expected, epsilon = 1.0e-7);
+
+    let mut x = DVector::zeros(n);
+    let report = na::bicgstab(&cs_m, &b, &mut x, Some(&ilu0), 1.0e-10, 1000);
+    assert!(report.converged);
+    assert!(report.niter <= plain.niter);
+    assert_relative_eq!(x, expected, epsilon = 1.0e-7);
+
+    let mut x = DVector::zeros(n);
+    let report = na::gmres(&cs_m, &b, &mut x, Some(&ilu0), 30, 1.0e-10, 1000);
+    assert!(report.converged);
+    assert!(report.residual_norm <= 1.0e-8 * b.norm());
+    assert_relative_eq!(x, expected, epsilon = 1.0e-7);
+}
+
+#[test]
+fn cs_incomplete_factorizations_failure() {
+    // The diagonal is not part of the pattern.
+    let m = DMatrix::from_row_slice(2, 2, &[0.0, 1.0, 1.0, 0.0]);
+    let cs_m: CsMatrix<f64> = m.into();
+
+    assert!(CsIncompleteCholesky::new(&cs_m).is_none());
+    assert!(CsIncompleteLU::new(&cs_m).is_none());
+}
@@ -0,0 +1,93 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{CsLU, CsMatrix, DMatrix, LinalgError, Matrix4, Vector4};
+
+// A random sparse unsymmetric matrix whose non-zero entries are not on the diagonal.
+fn random_unsymmetric(n: usize) -> DMatrix<f64> {
+    let mut m = DMatrix::<f64>::new_random(n, n).map(|e| if e < 0.9 { 0.0 } else { e - 0.5 });
+
+    for i in 0..n {
+        m[(i, i)] = 0.0;
+        m[(i, (i * 7 + 3) % n)] = 1.0 + i as f64 / n as f64;
+    }
+
+    m
+}
+
+fn test_lu(m: &DMatrix<f64>, lu: &CsLU<f64, na::Dynamic>) {
+    let n = m.nrows();
+    assert!(lu.l().is_sorted());
+    assert!(lu.u().is_sorted());
+
+    // P * m * Q = L * U
+    let l: DMatrix<f64> = lu.l().clone().into();
+    let u: DMatrix<f64> = lu.u().clone().into();
+    let p = lu.row_permutation();
+    let pmq = match lu.column_permutation() {
+        Some(q) => DMatrix::from_fn(n, n, |i, j| m[(p[i], q[j])]),
+        None => DMatrix::from_fn(n, n, |i, j| m[(p[i], j)]),
+    };
+    assert_eq!(l.upper_triangle(), DMatrix::identity(n, n));
+    assert_eq!(u.lower_triangle(), DMatrix::from_diagonal(&u.diagonal()));
+    assert!((&l * &u - pmq).norm() <= 1.0e-10 * l.norm() * u.norm());
+
+    let b = DMatrix::from_fn(n, 2, |i, j| (i * 2 + j) as f64 - 5.0);
+    let x = lu.solve(&b);
+    let xt = lu.solve_transpose(&b);
+    assert!((m * &x - &b).norm() <= 1.0e-10 * l.norm() * u.norm() * x.norm());
+    assert!((m.transpose() * &xt - &b).norm() <= 1.0e-10 * l.norm() * u.norm() * xt.norm());
+    assert_relative_eq!(lu.determinant(), m.determinant(), max_relative = 1.0e-8);
+}
+
+#[test]
+fn cs_lu_static() {
+    let a = Matrix4::new(
+        0.0, 2.0, 0.0, 1.0,
+        3.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 4.0, 0.0,
+        1.0, 0.0, 2.0, 5.0
+    );
+    let cs_a: CsMatrix<_, _, _> = a.into();
+    let lu = CsLU::new(&cs_a).unwrap();
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_relative_eq!(lu.solve(&b), a.lu().solve(&b).unwrap(), epsilon = 1.0e-10);
+    assert_relative_eq!(lu.solve_transpose(&b), a.transpose().lu().solve(&b).unwrap(), epsilon = 1.0e-10);
+    assert_relative_eq!(lu.determinant(), a.determinant(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn cs_lu() {
+    for n in &[1, 2, 10, 50] {
+        let m = random_unsymmetric(*n);
+        let cs_m: CsMatrix<f64> = m.clone().into();
+        test_lu(&m, &CsLU::new(&cs_m).unwrap());
+    }
+}
+
+#[test]
+fn cs_lu_with_permutation() {
+    for n in &[1, 2, 10, 50] {
+        let m = random_unsymmetric(*n);
+        let cs_m: CsMatrix<f64> = m.clone().into();
+        let perm = cs_m.column_approximate_minimum_degree_ordering();
+
+        for threshold in &[0.1, 1.0] {
+            test_lu(&m, &CsLU::new_with_permutation(&cs_m, &perm, *threshold).unwrap());
+        }
+    }
+}
+
+#[test]
+fn cs_lu_singular() {
+    let a = Matrix4::new(
+        1.0, 2.0, 0.0, 1.0,
+        3.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        1.0, 0.0, 0.0, 5.0
+    );
+    let cs_a: CsMatrix<_, _, _> = a.into();
+
+    assert!(CsLU::new(&cs_a).is_none());
+    assert_eq!(CsLU::try_decompose(&cs_a, None, 1.0).err(), Some(LinalgError::Singular { pivot: 2 }));
+}
@@ -1,8 +1,8 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
 
-use na::io;
-use na::DMatrix;
+use na::io::{self, MatrixMarketError};
+use na::{Complex, CsMatrix, DMatrix, Matrix2};
 
 #[test]
 fn cs_matrix_market() {
@@ -53,3 +53,188 @@ fn cs_matrix_market() {
 
     assert_eq!(mat, expected);
 }
+
+#[test]
+fn cs_matrix_market_symmetry() {
+    let symmetric = r#"%%MatrixMarket matrix coordinate real symmetric
+3 3 4
+1 1 2.0
+2 1 -1.0
+3 2 4.5
+3 3 1e1
+"#;
+    let mat: DMatrix<f64> = io::cs_matrix_from_matrix_market_str(symmetric).unwrap().into();
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        2.0,  -1.0, 0.0,
+        -1.0, 0.0,  4.5,
+        0.0,  4.5,  10.0,
+    ]);
+    assert_eq!(mat, expected);
+
+    let skew = r#"%%MatrixMarket matrix coordinate integer skew-symmetric
+3 3 2
+2 1 3
+3 2 -7
+"#;
+    let mat: DMatrix<f64> = io::dmatrix_from_matrix_market_str(skew).unwrap();
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        0.0, -3.0, 0.0,
+        3.0, 0.0,  7.0,
+        0.0, -7.0, 0.0,
+    ]);
+    assert_eq!(mat, expected);
+
+    let pattern = r#"%%MatrixMarket matrix coordinate pattern general
+2 3 3
+1 1
+2 3
+
+1 3
+"#;
+    let cs_mat = io::cs_matrix_from_matrix_market_str::<f64>(pattern).unwrap();
+    assert_eq!(cs_mat.len(), 3);
+    let mat: DMatrix<f64> = cs_mat.into();
+    assert_eq!(mat, DMatrix::from_row_slice(2, 3, &[1.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+
+    let hermitian = r#"%%MatrixMarket matrix coordinate complex hermitian
+2 2 2
+1 1 1.0 0.0
+2 1 2.0 -3.0
+"#;
+    let mat: DMatrix<Complex<f64>> = io::dmatrix_from_matrix_market_str(hermitian).unwrap();
+    let expected = DMatrix::from_row_slice(2, 2, &[
+        Complex::new(1.0, 0.0),  Complex::new(2.0, 3.0),
+        Complex::new(2.0, -3.0), Complex::new(0.0, 0.0),
+    ]);
+    assert_eq!(mat, expected);
+}
+
+#[test]
+fn cs_matrix_market_array() {
+    let general = r#"%%MatrixMarket matrix array real general
+% Column-major values.
+2 3
+1.0
+2.0
+3.0
+0.0
+5.0
+6.0
+"#;
+    let mat = io::dmatrix_from_matrix_market_str::<f64>(general).unwrap();
+    assert_eq!(mat, DMatrix::from_row_slice(2, 3, &[1.0, 3.0, 5.0, 2.0, 0.0, 6.0]));
+
+    // Explicit zeros of dense files are not stored in sparse matrices.
+    let cs_mat = io::cs_matrix_from_matrix_market_str::<f64>(general).unwrap();
+    assert_eq!(cs_mat.len(), 5);
+    assert_eq!(DMatrix::from(cs_mat), mat);
+
+    let symmetric = r#"%%MatrixMarket matrix array real symmetric
+3 3
+1.0
+2.0
+3.0
+4.0
+5.0
+6.0
+"#;
+    let mat = io::dmatrix_from_matrix_market_str::<f64>(symmetric).unwrap();
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 5.0,
+        3.0, 5.0, 6.0,
+    ]);
+    assert_eq!(mat, expected);
+
+    let skew = r#"%%MatrixMarket matrix array real skew-symmetric
+3 3
+1.0
+2.0
+3.0
+"#;
+    let mat = io::dmatrix_from_matrix_market_str::<f64>(skew).unwrap();
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        0.0, -1.0, -2.0,
+        1.0, 0.0,  -3.0,
+        2.0, 3.0,  0.0,
+    ]);
+    assert_eq!(mat, expected);
+}
+
+#[test]
+fn cs_matrix_market_errors() {
+    fn error_line(data: &str) -> Option<usize> {
+        match io::cs_matrix_from_matrix_market_str::<f64>(data).unwrap_err() {
+            MatrixMarketError::Syntax { line } => Some(line),
+            MatrixMarketError::InvalidHeader { line } => Some(line),
+            MatrixMarketError::IncompatibleField { line } => Some(line),
+            MatrixMarketError::InvalidSize { line } => Some(line),
+            MatrixMarketError::InvalidEntry { line } => Some(line),
+            MatrixMarketError::IndexOutOfBounds { line } => Some(line),
+            _ => None,
+        }
+    }
+
+    let no_header = "2 2 1\n1 1 1.0\n";
+    assert!(matches!(io::cs_matrix_from_matrix_market_str::<f64>(no_header), Err(MatrixMarketError::Syntax { .. })));
+
+    let bad_header = "\n%%MatrixMarket matrix coordinate real upper\n2 2 1\n1 1 1.0\n";
+    assert!(matches!(io::cs_matrix_from_matrix_market_str::<f64>(bad_header), Err(MatrixMarketError::InvalidHeader { .. })));
+    assert_eq!(error_line(bad_header), Some(2));
+
+    let complex = "%%MatrixMarket matrix coordinate complex general\n2 2 1\n1 1 1.0 2.0\n";
+    assert!(matches!(io::cs_matrix_from_matrix_market_str::<f64>(complex), Err(MatrixMarketError::IncompatibleField { .. })));
+    assert!(io::cs_matrix_from_matrix_market_str::<Complex<f64>>(complex).is_ok());
+
+    let not_square = "%%MatrixMarket matrix coordinate real symmetric\n%\n2 3 1\n1 1 1.0\n";
+    assert!(matches!(io::cs_matrix_from_matrix_market_str::<f64>(not_square), Err(MatrixMarketError::InvalidSize { .. })));
+    assert_eq!(error_line(not_square), Some(3));
+
+    let bad_value = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n2 2 abc\n";
+    assert!(matches!(io::cs_matrix_from_matrix_market_str::<f64>(bad_value), Err(MatrixMarketError::InvalidEntry { .. })));
+    assert_eq!(error_line(bad_value), Some(4));
+
+    let out_of_bounds = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n% comment\n3 1 1.0\n";
+    assert!(matches!(io::cs_matrix_from_matrix_market_str::<f64>(out_of_bounds), Err(MatrixMarketError::IndexOutOfBounds { .. })));
+    assert_eq!(error_line(out_of_bounds), Some(5));
+
+    let missing = "%%MatrixMarket matrix coordinate real general\n2 2 3\n1 1 1.0\n";
+    assert!(matches!(
+        io::cs_matrix_from_matrix_market_str::<f64>(missing),
+        Err(MatrixMarketError::EntryCount { expected: 3, found: 1 })
+    ));
+
+    assert!(matches!(io::cs_matrix_from_matrix_market::<f64, _>("/nonexistent/file.mtx"), Err(MatrixMarketError::Io(_))));
+}
+
+#[test]
+fn cs_matrix_market_write() {
+    let mat = DMatrix::from_row_slice(3, 4, &[
+        1.0, 0.0,   0.0,   6.0,
+        0.0, 10.5,  0.0,   0.0,
+        0.0, -2.5e-20, 0.1, 0.0,
+    ]);
+    let cs_mat: CsMatrix<f64> = mat.clone().into();
+
+    let mut sparse_file = Vec::new();
+    io::write_matrix_market(&mut sparse_file, &cs_mat).unwrap();
+    let sparse_str = String::from_utf8(sparse_file).unwrap();
+    assert!(sparse_str.starts_with("%%MatrixMarket matrix coordinate real general\n3 4 5\n"));
+    assert_eq!(DMatrix::from(io::cs_matrix_from_matrix_market_str::<f64>(&sparse_str).unwrap()), mat);
+
+    let mut dense_file = Vec::new();
+    io::write_matrix_market(&mut dense_file, &mat).unwrap();
+    let dense_str = String::from_utf8(dense_file).unwrap();
+    assert!(dense_str.starts_with("%%MatrixMarket matrix array real general\n3 4\n"));
+    assert_eq!(io::dmatrix_from_matrix_market_str::<f64>(&dense_str).unwrap(), mat);
+
+    let complex = Matrix2::new(
+        Complex::new(1.0, -2.0), Complex::new(0.5, 0.0),
+        Complex::new(0.0, 3.0),  Complex::new(-1.0, 1.0e10),
+    );
+    let mut complex_file = Vec::new();
+    io::write_matrix_market(&mut complex_file, &complex).unwrap();
+    let complex_str = String::from_utf8(complex_file).unwrap();
+    assert!(complex_str.starts_with("%%MatrixMarket matrix array complex general\n"));
+    assert_eq!(io::dmatrix_from_matrix_market_str::<Complex<f64>>(&complex_str).unwrap(), DMatrix::from_column_slice(2, 2, complex.as_slice()));
+}
@@ -1,7 +1,7 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
 
-use na::{Matrix3x4, Matrix4x5, Matrix3x5, CsMatrix, Vector5, CsVector};
+use na::{Matrix3x4, Matrix4x5, Matrix3x5, Matrix4, CsMatrix, Vector3, Vector4, Vector5, CsVector, DMatrix, DVector};
 
 #[test]
 fn axpy_cs() {
@@ -70,3 +70,143 @@ fn cs_mat_add() {
     assert!(sum.is_sorted());
     assert_eq!(Matrix4x5::from(sum), m1 + m2);
 }
+
+
+#[test]
+fn cs_mat_sub_neg() {
+    let m1 = Matrix4x5::new(
+        4.0, 1.0, 4.0, 0.0, 0.0,
+        5.0, 6.0, 0.0, 8.0, 0.0,
+        9.0, 10.0, 11.0, 12.0, 0.0,
+        0.0, 0.0, 1.0, 0.0, 10.0
+    );
+
+    let m2 = Matrix4x5::new(
+        0.0, 1.0, 4.0, 0.0, 14.0,
+        5.0, 6.0, 0.0, 8.0, 15.0,
+        9.0, 10.0, 11.0, 12.0, 0.0,
+        0.0, 0.0, 13.0, 0.0, 0.0,
+    );
+
+    let sm1: CsMatrix<_, _, _> = m1.into();
+    let sm2: CsMatrix<_, _, _> = m2.into();
+
+    let diff = &sm1 - &sm2;
+
+    assert!(diff.is_sorted());
+    assert_eq!(Matrix4x5::from(diff), m1 - m2);
+    assert_eq!(Matrix4x5::from(-sm1), -m1);
+}
+
+
+#[test]
+fn cs_mat_dense_mul() {
+    let m1 = Matrix3x4::new(
+        0.0, 1.0, 4.0, 0.0,
+        5.0, 6.0, 0.0, 8.0,
+        9.0, 10.0, 11.0, 12.0,
+    );
+
+    let m2 = Matrix4x5::new(
+        5.0, 6.0, 0.0, 8.0, 15.0,
+        9.0, 10.0, 11.0, 12.0, 0.0,
+        0.0, 0.0, 13.0, 0.0, 0.0,
+        0.0, 1.0, 4.0, 0.0, 14.0,
+    );
+
+    let sm1: CsMatrix<_, _, _> = m1.into();
+    let sm2: CsMatrix<_, _, _> = m2.into();
+
+    assert_eq!(&sm1 * &m2, m1 * m2);
+    assert_eq!(&m1 * &sm2, m1 * m2);
+
+    let dm1 = DMatrix::from_column_slice(3, 4, m1.as_slice());
+    let dsm1: CsMatrix<f64> = dm1.clone().into();
+    let dm2 = DMatrix::from_column_slice(4, 5, m2.as_slice());
+    assert_eq!(&dsm1 * &dm2, &dm1 * &dm2);
+}
+
+
+#[test]
+fn cs_gemv_sparse() {
+    let m = Matrix3x4::new(
+        0.0, 1.0, 4.0, 0.0,
+        5.0, 6.0, 0.0, 8.0,
+        9.0, 10.0, 11.0, 12.0,
+    );
+    let sm: CsMatrix<_, _, _> = m.into();
+
+    let x = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let mut y = Vector3::new(1.0, -1.0, 2.0);
+    let expected = m * x * 2.0 + y * 3.0;
+    y.gemv_sparse(2.0, &sm, &x, 3.0);
+    assert_eq!(y, expected);
+
+    let x = Vector3::new(1.0, 2.0, 3.0);
+    let mut y = Vector4::new(1.0, -1.0, 2.0, 0.5);
+    let expected = m.transpose() * x * 2.0 + y * 3.0;
+    y.gemv_tr_sparse(2.0, &sm, &x, 3.0);
+    assert_eq!(y, expected);
+
+    let expected = m.transpose() * x;
+    y.gemv_tr_sparse(1.0, &sm, &x, 0.0);
+    assert_eq!(y, expected);
+}
+
+
+#[test]
+fn cs_tr_mul() {
+    let m1 = Matrix4x5::new(
+        4.0, 1.0, 4.0, 0.0, 0.0,
+        5.0, 6.0, 0.0, 8.0, 0.0,
+        9.0, 10.0, 11.0, 12.0, 0.0,
+        0.0, 0.0, 1.0, 0.0, 10.0
+    );
+    let m2 = Matrix4::new(
+        0.0, 1.0, 4.0, 0.0,
+        5.0, 0.0, 0.0, 8.0,
+        0.0, 0.0, 0.0, 0.0,
+        0.0, 2.0, 13.0, 0.0,
+    );
+
+    let sm1: CsMatrix<_, _, _> = m1.into();
+    let sm2: CsMatrix<_, _, _> = m2.into();
+
+    let prod = sm1.tr_mul(&sm2);
+    assert!(prod.is_sorted());
+    assert_eq!(na::Matrix5x4::from(prod), m1.transpose() * m2);
+
+    let normal = sm1.tr_mul(&sm1);
+    assert!(normal.is_sorted());
+    assert_eq!(na::Matrix5::from(normal), m1.transpose() * m1);
+}
+
+
+#[test]
+fn cs_quadform() {
+    let n = 6;
+    let a = DMatrix::from_fn(n, n, |i, j| if i == j { 4.0 } else if i + 1 == j { -1.0 } else if j + 2 == i { 0.5 } else { 0.0 });
+    let sa: CsMatrix<f64> = a.clone().into();
+
+    // Symmetric permutation.
+    let perm = [3, 0, 5, 1, 4, 2];
+    let p = DMatrix::from_fn(n, n, |i, j| if perm[i] == j { 1.0 } else { 0.0 });
+    let sp: CsMatrix<f64> = p.clone().into();
+    let pap = sp.quadform_tr(&sa);
+    assert!(pap.is_sorted());
+    assert_eq!(DMatrix::from(pap), &p * &a * p.transpose());
+    assert_eq!(DMatrix::from(sp.quadform_tr(&sa)), DMatrix::from_fn(n, n, |i, j| a[(perm[i], perm[j])]));
+
+    // Galerkin coarse operator with a piecewise-constant prolongation.
+    let prolongation = DMatrix::from_fn(n, 3, |i, j| if i / 2 == j { 1.0 } else { 0.0 });
+    let sprolongation: CsMatrix<f64> = prolongation.clone().into();
+    let coarse = sprolongation.quadform(&sa);
+    assert!(coarse.is_sorted());
+    assert_eq!(DMatrix::from(coarse), prolongation.transpose() * &a * &prolongation);
+
+    let x = DVector::from_fn(n, |i, _| i as f64);
+    let mut y = DVector::zeros(3);
+    y.gemv_tr_sparse(1.0, &sprolongation, &x, 0.0);
+    assert_eq!(y, prolongation.transpose() * x);
+}
+
@@ -0,0 +1,119 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{CsCholesky, CsMatrix, DMatrix, DVector};
+
+// The 5-point laplacian of a `n x n` grid, shifted to be positive-definite.
+fn grid_laplacian(n: usize) -> CsMatrix<f64> {
+    let mut irows = Vec::new();
+    let mut icols = Vec::new();
+    let mut vals = Vec::new();
+
+    for x in 0..n {
+        for y in 0..n {
+            let i = x * n + y;
+            irows.push(i);
+            icols.push(i);
+            vals.push(4.1);
+
+            if x + 1 < n {
+                irows.extend_from_slice(&[i, i + n]);
+                icols.extend_from_slice(&[i + n, i]);
+                vals.extend_from_slice(&[-1.0, -1.0]);
+            }
+
+            if y + 1 < n {
+                irows.extend_from_slice(&[i, i + 1]);
+                icols.extend_from_slice(&[i + 1, i]);
+                vals.extend_from_slice(&[-1.0, -1.0]);
+            }
+        }
+    }
+
+    CsMatrix::from_triplet(n * n, n * n, &irows, &icols, &vals)
+}
+
+fn is_permutation(perm: &[usize], n: usize) -> bool {
+    let mut sorted = perm.to_vec();
+    sorted.sort();
+    sorted == (0..n).collect::<Vec<_>>()
+}
+
+fn test_ordering(m: &CsMatrix<f64>, perm: &[usize]) {
+    let n = m.nrows();
+    assert!(is_permutation(perm, n));
+
+    let natural = CsCholesky::new(m);
+    let permuted = CsCholesky::new_with_permutation(m, perm);
+    assert_eq!(permuted.permutation(), Some(perm));
+
+    // The factor is the one of the symmetrically permuted matrix.
+    let dense: DMatrix<f64> = m.clone().into();
+    let dense_permuted = DMatrix::from_fn(n, n, |i, j| dense[(perm[i], perm[j])]);
+    let l: DMatrix<f64> = permuted.l().unwrap().clone().into();
+    assert_relative_eq!(&l * l.transpose(), dense_permuted, epsilon = 1.0e-10);
+
+    // The permutation is applied transparently.
+    let b = DVector::from_fn(n, |i, _| (i % 7) as f64 - 3.0);
+    let x = permuted.solve(&b).unwrap();
+    assert_relative_eq!(&dense * &x, b, epsilon = 1.0e-10);
+    assert_relative_eq!(x, natural.solve(&b).unwrap(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn cs_cholesky_approximate_minimum_degree() {
+    let m = grid_laplacian(20);
+    test_ordering(&m, &m.approximate_minimum_degree_ordering());
+
+    let m = grid_laplacian(40);
+    let perm = m.approximate_minimum_degree_ordering();
+    let natural = CsCholesky::new(&m).unwrap_l().unwrap();
+    let permuted = CsCholesky::new_with_permutation(&m, &perm).unwrap_l().unwrap();
+    assert!(permuted.len() * 2 < natural.len());
+}
+
+#[test]
+fn cs_cholesky_nested_dissection() {
+    let m = grid_laplacian(20);
+    test_ordering(&m, &m.nested_dissection_ordering());
+
+    let m = grid_laplacian(40);
+    let perm = m.nested_dissection_ordering();
+    let natural = CsCholesky::new(&m).unwrap_l().unwrap();
+    let permuted = CsCholesky::new_with_permutation(&m, &perm).unwrap_l().unwrap();
+    assert!(permuted.len() * 2 < natural.len());
+}
+
+#[test]
+fn cs_ordering_disconnected() {
+    // Two disconnected grids and isolated nodes.
+    let dense: DMatrix<f64> = grid_laplacian(9).into();
+    let mut block = DMatrix::identity(170, 170);
+    block.slice_mut((0, 0), (81, 81)).copy_from(&dense);
+    block.slice_mut((81, 81), (81, 81)).copy_from(&dense);
+    let m: CsMatrix<f64> = block.into();
+
+    test_ordering(&m, &m.approximate_minimum_degree_ordering());
+    test_ordering(&m, &m.nested_dissection_ordering());
+}
+
+#[test]
+fn cs_cholesky_solve_matrix() {
+    let m = grid_laplacian(4);
+    let perm = m.nested_dissection_ordering();
+    let chol = CsCholesky::new_with_permutation(&m, &perm);
+    let b = DMatrix::from_fn(16, 3, |i, j| (i * 3 + j) as f64);
+    let x = chol.solve(&b).unwrap();
+
+    let dense: DMatrix<f64> = m.into();
+    assert_relative_eq!(dense * x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn cs_cholesky_not_positive_definite_with_permutation() {
+    let m: CsMatrix<f64> = DMatrix::from_diagonal_element(4, 4, -1.0).into();
+    let perm = m.approximate_minimum_degree_ordering();
+    let chol = CsCholesky::new_with_permutation(&m, &perm);
+
+    assert!(chol.l().is_none());
+    assert!(chol.solve(&DVector::<f64>::zeros(4)).is_none());
+}
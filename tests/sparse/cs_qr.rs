@@ -0,0 +1,84 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{CsMatrix, CsQR, DMatrix, LinalgError, Matrix4x3, Vector4};
+
+// A random tall sparse matrix with full column rank.
+fn random_tall(nrows: usize, ncols: usize) -> DMatrix<f64> {
+    let mut m = DMatrix::<f64>::new_random(nrows, ncols).map(|e| if e < 0.85 { 0.0 } else { e - 0.5 });
+
+    for j in 0..ncols {
+        m[((j * 7 + 2) % nrows, j)] = 2.0 + j as f64 / ncols as f64;
+    }
+
+    m
+}
+
+#[test]
+fn cs_qr() {
+    for &(nrows, ncols) in &[(1, 1), (5, 3), (20, 20), (60, 25)] {
+        let m = random_tall(nrows, ncols);
+        let cs_m: CsMatrix<f64> = m.clone().into();
+        let qr = CsQR::new(&cs_m);
+        let r: DMatrix<f64> = qr.r().clone().into();
+        let perm = qr.column_permutation();
+        let mq = DMatrix::from_fn(nrows, ncols, |i, j| m[(i, perm[j])]);
+
+        assert!(qr.r().is_sorted());
+        assert!(qr.is_full_rank());
+        assert_eq!(r.lower_triangle(), DMatrix::from_diagonal(&r.diagonal()));
+        assert_relative_eq!(r.transpose() * &r, mq.transpose() * &mq, epsilon = 1.0e-10);
+
+        let b = DMatrix::from_fn(nrows, 2, |i, j| (i + j * 3) as f64 - 4.0);
+        let sol = cs_m.solve_least_squares(&b).unwrap();
+        let expected = m.solve_least_squares(&b);
+
+        assert_eq!(sol.rank, ncols);
+        assert_relative_eq!(sol.solution, expected.solution, epsilon = 1.0e-8);
+        assert_relative_eq!(sol.residual_norm, expected.residual_norm, epsilon = 1.0e-8);
+    }
+}
+
+#[test]
+fn cs_qr_static() {
+    let a = Matrix4x3::new(
+        1.0, 0.0, 0.0,
+        0.0, 2.0, 0.0,
+        1.0, 0.0, 3.0,
+        0.0, 1.0, 1.0
+    );
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let cs_a: CsMatrix<_, _, _> = a.into();
+    let sol = cs_a.solve_least_squares(&b).unwrap();
+
+    assert_relative_eq!(sol.solution, a.solve_least_squares(&b).solution, epsilon = 1.0e-10);
+}
+
+#[test]
+fn cs_qr_rank_deficient() {
+    // The second column is zero.
+    let a = DMatrix::from_row_slice(4, 3, &[
+        1.0, 0.0, 2.0,
+        0.0, 0.0, 1.0,
+        3.0, 0.0, 0.0,
+        0.0, 0.0, 1.0
+    ]);
+    let cs_a: CsMatrix<f64> = a.into();
+
+    let qr = CsQR::new(&cs_a);
+    assert!(!qr.is_full_rank());
+
+    match cs_a.solve_least_squares(&DMatrix::<f64>::zeros(4, 1)) {
+        Err(LinalgError::Singular { pivot }) => assert_eq!(qr.column_permutation()[pivot], 1),
+        _ => panic!("The rank-deficient system should not be solvable."),
+    }
+
+    // Structurally rank-deficient: only the first row is non-zero.
+    let a = DMatrix::from_row_slice(3, 2, &[
+        1.0, 1.0,
+        0.0, 0.0,
+        0.0, 0.0
+    ]);
+    let cs_a: CsMatrix<f64> = a.into();
+
+    assert!(!CsQR::new(&cs_a).is_full_rank());
+}
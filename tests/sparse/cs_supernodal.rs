@@ -0,0 +1,102 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{CooMatrix, CsCholesky, CsMatrix, DMatrix, DVector};
+
+// The 7-point laplacian of a `n x n x n` grid with `ndofs` coupled unknowns per node, shifted to
+// be positive-definite.
+fn grid_laplacian_3d(n: usize, ndofs: usize) -> CsMatrix<f64> {
+    let size = n * n * n * ndofs;
+    let mut coo = CooMatrix::new(size, size);
+    let node = |x: usize, y: usize, z: usize| (x * n + y) * n + z;
+
+    for x in 0..n {
+        for y in 0..n {
+            for z in 0..n {
+                let i = node(x, y, z);
+                let mut neighbors = Vec::new();
+
+                if x + 1 < n { neighbors.push(node(x + 1, y, z)) }
+                if y + 1 < n { neighbors.push(node(x, y + 1, z)) }
+                if z + 1 < n { neighbors.push(node(x, y, z + 1)) }
+
+                for a in 0..ndofs {
+                    for b in 0..ndofs {
+                        let coupling = if a == b { 6.5 } else { 0.25 };
+                        coo.push(i * ndofs + a, i * ndofs + b, coupling);
+
+                        for j in &neighbors {
+                            let val = if a == b { -1.0 } else { -0.1 };
+                            coo.push(i * ndofs + a, j * ndofs + b, val);
+                            coo.push(j * ndofs + b, i * ndofs + a, val);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    coo.to_cs_matrix()
+}
+
+fn test_supernodal(m: &CsMatrix<f64>, perm: Option<&[usize]>) {
+    let n = m.nrows();
+    let (mut scalar, mut supernodal) = match perm {
+        Some(perm) => (
+            CsCholesky::new_symbolic_with_permutation(m, perm),
+            CsCholesky::new_symbolic_with_permutation(m, perm),
+        ),
+        None => (CsCholesky::new_symbolic(m), CsCholesky::new_symbolic(m)),
+    };
+
+    assert!(scalar.decompose_left_looking(m.values()));
+    assert!(supernodal.decompose_supernodal(m.values()));
+    assert!(supernodal.supernode_count() < n);
+
+    let l1: DMatrix<f64> = scalar.l().unwrap().clone().into();
+    let l2: DMatrix<f64> = supernodal.l().unwrap().clone().into();
+    assert_relative_eq!(l1, l2, epsilon = 1.0e-10);
+
+    let dense: DMatrix<f64> = m.clone().into();
+    let b = DVector::from_fn(n, |i, _| (i % 7) as f64 - 3.0);
+    let x = supernodal.solve(&b).unwrap();
+    assert_relative_eq!(&dense * &x, b, epsilon = 1.0e-10);
+
+    // Refactorization with different values but the same pattern.
+    let scaled: Vec<f64> = m.values().iter().map(|v| *v * 2.0).collect();
+    assert!(supernodal.decompose_supernodal(&scaled));
+    let x2 = supernodal.solve(&b).unwrap();
+    assert_relative_eq!(x2 * 2.0, x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn cs_supernodal_cholesky_natural() {
+    test_supernodal(&grid_laplacian_3d(4, 3), None);
+}
+
+#[test]
+fn cs_supernodal_cholesky_nested_dissection() {
+    let m = grid_laplacian_3d(5, 2);
+    let perm = m.nested_dissection_ordering();
+    test_supernodal(&m, Some(&perm));
+}
+
+#[test]
+fn cs_supernodal_cholesky_amd() {
+    let m = grid_laplacian_3d(4, 3);
+    let perm = m.approximate_minimum_degree_ordering();
+    test_supernodal(&m, Some(&perm));
+}
+
+#[test]
+fn cs_supernodal_cholesky_fail() {
+    let m = grid_laplacian_3d(3, 2);
+    let mut chol = CsCholesky::new_symbolic(&m);
+
+    // Negating the diagonal makes the matrix indefinite.
+    let values: Vec<f64> = m.values().iter().map(|v| if *v > 6.0 { -*v } else { *v }).collect();
+    assert!(!chol.decompose_supernodal(&values));
+    assert!(chol.l().is_none());
+
+    assert!(chol.decompose_supernodal(m.values()));
+    assert!(chol.l().is_some());
+}
@@ -1,8 +1,16 @@
 mod cs_cholesky;
 mod cs_construction;
+mod cs_iterative;
 mod cs_conversion;
+mod cs_edition;
+mod cs_eigen;
+mod cs_formats;
+mod cs_lu;
 mod cs_matrix;
 #[cfg(feature = "io")]
 mod cs_matrix_market;
 mod cs_ops;
+mod cs_ordering;
+mod cs_qr;
 mod cs_solve;
+mod cs_supernodal;